@@ -1,4 +1,3 @@
-use itertools::Itertools;
 use plotters::data::fitting_range;
 use plotters::prelude::*;
 use std::collections::BTreeMap;
@@ -40,23 +39,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .map(|(k, v)| (k.0.clone(), k.1.clone(), Quartiles::new(&v)))
         .collect();
 
-    let category = Category::new(
-        "Host",
-        dataset
-            .iter()
-            .unique_by(|x| x.0.clone())
-            .sorted_by(|a, b| b.2.median().partial_cmp(&a.2.median()).unwrap())
-            .map(|x| x.0.clone())
-            .collect(),
-    );
+    let medians: HashMap<_, _> = dataset.iter().map(|x| (&x.0, x.2.median())).collect();
+    let category = Category::from_keys("Host", dataset.iter().map(|x| x.0.clone()))
+        .sorted_by(|a, b| medians[b].partial_cmp(&medians[a]).unwrap());
 
     let mut colors = (0..).map(Palette99::pick);
-    let mut offsets = (-12..).step_by(24);
     let mut series = BTreeMap::new();
     for x in dataset.iter() {
         let entry = series
             .entry(x.1.clone())
-            .or_insert_with(|| (Vec::new(), colors.next().unwrap(), offsets.next().unwrap()));
+            .or_insert_with(|| (Vec::new(), colors.next().unwrap()));
         entry.0.push((x.0.clone(), &x.2));
     }
 
@@ -84,14 +76,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .line_style_2(&WHITE)
         .draw()?;
 
-    for (label, (values, style, offset)) in &series {
+    let slot = chart.y_slot_size();
+    let offsets = slot.group_offsets(series.len(), 0.8);
+    for ((label, (values, style)), offset) in series.iter().zip(offsets) {
         chart
             .draw_series(values.iter().map(|x| {
                 Boxplot::new_horizontal(category.get(&x.0).unwrap(), &x.1)
-                    .width(20)
+                    .width(slot.width(0.3))
                     .whisker_width(0.5)
                     .style(style)
-                    .offset(*offset)
+                    .offset(offset)
             }))?
             .label(label)
             .legend(move |(x, y)| Rectangle::new([(x - 5, y - 5), (x + 5, y + 5)], style.filled()));
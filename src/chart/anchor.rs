@@ -0,0 +1,168 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::backend::{BackendCoord, DrawingBackend};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{PathElement, Text};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// The registry of the named points, which are registered while the figure is drawn and
+/// referenced later to connect the elements of different chart regions, e.g. a legend entry
+/// and its series. The points are kept in the backend coordinate, thus the anchors registered
+/// on different charts or drawing areas can be connected with each other.
+#[derive(Default)]
+pub struct AnchorRegistry {
+    anchors: RefCell<HashMap<String, BackendCoord>>,
+}
+
+impl AnchorRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the name of the anchor registered for the legend entry of a series, see
+    /// `SeriesLabelStyle::anchors`
+    /// - `label`: The label of the series
+    pub fn legend_name(label: &str) -> String {
+        format!("legend:{}", label)
+    }
+
+    /// Register a point in the backend coordinate, the anchor with the same name is replaced
+    /// - `name`: The name of the anchor
+    /// - `pos`: The position in the backend coordinate
+    pub fn register<S: Into<String>>(&self, name: S, pos: BackendCoord) {
+        self.anchors.borrow_mut().insert(name.into(), pos);
+    }
+
+    /// Register a point in the guest coordinate of a drawing area, e.g. a data point of the
+    /// chart with `ChartContext::plotting_area`
+    /// - `name`: The name of the anchor
+    /// - `area`: The drawing area which defines the coordinate
+    /// - `coord`: The position in the guest coordinate
+    pub fn register_on<S, DB, CT>(&self, name: S, area: &DrawingArea<DB, CT>, coord: &CT::From)
+    where
+        S: Into<String>,
+        DB: DrawingBackend,
+        CT: CoordTranslate,
+    {
+        self.register(name, area.map_coordinate(coord));
+    }
+
+    /// Get the position of an anchor in the backend coordinate
+    /// - `name`: The name of the anchor
+    pub fn get(&self, name: &str) -> Option<BackendCoord> {
+        self.anchors.borrow().get(name).copied()
+    }
+
+    /// Draw a straight line connecting two anchors. The line is truncated to the drawing area,
+    /// thus the anchors in different regions of the figure should be connected on the root area.
+    /// - `area`: The drawing area to draw on, usually the root area
+    /// - `from`: The name of the anchor where the line starts
+    /// - `to`: The name of the anchor where the line ends
+    /// - `style`: The style of the line
+    /// - **returns**: If both anchors are registered and the line is drawn
+    pub fn draw_connector<DB: DrawingBackend, S: Into<ShapeStyle>>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        from: &str,
+        to: &str,
+        style: S,
+    ) -> Result<bool, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (from, to) = match (self.get(from), self.get(to)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(false),
+        };
+        let (x0, y0) = area.get_base_pixel();
+        area.draw(&PathElement::new(
+            vec![(from.0 - x0, from.1 - y0), (to.0 - x0, to.1 - y0)],
+            style,
+        ))?;
+        Ok(true)
+    }
+
+    /// Draw a callout, which is a text placed away from an anchor with a line pointing to it
+    /// - `area`: The drawing area to draw on, usually the root area
+    /// - `anchor`: The name of the anchor
+    /// - `offset`: The offset of the text from the anchor in pixels
+    /// - `text`: The text of the callout
+    /// - `line_style`: The style of the line
+    /// - `text_style`: The style of the text
+    /// - **returns**: If the anchor is registered and the callout is drawn
+    pub fn draw_callout<'a, DB: DrawingBackend, S: Into<ShapeStyle>, T: Into<TextStyle<'a>>>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        anchor: &str,
+        offset: (i32, i32),
+        text: &str,
+        line_style: S,
+        text_style: T,
+    ) -> Result<bool, DrawingAreaErrorKind<DB::ErrorType>> {
+        let pos = match self.get(anchor) {
+            Some(pos) => pos,
+            None => return Ok(false),
+        };
+        let (x0, y0) = area.get_base_pixel();
+        let from = (pos.0 - x0, pos.1 - y0);
+        let to = (from.0 + offset.0, from.1 + offset.1);
+        area.draw(&PathElement::new(vec![from, to], line_style))?;
+        area.draw(&Text::new(text, to, text_style))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_anchor_registry() {
+        let paths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = paths.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |_, _, path| {
+                drawn.borrow_mut().push(path);
+            });
+        });
+
+        let anchors = AnchorRegistry::new();
+        let (_, right) = drawing_area.split_horizontally(100);
+        let mut chart = ChartBuilder::on(&right)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        anchors.register_on("peak", chart.plotting_area(), &(5, 5));
+        anchors.register("note", (10, 10));
+        assert_eq!(anchors.get("peak"), Some((150, 99)));
+        assert_eq!(anchors.get("missing"), None);
+
+        assert!(anchors
+            .draw_connector(&drawing_area, "note", "peak", &BLACK)
+            .unwrap());
+        assert!(!anchors
+            .draw_connector(&drawing_area, "note", "missing", &BLACK)
+            .unwrap());
+
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0], vec![(10, 10), (150, 99)]);
+        drop(paths);
+
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 3, &RED)))
+            .unwrap()
+            .label("peak")
+            .legend(|(x, y)| Circle::new((x, y), 3, &RED));
+        chart
+            .configure_series_labels()
+            .anchors(&anchors)
+            .draw()
+            .unwrap();
+        let legend = anchors
+            .get(&AnchorRegistry::legend_name("peak"))
+            .expect("Legend anchor");
+        assert!(legend.0 >= 100);
+    }
+}
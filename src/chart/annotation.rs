@@ -0,0 +1,340 @@
+use super::context::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, EmptyElement, PathElement, Rectangle, Text};
+use crate::style::{IntoFont, RGBColor, TextStyle};
+
+#[cfg(feature = "chrono")]
+use crate::style::ShapeStyle;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The default color of the annotations
+const DEFAULT_COLOR: [u8; 3] = [96, 96, 96];
+
+/// The opacity of the shaded regions
+const REGION_OPACITY: f64 = 0.15;
+
+/// The distance between the annotated position and its label in pixels
+const LABEL_OFFSET: i32 = 4;
+
+/// An annotation keyed to the data coordinate of the chart
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum Annotation<X, Y> {
+    /// The vertical line at the X value, e.g. a release
+    VLine {
+        x: X,
+        label: Option<String>,
+        color: Option<[u8; 3]>,
+    },
+    /// The horizontal line at the Y value, e.g. a threshold
+    HLine {
+        y: Y,
+        label: Option<String>,
+        color: Option<[u8; 3]>,
+    },
+    /// The shaded region between two X values, e.g. an incident
+    XRegion {
+        from: X,
+        to: X,
+        label: Option<String>,
+        color: Option<[u8; 3]>,
+    },
+    /// The shaded region between two Y values, e.g. the expected range
+    YRegion {
+        from: Y,
+        to: Y,
+        label: Option<String>,
+        color: Option<[u8; 3]>,
+    },
+    /// The text pointing at a data point
+    Callout {
+        x: X,
+        y: Y,
+        text: String,
+        color: Option<[u8; 3]>,
+    },
+}
+
+impl<X, Y> Annotation<X, Y> {
+    fn color(&self) -> RGBColor {
+        let color = match self {
+            Annotation::VLine { color, .. }
+            | Annotation::HLine { color, .. }
+            | Annotation::XRegion { color, .. }
+            | Annotation::YRegion { color, .. }
+            | Annotation::Callout { color, .. } => color.unwrap_or(DEFAULT_COLOR),
+        };
+        RGBColor(color[0], color[1], color[2])
+    }
+}
+
+/// The set of annotations which is maintained separately from the data and the code of the
+/// chart, e.g. loaded from a document with serde, and applied to the chart at render time
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnnotationLayer<X, Y> {
+    /// The annotations drawn in order
+    pub annotations: Vec<Annotation<X, Y>>,
+}
+
+impl<X, Y> Default for AnnotationLayer<X, Y> {
+    fn default() -> Self {
+        Self {
+            annotations: vec![],
+        }
+    }
+}
+
+impl<X: Clone, Y: Clone> AnnotationLayer<X, Y> {
+    /// Create an empty annotation layer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an annotation to the layer
+    pub fn add(&mut self, annotation: Annotation<X, Y>) -> &mut Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Draw the annotations on the chart, the lines and the regions span the plotting area and
+    /// the parts out of the chart range are clipped
+    /// - `chart`: The chart to annotate
+    pub fn draw<'b, DB: DrawingBackend + 'b, XR, YR>(
+        &self,
+        chart: &ChartContext<'b, DB, RangedCoord<XR, YR>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+    {
+        let area = chart.plotting_area();
+        let x_range = area.get_x_range();
+        let y_range = area.get_y_range();
+        let font = ("sans-serif", 12).into_font();
+
+        for annotation in &self.annotations {
+            let color = annotation.color();
+            let text_style: TextStyle = font.color(&color);
+            let (label, anchor) = match annotation {
+                Annotation::VLine { x, label, .. } => {
+                    area.draw(&PathElement::new(
+                        vec![
+                            (x.clone(), y_range.start.clone()),
+                            (x.clone(), y_range.end.clone()),
+                        ],
+                        color.stroke_width(1),
+                    ))?;
+                    (label.as_ref(), (x.clone(), y_range.end.clone()))
+                }
+                Annotation::HLine { y, label, .. } => {
+                    area.draw(&PathElement::new(
+                        vec![
+                            (x_range.start.clone(), y.clone()),
+                            (x_range.end.clone(), y.clone()),
+                        ],
+                        color.stroke_width(1),
+                    ))?;
+                    (label.as_ref(), (x_range.start.clone(), y.clone()))
+                }
+                Annotation::XRegion {
+                    from, to, label, ..
+                } => {
+                    area.draw(&Rectangle::new(
+                        [
+                            (from.clone(), y_range.start.clone()),
+                            (to.clone(), y_range.end.clone()),
+                        ],
+                        color.mix(REGION_OPACITY).filled(),
+                    ))?;
+                    (label.as_ref(), (from.clone(), y_range.end.clone()))
+                }
+                Annotation::YRegion {
+                    from, to, label, ..
+                } => {
+                    area.draw(&Rectangle::new(
+                        [
+                            (x_range.start.clone(), from.clone()),
+                            (x_range.end.clone(), to.clone()),
+                        ],
+                        color.mix(REGION_OPACITY).filled(),
+                    ))?;
+                    (label.as_ref(), (x_range.start.clone(), to.clone()))
+                }
+                Annotation::Callout { x, y, text, .. } => {
+                    area.draw(&Circle::new((x.clone(), y.clone()), 3, color.filled()))?;
+                    (Some(text), (x.clone(), y.clone()))
+                }
+            };
+
+            if let Some(label) = label {
+                area.draw(
+                    &(EmptyElement::at(anchor)
+                        + Text::new(label.clone(), (LABEL_OFFSET, LABEL_OFFSET), text_style)),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<'a, DB, X, Y, Z> ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    DB: DrawingBackend + 'a,
+    X: Ranged<ValueType = DateTime<Z>>,
+    Y: Ranged,
+    Y::ValueType: Clone,
+    Z: TimeZone,
+{
+    /// Draw the vertical line labeled "now" at the current time, in the time zone of the X axis.
+    /// Call it on every redraw of a monitoring chart to keep the line up to date. Nothing is
+    /// drawn when the current time is out of the X range.
+    /// - `style`: The style of the line, the label uses the same color
+    pub fn draw_now_line<S: Into<ShapeStyle>>(
+        &self,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let timezone = self.x_range().start.timezone();
+        self.draw_now_line_at(&Utc::now().with_timezone(&timezone), style)
+    }
+
+    /// Draw the vertical line labeled "now" at the given instant, see `draw_now_line`
+    /// - `now`: The instant to mark
+    /// - `style`: The style of the line, the label uses the same color
+    pub fn draw_now_line_at<S: Into<ShapeStyle>>(
+        &self,
+        now: &DateTime<Z>,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let x_range = self.x_range();
+        if *now < x_range.start || *now > x_range.end {
+            return Ok(());
+        }
+
+        let style = style.into();
+        let y_range = self.y_range();
+        let area = self.plotting_area();
+        area.draw(&PathElement::new(
+            vec![
+                (now.clone(), y_range.start.clone()),
+                (now.clone(), y_range.end.clone()),
+            ],
+            style.clone(),
+        ))?;
+        area.draw(
+            &(EmptyElement::at((now.clone(), y_range.end))
+                + Text::new(
+                    "now",
+                    (LABEL_OFFSET, LABEL_OFFSET),
+                    ("sans-serif", 12).into_font().color(&style.color),
+                )),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn sample_layer() -> AnnotationLayer<f64, f64> {
+        let mut layer = AnnotationLayer::new();
+        layer
+            .add(Annotation::VLine {
+                x: 2.0,
+                label: Some("v1.0".to_string()),
+                color: None,
+            })
+            .add(Annotation::XRegion {
+                from: 5.0,
+                to: 6.0,
+                label: Some("outage".to_string()),
+                color: Some([255, 0, 0]),
+            })
+            .add(Annotation::Callout {
+                x: 8.0,
+                y: 3.0,
+                text: "peak".to_string(),
+                color: None,
+            });
+        layer
+    }
+
+    #[test]
+    fn test_annotation_layer() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, _, _| {
+                assert!(filled);
+                assert_eq!(c.rgb(), (255, 0, 0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        sample_layer().draw(&chart).expect("Draw annotations");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_now_line() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let now = Utc.ymd(2020, 1, 1).and_hms(12, 0, 0);
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c.rgb(), (255, 0, 0));
+                assert_eq!(path[0].0, 50);
+                assert_eq!(path[1].0, 50);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(
+                (now - Duration::hours(1)..now + Duration::hours(1)).relative_to(now),
+                0.0..1.0,
+            )
+            .expect("Create chart");
+        chart.draw_now_line_at(&now, &RED).expect("Draw now line");
+        chart
+            .draw_now_line_at(&(now + Duration::hours(2)), &RED)
+            .expect("Skip the now line out of range");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let layer: AnnotationLayer<f64, f64> = serde_json::from_str(
+            r#"{"annotations": [
+                {"type": "v_line", "x": 2.0, "label": "v1.0"},
+                {"type": "x_region", "from": 5.0, "to": 6.0, "label": "outage", "color": [255, 0, 0]},
+                {"type": "callout", "x": 8.0, "y": 3.0, "text": "peak"}
+            ]}"#,
+        )
+        .expect("Parse annotations");
+        assert_eq!(layer, sample_layer());
+        assert_eq!(
+            serde_json::from_str::<AnnotationLayer<f64, f64>>(
+                &serde_json::to_string(&layer).unwrap()
+            )
+            .unwrap(),
+            layer
+        );
+    }
+}
@@ -1,10 +1,15 @@
-use super::context::ChartContext;
+use super::context::{ChartContext, DEFAULT_CULL_MARGIN};
 
-use crate::coord::{AsRangedCoord, RangedCoord, Shift};
+use crate::coord::{
+    AsRangedCoord, LogScalable, NiceRange, PercentCoord, PowerLogCoord, RangedCoord, Shift,
+};
+use crate::data::stack_shares;
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
 
+use std::ops::Range;
+
 /// The enum used to specify the position of label area.
 /// This is used when we configure the label area size with the API `set_label_area_size`
 #[derive(Copy, Clone)]
@@ -15,6 +20,39 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/// The padding between the plot frame and the data along an axis, see
+/// `ChartBuilder::x_axis_margin` and `ChartBuilder::y_axis_margin`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisMargin {
+    /// The padding in pixels
+    Pixels(u32),
+    /// The padding in percent of the axis length
+    Percent(f64),
+}
+
+impl AxisMargin {
+    /// Get the padding in pixels, which leaves at least one pixel to the data
+    fn in_pixels(self, length: u32) -> i32 {
+        let margin = match self {
+            AxisMargin::Pixels(size) => size as i32,
+            AxisMargin::Percent(p) => (f64::from(length) * p / 100.0).round() as i32,
+        };
+        margin.max(0).min((length as i32 - 1) / 2)
+    }
+}
+
+impl From<u32> for AxisMargin {
+    fn from(size: u32) -> Self {
+        AxisMargin::Pixels(size)
+    }
+}
+
+impl From<i32> for AxisMargin {
+    fn from(size: i32) -> Self {
+        AxisMargin::Pixels(size.max(0) as u32)
+    }
+}
+
 /// The helper object to create a chart context, which is used for the high-level figure drawing.
 /// With the help of this object, we can convert a basic drawing area into a chart context, which
 /// allows the high-level charting API being used on the drawing area.
@@ -24,6 +62,7 @@ pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: [u32; 4],
+    axis_margin: [AxisMargin; 2],
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -36,6 +75,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             root_area: root,
             title: None,
             margin: [0; 4],
+            axis_margin: [AxisMargin::Pixels(0); 2],
             overlap_plotting_area: [false; 4],
         }
     }
@@ -80,6 +120,24 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Pad the data away from the left and the right sides of the plot frame, so that the
+    /// markers at the extremes of the X range aren't clipped by the frame. The declared range
+    /// and the tick values are unchanged, and the X axis still spans the whole frame.
+    /// - `margin`: The padding in pixels, or `AxisMargin::Percent` of the axis length
+    pub fn x_axis_margin<M: Into<AxisMargin>>(&mut self, margin: M) -> &mut Self {
+        self.axis_margin[0] = margin.into();
+        self
+    }
+
+    /// Pad the data away from the top and the bottom sides of the plot frame, so that the
+    /// markers at the extremes of the Y range aren't clipped by the frame. The declared range
+    /// and the tick values are unchanged, and the Y axis still spans the whole frame.
+    /// - `margin`: The padding in pixels, or `AxisMargin::Percent` of the axis length
+    pub fn y_axis_margin<M: Into<AxisMargin>>(&mut self, margin: M) -> &mut Self {
+        self.axis_margin[1] = margin.into();
+        self
+    }
+
     /// Set all the label area size with the same value
     pub fn set_all_label_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
         let size = size.in_pixels(self.root_area);
@@ -150,6 +208,23 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Build the chart with a 2D Cartesian coordinate system, after expanding both ranges outward
+    /// to the nearest nice tick values, so that the plot frame coincides with the ticks.
+    /// - `x_spec`: The range of X axis
+    /// - `y_spec`: The range of Y axis
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged_nice<X: AsRangedCoord + NiceRange, Y: AsRangedCoord + NiceRange>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        self.build_ranged(x_spec.nicify(), y_spec.nicify())
+    }
+
     /// Build the chart with a 2D Cartesian coordinate system. The function will returns a chart
     /// context, where data series can be rendered on.
     /// - `x_spec`: The specification of X axis
@@ -164,8 +239,189 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
-        let mut label_areas = [None, None, None, None];
+        let drawing_area = self.chart_area()?;
+        self.build_on(drawing_area, self.label_area_size, x_spec, y_spec)
+    }
+
+    /// Build the chart with the log scaled X axis, which is labeled with the powers of ten
+    /// - `x_range`: The range of X axis, which should be positive
+    /// - `y_spec`: The specification of Y axis
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_log_x<XV: LogScalable, Y: AsRangedCoord>(
+        &mut self,
+        x_range: Range<XV>,
+        y_spec: Y,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<PowerLogCoord<XV>, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        self.build_ranged(PowerLogCoord::from(x_range), y_spec)
+    }
+
+    /// Build the chart with the log scaled Y axis, which is labeled with the powers of ten
+    /// - `x_spec`: The specification of X axis
+    /// - `y_range`: The range of Y axis, which should be positive
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_log_y<X: AsRangedCoord, YV: LogScalable>(
+        &mut self,
+        x_spec: X,
+        y_range: Range<YV>,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<X::CoordDescType, PowerLogCoord<YV>>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        self.build_ranged(x_spec, PowerLogCoord::from(y_range))
+    }
+
+    /// Build the chart with both axes log scaled and labeled with the powers of ten
+    /// - `x_range`: The range of X axis, which should be positive
+    /// - `y_range`: The range of Y axis, which should be positive
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_log_log<XV: LogScalable, YV: LogScalable>(
+        &mut self,
+        x_range: Range<XV>,
+        y_range: Range<YV>,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<PowerLogCoord<XV>, PowerLogCoord<YV>>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        self.build_ranged(PowerLogCoord::from(x_range), PowerLogCoord::from(y_range))
+    }
+
+    /// Build the percent stacked chart, whose Y axis is the share of the total from 0% to 100%
+    /// and labeled in percent. The series are normalized and stacked by `stack_shares`, thus
+    /// the returned values can be drawn as the upper bounds of the bands directly.
+    /// - `x_spec`: The specification of X axis
+    /// - `series`: The values of each series, paired by their index
+    /// - Returns: The chart context and the stacked shares of each series
+    #[allow(clippy::type_complexity)]
+    pub fn build_percent_stacked<X: AsRangedCoord, V: Into<f64> + Copy>(
+        &mut self,
+        x_spec: X,
+        series: &[Vec<V>],
+    ) -> Result<
+        (
+            ChartContext<'a, DB, RangedCoord<X::CoordDescType, PercentCoord>>,
+            Vec<Vec<f64>>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let chart = self.build_ranged(x_spec, PercentCoord::whole())?;
+        Ok((chart, stack_shares(series)))
+    }
 
+    /// Build the chart with a residual panel below it. The panel shares the X axis with the
+    /// main chart, thus the main chart has no bottom label area and both plotting areas are
+    /// aligned horizontally. This is the common layout to show the residuals or the ratios of
+    /// the data to the model, see `residuals`.
+    /// - `x_spec`: The specification of the shared X axis
+    /// - `y_spec`: The specification of Y axis of the main chart
+    /// - `residual_spec`: The specification of Y axis of the residual panel
+    /// - `panel_size`: The fraction of the chart height taken by the panel, e.g. 0.25
+    /// - Returns: The chart context of the main chart and the residual panel
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged_with_residuals<
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord,
+        R: AsRangedCoord,
+    >(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+        residual_spec: R,
+        panel_size: f64,
+    ) -> Result<
+        (
+            ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+            ChartContext<'a, DB, RangedCoord<X::CoordDescType, R::CoordDescType>>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let drawing_area = self.chart_area()?;
+        let (_, h) = drawing_area.dim_in_pixel();
+        let split = (f64::from(h) * (1.0 - panel_size.clamp(0.0, 1.0))).round() as i32;
+        let (upper, lower) = drawing_area.split_vertically(split);
+
+        let mut upper_label_area_size = self.label_area_size;
+        upper_label_area_size[LabelAreaPosition::Bottom as usize] = 0;
+        let mut lower_label_area_size = self.label_area_size;
+        lower_label_area_size[LabelAreaPosition::Top as usize] = 0;
+
+        Ok((
+            self.build_on(upper, upper_label_area_size, x_spec.clone(), y_spec)?,
+            self.build_on(lower, lower_label_area_size, x_spec, residual_spec)?,
+        ))
+    }
+
+    /// Build the chart with the marginal panels on the top and the right sides, which is the
+    /// layout of the scatter plot with the marginal histograms. The top panel shares the X axis
+    /// and the right panel shares the Y axis with the main chart, see `MarginalHistograms`.
+    /// - `x_spec`: The specification of the shared X axis
+    /// - `y_spec`: The specification of the shared Y axis
+    /// - `top_spec`: The specification of Y axis of the top panel
+    /// - `right_spec`: The specification of X axis of the right panel
+    /// - `panel_size`: The fraction of the chart height and width taken by the panels, e.g. 0.2
+    /// - Returns: The chart context of the main chart, the top panel and the right panel
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged_with_marginals<
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord + Clone,
+        T: AsRangedCoord,
+        R: AsRangedCoord,
+    >(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+        top_spec: T,
+        right_spec: R,
+        panel_size: f64,
+    ) -> Result<
+        (
+            ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+            ChartContext<'a, DB, RangedCoord<X::CoordDescType, T::CoordDescType>>,
+            ChartContext<'a, DB, RangedCoord<R::CoordDescType, Y::CoordDescType>>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let drawing_area = self.chart_area()?;
+        let (w, h) = drawing_area.dim_in_pixel();
+        let panel_size = panel_size.clamp(0.0, 1.0);
+        let split_x = (f64::from(w) * (1.0 - panel_size)).round() as i32;
+        let split_y = (f64::from(h) * panel_size).round() as i32;
+        let (upper, lower) = drawing_area.split_vertically(split_y);
+        let (top_area, _) = upper.split_horizontally(split_x);
+        let (main_area, right_area) = lower.split_horizontally(split_x);
+
+        let size = self.label_area_size;
+        let (top, bottom, left, right) = (
+            LabelAreaPosition::Top as usize,
+            LabelAreaPosition::Bottom as usize,
+            LabelAreaPosition::Left as usize,
+            LabelAreaPosition::Right as usize,
+        );
+
+        Ok((
+            self.build_on(
+                main_area,
+                [0, size[bottom], size[left], 0],
+                x_spec.clone(),
+                y_spec.clone(),
+            )?,
+            self.build_on(top_area, [size[top], 0, size[left], 0], x_spec, top_spec)?,
+            self.build_on(
+                right_area,
+                [0, size[bottom], 0, size[right]],
+                right_spec,
+                y_spec,
+            )?,
+        ))
+    }
+
+    /// Get the area of the chart with the margin and the caption applied
+    fn chart_area(&self) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
@@ -177,14 +433,29 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+        if let Some((ref title, ref style)) = self.title {
             drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        }
+
+        Ok(drawing_area)
+    }
+
+    /// Split the label areas from the chart area and create the chart context
+    #[allow(clippy::type_complexity)]
+    fn build_on<X: AsRangedCoord, Y: AsRangedCoord>(
+        &self,
+        mut drawing_area: DrawingArea<DB, Shift>,
+        label_area_size: [u32; 4],
+        x_spec: X,
+        y_spec: Y,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let mut label_areas = [None, None, None, None];
+
+        let (root_x, root_y) = self.root_area.get_base_pixel();
+        let (area_x, area_y) = drawing_area.get_base_pixel();
 
         let (w, h) = drawing_area.dim_in_pixel();
 
@@ -197,7 +468,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 continue;
             }
 
-            let size = self.label_area_size[idx] as i32;
+            let size = label_area_size[idx] as i32;
 
             let split_point = if dx + dy < 0 { size } else { -size };
 
@@ -221,8 +492,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 if h > 0 && w > 0 {
                     std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
                 }
-            } else if self.label_area_size[dst_idx] != 0 {
-                let size = self.label_area_size[dst_idx] as i32;
+            } else if label_area_size[dst_idx] != 0 {
+                let size = label_area_size[dst_idx] as i32;
                 let (dw, dh) = drawing_area.dim_in_pixel();
                 let x0 = if DIR[dst_idx].0 > 0 {
                     dw as i32 - size
@@ -245,8 +516,15 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
+        let (w, h) = drawing_area.dim_in_pixel();
+        let axis_margin = (
+            self.axis_margin[0].in_pixels(w),
+            self.axis_margin[1].in_pixels(h),
+        );
         let mut pixel_range = drawing_area.get_pixel_range();
-        pixel_range.1 = (pixel_range.1.end - 1)..(pixel_range.1.start - 1);
+        pixel_range.0 = (pixel_range.0.start + axis_margin.0)..(pixel_range.0.end - axis_margin.0);
+        pixel_range.1 =
+            (pixel_range.1.end - 1 - axis_margin.1)..(pixel_range.1.start - 1 + axis_margin.1);
 
         let mut x_label_area = [None, None];
         let mut y_label_area = [None, None];
@@ -266,9 +544,12 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             )),
             series_anno: vec![],
             drawing_area_pos: (
-                actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
-                actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
+                actual_drawing_area_pos[2] + area_x - root_x,
+                actual_drawing_area_pos[0] + area_y - root_y,
             ),
+            axis_margin,
+            clip_series: true,
+            cull_margin: Some(DEFAULT_CULL_MARGIN),
         })
     }
 }
@@ -303,6 +584,124 @@ mod test {
         assert_eq!(chart.label_area_size[3], 200);
     }
 
+    #[test]
+    fn test_build_ranged_nice() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged_nice(0.13..0.87, 3..97)
+            .expect("Create chart");
+        assert_eq!(chart.x_range(), 0.1..0.9);
+        assert_eq!(chart.y_range(), 0..100);
+    }
+
+    #[test]
+    fn test_build_ranged_with_residuals() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let (chart, panel) = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged_with_residuals(0..10, 0..100, -5..5, 0.25)
+            .expect("Create chart");
+
+        let (main_x, main_y) = (
+            chart.as_coord_spec().get_x_axis_pixel_range(),
+            chart.as_coord_spec().get_y_axis_pixel_range(),
+        );
+        let (panel_x, panel_y) = (
+            panel.as_coord_spec().get_x_axis_pixel_range(),
+            panel.as_coord_spec().get_y_axis_pixel_range(),
+        );
+
+        assert_eq!(main_x, panel_x);
+        assert_eq!(main_x, 30..200);
+        assert_eq!(main_y, 0..150);
+        assert_eq!(panel_y, 150..180);
+        assert_eq!(panel.y_range(), -5..5);
+    }
+
+    #[test]
+    fn test_build_ranged_with_marginals() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let (chart, top, right) = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged_with_marginals(0..10, 0..100, 0..5, 0..5, 0.25)
+            .expect("Create chart");
+
+        let main_x = chart.as_coord_spec().get_x_axis_pixel_range();
+        let main_y = chart.as_coord_spec().get_y_axis_pixel_range();
+
+        assert_eq!(main_x, 30..150);
+        assert_eq!(main_y, 50..180);
+        assert_eq!(top.as_coord_spec().get_x_axis_pixel_range(), main_x);
+        assert_eq!(top.as_coord_spec().get_y_axis_pixel_range(), 0..50);
+        assert_eq!(right.as_coord_spec().get_y_axis_pixel_range(), main_y);
+        assert_eq!(right.as_coord_spec().get_x_axis_pixel_range(), 150..200);
+    }
+
+    #[test]
+    fn test_build_log() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_log_log(1.0..1000.0, 1u32..100u32)
+            .expect("Create chart");
+        assert_eq!(chart.y_range(), 1..100);
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"10⁰".to_string()));
+        assert!(labels.contains(&"10²".to_string()));
+    }
+
+    #[test]
+    fn test_build_percent_stacked() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let (mut chart, shares) = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_percent_stacked(0..2, &[vec![1, 3], vec![3, 1]])
+            .expect("Create chart");
+        assert_eq!(chart.y_range(), 0.0..1.0);
+        assert_eq!(shares, vec![vec![0.25, 0.75], vec![1.0, 1.0]]);
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"20%".to_string()));
+        assert!(labels.contains(&"40%".to_string()));
+    }
+
+    #[test]
+    fn test_axis_margin() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_axis_margin(10)
+            .y_axis_margin(AxisMargin::Percent(10.0))
+            .build_ranged(0..10, 0..10)
+            .unwrap();
+
+        assert_eq!(chart.x_range(), 0..10);
+        assert_eq!(chart.as_coord_spec().get_x_axis_pixel_range(), 10..190);
+        assert_eq!(chart.as_coord_spec().get_y_axis_pixel_range(), 20..180);
+        assert_eq!(chart.axis_margin, (10, 20));
+        assert_eq!(AxisMargin::Pixels(300).in_pixels(100), 49);
+    }
+
     #[test]
     fn test_margin_configure() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -0,0 +1,271 @@
+use super::builder::ChartBuilder;
+use super::context::ChartContext;
+use crate::coord::{RangedCoord, RangedCoordf64};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{EmptyElement, Rectangle, Text};
+use crate::style::{
+    Color, IntoFont, MapperLegend, RGBAColor, ShapeStyle, StyleMapper, TextStyle, BLACK, WHITE,
+};
+
+/// The number of colors sampled for the colorbar
+const COLORBAR_STOPS: usize = 64;
+
+/// The distance between the class names and the plotting area in pixels
+const LABEL_PADDING: i32 = 5;
+
+type ConfusionChart<'a, DB> = ChartContext<'a, DB, RangedCoord<RangedCoordf64, RangedCoordf64>>;
+
+/// The value shown in the cells of the confusion matrix, which also determines the shading
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CellValue {
+    /// The number of the samples
+    Count,
+    /// The percentage of the samples of the actual class, thus each row sums up to 100%
+    Percentage,
+    /// The number of the samples followed by the percentage, the cells are shaded by the
+    /// percentage
+    Both,
+}
+
+/// The shading of the cells, which fades the color into the background by the value
+struct CellShading {
+    color: RGBAColor,
+    max: f64,
+}
+
+impl StyleMapper<f64> for CellShading {
+    fn style(&self, value: &f64) -> ShapeStyle {
+        let t = if self.max > 0.0 {
+            (value / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.color.mix(t).filled()
+    }
+
+    fn legend(&self) -> MapperLegend {
+        MapperLegend::ColorBar {
+            min: 0.0,
+            max: self.max,
+            colors: (0..COLORBAR_STOPS)
+                .map(|i| {
+                    let value = self.max * i as f64 / (COLORBAR_STOPS - 1) as f64;
+                    self.style(&value).color
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The confusion matrix of a classifier, which counts the samples by the actual class, the
+/// rows of the matrix, and the predicted class, the columns of the matrix
+#[derive(Clone, Debug)]
+pub struct ConfusionMatrix {
+    classes: Vec<String>,
+    counts: Vec<Vec<u32>>,
+}
+
+impl ConfusionMatrix {
+    /// Create an empty matrix
+    /// - `classes`: The names of the classes
+    pub fn new<S: ToString>(classes: &[S]) -> Self {
+        Self {
+            classes: classes.iter().map(ToString::to_string).collect(),
+            counts: vec![vec![0; classes.len()]; classes.len()],
+        }
+    }
+
+    /// Create the matrix from the classified samples
+    /// - `classes`: The names of the classes
+    /// - `samples`: The indices of the actual class and the predicted class of each sample
+    pub fn from_samples<S: ToString, I: IntoIterator<Item = (usize, usize)>>(
+        classes: &[S],
+        samples: I,
+    ) -> Self {
+        let mut matrix = Self::new(classes);
+        for (actual, predicted) in samples {
+            matrix.add(actual, predicted);
+        }
+        matrix
+    }
+
+    /// Count a sample, the indices out of the classes are ignored
+    /// - `actual`: The index of the actual class
+    /// - `predicted`: The index of the predicted class
+    pub fn add(&mut self, actual: usize, predicted: usize) -> &mut Self {
+        if let Some(count) = self
+            .counts
+            .get_mut(actual)
+            .and_then(|row| row.get_mut(predicted))
+        {
+            *count += 1;
+        }
+        self
+    }
+
+    /// Get the names of the classes
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Get the number of the samples of the actual class classified as the predicted class
+    /// - **returns**: The count, `None` if the indices are out of the classes
+    pub fn count(&self, actual: usize, predicted: usize) -> Option<u32> {
+        self.counts.get(actual)?.get(predicted).cloned()
+    }
+
+    /// Get the percentage of the samples of the actual class classified as the predicted class
+    /// - **returns**: The percentage, `None` if the indices are out of the classes
+    pub fn percentage(&self, actual: usize, predicted: usize) -> Option<f64> {
+        let row = self.counts.get(actual)?;
+        let count = *row.get(predicted)?;
+        let total: u32 = row.iter().sum();
+        if total == 0 {
+            return Some(0.0);
+        }
+        Some(f64::from(count) * 100.0 / f64::from(total))
+    }
+
+    fn cell_text(&self, actual: usize, predicted: usize, value: CellValue) -> String {
+        let count = self.count(actual, predicted).unwrap_or(0);
+        let percentage = self.percentage(actual, predicted).unwrap_or(0.0);
+        match value {
+            CellValue::Count => format!("{}", count),
+            CellValue::Percentage => format!("{:.1}%", percentage),
+            CellValue::Both => format!("{} ({:.1}%)", count, percentage),
+        }
+    }
+
+    /// Draw the matrix as a heatmap with the values in the cells. The actual classes are the
+    /// rows from the top and the predicted classes are the columns from the left. The class
+    /// names are drawn in the left and the bottom label areas, and the colorbar in the right
+    /// label area, each of them is omitted if the chart doesn't have the label area.
+    /// - `builder`: The builder with the label areas, the margin and the caption of the chart
+    /// - `color`: The color of the largest value, the smaller values are lighter
+    /// - `value`: The value shown in the cells
+    /// - **returns**: The chart, where each cell is a unit square
+    pub fn draw<'a, DB: DrawingBackend, C: Color>(
+        &self,
+        builder: &mut ChartBuilder<'a, '_, DB>,
+        color: &C,
+        value: CellValue,
+    ) -> Result<ConfusionChart<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let n = self.classes.len();
+        let mut chart = builder.build_ranged(0.0..n as f64, 0.0..n as f64)?;
+
+        let max_count = self.counts.iter().flatten().cloned().max().unwrap_or(0);
+        let shading = CellShading {
+            color: color.to_rgba(),
+            max: match value {
+                CellValue::Count => f64::from(max_count.max(1)),
+                _ => 100.0,
+            },
+        };
+        let text_style: TextStyle = ("sans-serif", 12).into_font().into();
+
+        for actual in 0..n {
+            for predicted in 0..n {
+                let shade = match value {
+                    CellValue::Count => f64::from(self.count(actual, predicted).unwrap_or(0)),
+                    _ => self.percentage(actual, predicted).unwrap_or(0.0),
+                };
+                let (x, y) = (predicted as f64, (n - 1 - actual) as f64);
+                let area = chart.plotting_area();
+                area.draw(&Rectangle::new(
+                    [(x, y), (x + 1.0, y + 1.0)],
+                    shading.style(&shade),
+                ))?;
+
+                let text = self.cell_text(actual, predicted, value);
+                let (w, h) = area.estimate_text_size(&text, &text_style.font)?;
+                let text_color = if shade > shading.max / 2.0 {
+                    &WHITE
+                } else {
+                    &BLACK
+                };
+                area.draw(
+                    &(EmptyElement::at((x + 0.5, y + 0.5))
+                        + Text::new(
+                            text,
+                            (-(w as i32) / 2, -(h as i32) / 2),
+                            text_style.color(text_color),
+                        )),
+                )?;
+            }
+        }
+
+        if let Some(area) = chart.x_label_area[1].as_ref() {
+            let (x0, _) = area.get_base_pixel();
+            for (predicted, class) in self.classes.iter().enumerate() {
+                let (w, _) = area.estimate_text_size(class, &text_style.font)?;
+                let cx = chart.backend_coord(&(predicted as f64 + 0.5, 0.0)).0 - x0;
+                area.draw_text(class, &text_style, (cx - w as i32 / 2, LABEL_PADDING))?;
+            }
+        }
+        if let Some(area) = chart.y_label_area[0].as_ref() {
+            let (aw, _) = area.dim_in_pixel();
+            let (_, y0) = area.get_base_pixel();
+            for (actual, class) in self.classes.iter().enumerate() {
+                let (w, h) = area.estimate_text_size(class, &text_style.font)?;
+                let cy = chart.backend_coord(&(0.0, (n - actual) as f64 - 0.5)).1 - y0;
+                area.draw_text(
+                    class,
+                    &text_style,
+                    (aw as i32 - w as i32 - LABEL_PADDING, cy - h as i32 / 2),
+                )?;
+            }
+        }
+
+        chart.draw_mapper_legend(&shading)?;
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_confusion_matrix() {
+        let matrix = ConfusionMatrix::from_samples(
+            &["cat", "dog"],
+            vec![(0, 0), (0, 0), (0, 1), (1, 1), (5, 0)],
+        );
+        assert_eq!(matrix.count(0, 0), Some(2));
+        assert_eq!(matrix.count(1, 0), Some(0));
+        assert_eq!(matrix.count(5, 0), None);
+        assert_eq!(matrix.percentage(0, 1), Some(100.0 / 3.0));
+        assert_eq!(matrix.percentage(0, 2), None);
+        assert_eq!(matrix.cell_text(0, 0, CellValue::Both), "2 (66.7%)");
+
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+            m.drop_check(|b| {
+                // 4 cells and 64 colorbar stops
+                assert_eq!(b.num_draw_rect_call, 4 + 64);
+            });
+        });
+
+        matrix
+            .draw(
+                ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(20)
+                    .y_label_area_size(30)
+                    .right_y_label_area_size(40),
+                &BLUE,
+                CellValue::Count,
+            )
+            .expect("Draw matrix");
+
+        let labels = labels.borrow();
+        for text in &["2", "1", "0", "cat", "dog"] {
+            assert!(labels.contains(&text.to_string()));
+        }
+    }
+}
@@ -1,20 +1,98 @@
+use num_traits::ToPrimitive;
 use std::borrow::Borrow;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
-use std::ops::Range;
+use std::ops::{Add, Range, Sub};
 use std::sync::Arc;
 
 use super::dual_coord::DualCoordChartContext;
-use super::mesh::MeshStyle;
-use super::series::SeriesLabelStyle;
+use super::mesh::{LabelOverflow, MeshStyle};
+use super::series::{
+    HasLegendGlyph, LegendGlyph, SeriesLabelPosition, SeriesLabelStyle, DEFAULT_MARKER_SIZE,
+};
+use super::validation::{validate_points, SeriesIssue, SeriesValidationError};
 
 use crate::coord::{
-    AsRangedCoord, CoordTranslate, MeshLine, Ranged, RangedCoord, ReverseCoordTranslate, Shift,
+    AsRangedCoord, ConvertedCoord, CoordTranslate, DiscreteRanged, MeshLine, Ranged, RangedCoord,
+    ReverseCoordTranslate, ReversibleRanged, Shift, SlotSize, SteppableRange,
 };
-use crate::drawing::backend::{BackendCoord, DrawingBackend};
+use crate::data::{auc, precision_recall_curve, roc_curve};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{Drawable, DynElement, IntoDynElement, PathElement, PointCollection};
-use crate::style::{AsRelative, FontTransform, ShapeStyle, SizeDesc, TextAlignment, TextStyle};
+use crate::element::{
+    Circle, Decoration, Drawable, DynElement, IntoDynElement, MarkerShape, PathElement,
+    PointCollection, Polygon, Rectangle, ScaleBar,
+};
+use crate::series::BubbleSize;
+use crate::style::{
+    AsRelative, Color, FontTransform, IntoFont, IntoTextStyle, MapperLegend, ShapeStyle, SizeDesc,
+    StyleMapper, TextAlignment, TextStyle, BLACK,
+};
+
+/// The default margin of the series culling in pixels, see `ChartContext::cull_margin`
+pub const DEFAULT_CULL_MARGIN: u32 = 64;
+
+/// The distance between the built-in decorations, such as the scale bar, and the border of the
+/// plotting area in pixels
+const DECORATION_MARGIN: u32 = 10;
+
+/// Draw the elements of a series on the area, skipping the elements out of the area by more than
+/// `cull_margin` and clipped to the area if `clip` is set. This is shared by all the ways of
+/// drawing a series, so they treat the plotting area in the same way.
+fn draw_series_on<DB, CT, E, R, S>(
+    area: &DrawingArea<DB, CT>,
+    clip: bool,
+    cull_margin: Option<u32>,
+    series: S,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+    for<'b> &'b E: PointCollection<'b, CT::From>,
+    E: Drawable<DB>,
+    R: Borrow<E>,
+    S: IntoIterator<Item = R>,
+{
+    let series = series.into_iter().filter(move |element| match cull_margin {
+        Some(margin) => !area.is_out_of_area(Borrow::<E>::borrow(element), margin),
+        None => true,
+    });
+    if clip {
+        return area.draw_clipped(series);
+    }
+    for element in series {
+        area.draw(element.borrow())?;
+    }
+    Ok(())
+}
+
+/// Stamp the marker at the points of a series on the area, the markers are culled and clipped
+/// in the same way as `draw_series_on` does
+fn stamp_series_on<DB, CT, E, P, I>(
+    area: &DrawingArea<DB, CT>,
+    clip: bool,
+    cull_margin: Option<u32>,
+    marker: &E,
+    points: I,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+    for<'b> &'b E: PointCollection<'b, BackendCoord>,
+    E: Drawable<DB>,
+    P: Borrow<CT::From>,
+    I: IntoIterator<Item = P>,
+{
+    let points = points.into_iter().filter(move |point| match cull_margin {
+        Some(margin) => !area.is_point_out_of_area(point.borrow(), margin),
+        None => true,
+    });
+    if clip {
+        area.stamp_repeated_clipped(marker, points)
+    } else {
+        area.stamp_repeated(marker, points)
+    }
+}
 
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
@@ -22,7 +100,9 @@ use crate::style::{AsRelative, FontTransform, ShapeStyle, SizeDesc, TextAlignmen
 #[allow(clippy::type_complexity)]
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
+    data: Vec<(String, String)>,
     draw_func: Option<Box<dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a>>,
+    stamp_legend: bool,
     phantom_data: PhantomData<DB>,
 }
 
@@ -31,16 +111,29 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.label.as_ref().map(|x| x.as_str()).unwrap_or("")
     }
 
+    pub(crate) fn get_data(&self) -> &[(String, String)] {
+        &self.data
+    }
+
     pub(crate) fn get_draw_func(
         &self,
     ) -> Option<&dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord>> {
         self.draw_func.as_ref().map(|x| x.borrow())
     }
 
+    /// Check if the legend element is created once at the origin and stamped, so that it shares
+    /// the definition with the identical markers, which is the case for the predefined glyphs and
+    /// `stamped_legend`
+    pub(crate) fn is_legend_stamped(&self) -> bool {
+        self.stamp_legend
+    }
+
     fn new() -> Self {
         Self {
             label: None,
+            data: vec![],
             draw_func: None,
+            stamp_legend: false,
             phantom_data: PhantomData,
         }
     }
@@ -52,6 +145,21 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self
     }
 
+    /// Record the data points of the series, which are exported with the series label by
+    /// `DataTable::from_chart`. The points aren't taken from the drawn elements, this is a
+    /// manual step and the data should be the same as the data passed to `draw_series`.
+    /// - `data`: The data points
+    pub fn data<X: Display, Y: Display, I: IntoIterator<Item = (X, Y)>>(
+        &mut self,
+        data: I,
+    ) -> &mut Self {
+        self.data = data
+            .into_iter()
+            .map(|(x, y)| (format!("{}", x), format!("{}", y)))
+            .collect();
+        self
+    }
+
     /// Set the legend element creator function
     /// - `func`: The function use to create the element
     /// *Note*: The creation function uses a shifted pixel-based coordinate system. And place the
@@ -61,6 +169,30 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         func: T,
     ) -> &mut Self {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.stamp_legend = false;
+        self
+    }
+
+    /// Set the legend element creator function like `legend`, but the function is called once
+    /// with the point (0,0) and the element is stamped at the legend entry, so that it shares the
+    /// definition with the identical markers of `draw_marker_series` on the backends which
+    /// support it
+    /// - `func`: The function use to create the element
+    pub fn stamped_legend<E, T>(&mut self, func: T) -> &mut Self
+    where
+        E: IntoDynElement<'a, DB, BackendCoord>,
+        T: Fn(BackendCoord) -> E + 'a,
+    {
+        self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.stamp_legend = true;
+        self
+    }
+
+    /// Use the predefined legend glyph as the legend element
+    /// - `glyph`: The legend glyph
+    pub fn legend_glyph(&mut self, glyph: LegendGlyph) -> &mut Self {
+        self.draw_func = Some(Box::new(move |p| glyph.make_element(p)));
+        self.stamp_legend = true;
         self
     }
 }
@@ -74,6 +206,12 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(super) drawing_area_pos: (i32, i32),
+    /// The padding between the plot frame and the data along the X and Y axes in pixels
+    pub(super) axis_margin: (i32, i32),
+    /// If the series are clipped to the plotting area
+    pub(super) clip_series: bool,
+    /// The margin in pixels beyond which the series elements out of the plotting area are culled
+    pub(super) cull_margin: Option<u32>,
 }
 
 /// A chart context state - This is the data that is needed to reconstruct the chart context
@@ -85,6 +223,9 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
 pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
+    axis_margin: (i32, i32),
+    clip_series: bool,
+    cull_margin: Option<u32>,
     coord: CT,
 }
 
@@ -93,6 +234,9 @@ impl<'a, CT: CoordTranslate + Clone> Clone for ChartState<CT> {
         Self {
             drawing_area_size: self.drawing_area_size,
             drawing_area_pos: self.drawing_area_pos,
+            axis_margin: self.axis_margin,
+            clip_series: self.clip_series,
+            cull_margin: self.cull_margin,
             coord: self.coord.clone(),
         }
     }
@@ -103,12 +247,34 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>>
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            axis_margin: chart.axis_margin,
+            clip_series: chart.clip_series,
+            cull_margin: chart.cull_margin,
             coord: chart.drawing_area.into_coord_spec(),
         }
     }
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Set if the series are clipped to the plotting area, which is enabled by default. The
+    /// clipping keeps the lines and the shapes crossing the border of the plotting area from
+    /// spilling over the labels, when it's disabled or unsupported by the backend, the points
+    /// out of the plotting area are moved onto its border instead.
+    pub fn clip_series(&mut self, clip: bool) -> &mut Self {
+        self.clip_series = clip;
+        self
+    }
+
+    /// Set the margin of the culling, which is `Some(DEFAULT_CULL_MARGIN)` by default. The series
+    /// element whose points are all beyond the same side of the plotting area by more than the
+    /// margin in pixels is skipped by `draw_series`, so only the visible part of a large dataset
+    /// reaches the backend when the chart is zoomed in. The margin should cover the size of the
+    /// markers and the labels drawn around the points, `None` disables the culling.
+    pub fn cull_margin(&mut self, margin: Option<u32>) -> &mut Self {
+        self.cull_margin = margin;
+        self
+    }
+
     /// Convert a chart context into a chart state, by doing so, the chart context is consumed and
     /// a saved chart state is created for later use.
     pub fn into_chart_state(self) -> ChartState<CT> {
@@ -123,6 +289,9 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         ChartState {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
+            axis_margin: self.axis_margin,
+            clip_series: self.clip_series,
+            cull_margin: self.cull_margin,
             coord: Arc::new(self.drawing_area.into_coord_spec()),
         }
     }
@@ -137,6 +306,9 @@ where
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            axis_margin: chart.axis_margin,
+            clip_series: chart.clip_series,
+            cull_margin: chart.cull_margin,
             coord: chart.drawing_area.as_coord_spec().clone(),
         }
     }
@@ -167,10 +339,153 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            axis_margin: self.axis_margin,
+            clip_series: self.clip_series,
+            cull_margin: self.cull_margin,
+        }
+    }
+}
+
+/// The direction of stepping the range of an axis, see `ChartState::step_x`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepDirection {
+    /// Toward the smaller values
+    Backward,
+    /// Toward the larger values
+    Forward,
+}
+
+impl StepDirection {
+    fn apply(self, fraction: f64) -> f64 {
+        match self {
+            StepDirection::Backward => -fraction.abs(),
+            StepDirection::Forward => fraction.abs(),
         }
     }
 }
 
+impl<X, Y> ChartState<RangedCoord<X, Y>>
+where
+    X: Ranged + From<Range<X::ValueType>>,
+    Y: Ranged + From<Range<Y::ValueType>>,
+    Range<X::ValueType>: SteppableRange,
+    Range<Y::ValueType>: SteppableRange,
+{
+    /// Move the X range by the largest nice step, i.e. 1, 2 or 5 times a power of 10, not
+    /// exceeding the fraction of the span, which is useful for navigating the chart with the
+    /// buttons. The lower end of the range is snapped to a multiple of the step.
+    /// - `direction`: The direction to move the range
+    /// - `fraction`: The fraction of the span to move, e.g. `0.1`
+    pub fn step_x(&mut self, direction: StepDirection, fraction: f64) -> &mut Self {
+        let range = self.coord.get_x_range().shift_by(direction.apply(fraction));
+        self.coord.set_x_spec(range);
+        self
+    }
+
+    /// Move the Y range by the largest nice step not exceeding the fraction of the span, see
+    /// `step_x`
+    /// - `direction`: The direction to move the range
+    /// - `fraction`: The fraction of the span to move, e.g. `0.1`
+    pub fn step_y(&mut self, direction: StepDirection, fraction: f64) -> &mut Self {
+        let range = self.coord.get_y_range().shift_by(direction.apply(fraction));
+        self.coord.set_y_spec(range);
+        self
+    }
+
+    /// Zoom both ranges around their centers in the discrete levels of the nice spans, so that
+    /// a zoom in and a zoom out return to a nice range, e.g. `0..10` is zoomed in to `2.5..7.5`
+    /// and zoomed out to `-6..14`. The ends of the ranges are snapped to the tenth of the span.
+    /// - `levels`: The number of levels to zoom in, the negative value zooms out
+    pub fn zoom_discrete(&mut self, levels: i32) -> &mut Self {
+        let x_range = self.coord.get_x_range().zoom_by(levels);
+        let y_range = self.coord.get_y_range().zoom_by(levels);
+        self.coord.set_x_spec(x_range);
+        self.coord.set_y_spec(y_range);
+        self
+    }
+}
+
+/// The position and size of a label area, relative to the root drawing area
+type LabelAreaLayout = Option<(BackendCoord, (u32, u32))>;
+
+/// An owned chart context, which doesn't borrow any drawing area or backend.
+/// Unlike the chart state, the layout of the label areas is also captured, so the chart context
+/// attached to a fresh backend is able to draw the axes and labels as well.
+///
+/// This is useful for GUI applications, which need to store the chart across frames and draw
+/// it onto a different backend for each frame.
+pub struct OwnedChartContext<CT: CoordTranslate> {
+    x_label_area: [LabelAreaLayout; 2],
+    y_label_area: [LabelAreaLayout; 2],
+    state: ChartState<CT>,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Convert the chart context into an owned chart context, which captures the coordinate spec
+    /// and the layout of the chart and can be attached to another drawing area later.
+    pub fn into_owned(self) -> OwnedChartContext<CT> {
+        let (dx, dy) = self.drawing_area.get_base_pixel();
+        let root = (dx - self.drawing_area_pos.0, dy - self.drawing_area_pos.1);
+        let layout = |area: &Option<DrawingArea<DB, Shift>>| {
+            area.as_ref().map(|area| {
+                let (x, y) = area.get_base_pixel();
+                ((x - root.0, y - root.1), area.dim_in_pixel())
+            })
+        };
+        OwnedChartContext {
+            x_label_area: [layout(&self.x_label_area[0]), layout(&self.x_label_area[1])],
+            y_label_area: [layout(&self.y_label_area[0]), layout(&self.y_label_area[1])],
+            state: self.into_chart_state(),
+        }
+    }
+}
+
+impl<CT: CoordTranslate> OwnedChartContext<CT> {
+    fn attach_impl<'a, DB: DrawingBackend>(
+        x_label_area: &[LabelAreaLayout; 2],
+        y_label_area: &[LabelAreaLayout; 2],
+        state: ChartState<CT>,
+        area: &DrawingArea<DB, Shift>,
+    ) -> ChartContext<'a, DB, CT> {
+        let restore =
+            |layout: &LabelAreaLayout| layout.map(|(pos, size)| area.clone().shrink(pos, size));
+        let mut chart = state.restore(area);
+        chart.x_label_area = [restore(&x_label_area[0]), restore(&x_label_area[1])];
+        chart.y_label_area = [restore(&y_label_area[0]), restore(&y_label_area[1])];
+        chart
+    }
+
+    /// Attach the owned chart context to the given drawing area, the owned context is consumed
+    ///
+    /// - `area`: The root drawing area the chart is attached to
+    /// - **returns** The chart context on the given drawing area
+    pub fn into_chart_context<'a, DB: DrawingBackend>(
+        self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> ChartContext<'a, DB, CT> {
+        Self::attach_impl(&self.x_label_area, &self.y_label_area, self.state, area)
+    }
+}
+
+impl<CT: CoordTranslate + Clone> OwnedChartContext<CT> {
+    /// Attach a copy of the owned chart context to the given drawing area.
+    /// This is typically called once per frame with the drawing area of the fresh backend.
+    ///
+    /// - `area`: The root drawing area the chart is attached to
+    /// - **returns** The chart context on the given drawing area
+    pub fn attach<'a, DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> ChartContext<'a, DB, CT> {
+        Self::attach_impl(
+            &self.x_label_area,
+            &self.y_label_area,
+            self.state.clone(),
+            area,
+        )
+    }
+}
+
 impl<
         'a,
         DB: DrawingBackend,
@@ -230,8 +545,13 @@ impl<
             line_style_2: None,
             x_label_style: None,
             y_label_style: None,
-            format_x: &|x| format!("{:?}", x),
-            format_y: &|y| format!("{:?}", y),
+            format_x: &|x| X::format_label(x),
+            format_y: &|y| Y::format_label(y),
+            label_cache_id: None,
+            x_label_precision: None,
+            y_label_precision: None,
+            x_label_notation: false,
+            y_label_notation: false,
             target: Some(self),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -239,6 +559,8 @@ impl<
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            x_label_overflow: LabelOverflow::Hide,
+            y_label_overflow: LabelOverflow::Hide,
         }
     }
 }
@@ -253,12 +575,151 @@ impl<'a, DB: DrawingBackend + 'a, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
+
+    /// Draw the decoration, such as the north arrow or the badge, inside of the plotting area.
+    /// The decoration is defined relative to its upper left corner, which is moved to the
+    /// position, so it follows the plotting area when the layout of the chart changes.
+    /// - `decoration`: The decoration to draw
+    /// - `position`: The position of the decoration in the plotting area
+    /// - `margin`: The distance between the decoration and the border of the plotting area
+    pub fn draw_decoration<E>(
+        &self,
+        decoration: &E,
+        position: SeriesLabelPosition,
+        margin: u32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        E: Decoration + Drawable<DB>,
+        for<'b> &'b E: PointCollection<'b, BackendCoord>,
+    {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = decoration
+            .estimate_dimension()
+            .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
+        let pos = position.layout_in_area((w, h), area.dim_in_pixel(), margin as i32);
+        area.shrink(pos, (w.max(0) as u32, h.max(0) as u32))
+            .draw(decoration)
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
     }
+
+    pub(crate) fn get_series_anno(&self) -> &[SeriesAnno<'a, DB>] {
+        &self.series_anno
+    }
+
+    pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
+        let idx = self.series_anno.len();
+        self.series_anno.push(SeriesAnno::new());
+        &mut self.series_anno[idx]
+    }
+
+    /// Draw a data series faded uniformly. The series is composited as a whole and blended onto
+    /// the chart with the opacity, so unlike the semi-transparent colors, the overlapping parts
+    /// of its elements, such as the joints of a line, don't look darker. If the backend doesn't
+    /// support the opacity groups, the series is drawn opaque.
+    /// - `series`: The series to draw
+    /// - `opacity`: The opacity of the series, from 0 to 1
+    pub fn draw_series_with_opacity<E, R, S>(
+        &mut self,
+        series: S,
+        opacity: f64,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let grouped = self.drawing_area.push_opacity(opacity)?;
+        let result = draw_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            series,
+        );
+        if grouped {
+            self.drawing_area.pop_opacity()?;
+        }
+        result?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a series of identical markers at the data points. The marker is defined in the
+    /// pixel coordinate relative to the data point, for example `Circle::new((0, 0), 3, &RED)`.
+    /// Unlike `draw_series`, the backends which support it render the marker only once, which
+    /// is much faster for large scatter plots and keeps the SVG output small.
+    pub fn draw_marker_series<E, P, I>(
+        &mut self,
+        marker: &E,
+        points: I,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, BackendCoord>,
+        E: Drawable<DB>,
+        P: Borrow<CT::From>,
+        I: IntoIterator<Item = P>,
+    {
+        stamp_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            marker,
+            points,
+        )?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series whose values are converted by the function at the drawing time, for
+    /// example from radians to degrees or from bytes to megabytes. Neither the axes nor the data
+    /// are changed, the values are converted point by point while the elements are drawn.
+    /// - `convert`: The function that converts the values of the series to the chart coordinate
+    /// - `series`: The data series
+    pub fn draw_converted_series<V, F, E, R, S>(
+        &mut self,
+        convert: F,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        F: Fn(&V) -> CT::From,
+        for<'b> &'b E: PointCollection<'b, V>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        {
+            let coord = ConvertedCoord::new(self.drawing_area.as_coord_spec(), convert);
+            let area = self.drawing_area.strip_coord_spec().apply_coord_spec(coord);
+            draw_series_on(&area, self.clip_series, self.cull_margin, series)?;
+        }
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series and derive the legend glyph from the series style, so that
+    /// only the label is needed for the series label area. The legend glyph can still be
+    /// overridden with `SeriesAnno::legend`.
+    pub fn draw_series_with_legend<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, CT::From>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R> + HasLegendGlyph,
+    {
+        let glyph = series.legend_glyph();
+        draw_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            series,
+        )?;
+        Ok(self.alloc_series_anno().legend_glyph(glyph))
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -280,16 +741,12 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Arc<Rang
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
-        }
-        Ok(())
-    }
-
-    pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
-        let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
-        &mut self.series_anno[idx]
+        draw_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            series,
+        )
     }
 
     /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements
@@ -308,23 +765,349 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Arc<Rang
     }
 }
 
+impl<'a, DB: DrawingBackend, X: DiscreteRanged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
+    /// Get the pixel size of a single slot on the discrete X axis, which can be used to
+    /// size and offset the elements relative to the category slot
+    pub fn x_slot_size(&self) -> SlotSize {
+        self.drawing_area.as_coord_spec().x_slot_size()
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: DiscreteRanged> ChartContext<'a, DB, RangedCoord<X, Y>> {
+    /// Get the pixel size of a single slot on the discrete Y axis
+    pub fn y_slot_size(&self) -> SlotSize {
+        self.drawing_area.as_coord_spec().y_slot_size()
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: ReversibleRanged, Y: Ranged>
+    ChartContext<'a, DB, RangedCoord<X, Y>>
+{
+    /// Get the length of the span on the X axis in the data space, which is the given number of
+    /// pixels long on the screen. This is the inverse of `x_length_in_pixels`.
+    ///
+    /// - `at`: The value where the span starts
+    /// - `pixels`: The length of the span in pixels, positive for the span going to the right
+    /// - **returns**: The length in the data space, `None` if the span is out of the axis
+    pub fn x_pixels_to_length<L>(&self, at: &X::ValueType, pixels: i32) -> Option<L>
+    where
+        X::ValueType: Sub<Output = L>,
+    {
+        let coord = self.drawing_area.as_coord_spec();
+        let start = coord.x_pixel(at);
+        Some(coord.x_value_at(start + pixels)? - coord.x_value_at(start)?)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: ReversibleRanged>
+    ChartContext<'a, DB, RangedCoord<X, Y>>
+{
+    /// Get the length of the span on the Y axis in the data space, which is the given number of
+    /// pixels long on the screen. This is the inverse of `y_length_in_pixels`.
+    ///
+    /// - `at`: The value where the span starts
+    /// - `pixels`: The length of the span in pixels, positive for the span going up
+    /// - **returns**: The length in the data space, `None` if the span is out of the axis
+    pub fn y_pixels_to_length<L>(&self, at: &Y::ValueType, pixels: i32) -> Option<L>
+    where
+        Y::ValueType: Sub<Output = L>,
+    {
+        let coord = self.drawing_area.as_coord_spec();
+        let start = coord.y_pixel(at);
+        Some(coord.y_value_at(start - pixels)? - coord.y_value_at(start)?)
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    DB: DrawingBackend + 'a,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged,
+{
+    /// Draw the scale bar inside of the plotting area, which represents a round length on the X
+    /// axis and takes at most a quarter of the plotting area width. The X axis should be linear,
+    /// since the scale of a nonlinear axis varies along the axis.
+    /// - `unit`: The unit of the X axis, e.g. `"m"`, see `ScaleBar::new`
+    /// - `position`: The position of the scale bar in the plotting area
+    /// - `style`: The style of the bar, the label uses the same color
+    pub fn draw_scale_bar<S: Into<ShapeStyle>>(
+        &self,
+        unit: &str,
+        position: SeriesLabelPosition,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let range = self.x_range();
+        let span = range.end - range.start;
+        let pixels_per_unit = f64::from(self.x_length_in_pixels(&range.start, span)) / span;
+
+        let (w, _) = self.drawing_area.dim_in_pixel();
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+        let bar = ScaleBar::new((0, 0), pixels_per_unit, w / 4, unit, style, font);
+        self.draw_decoration(&bar, position, DECORATION_MARGIN)
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the receiver operating characteristic (ROC) curve of a binary classifier with the
+    /// diagonal of the random classifier, the shaded area under the curve and the AUC value in
+    /// the lower right corner. The chart is usually built on `0.0..1.0` for both axes.
+    /// - `data`: The samples, each of them is the score and if the sample is actually positive
+    /// - `style`: The style of the curve, the shaded area uses the same color
+    /// - `label_style`: The text style of the AUC value
+    pub fn draw_roc_curve<'b, S: Into<ShapeStyle>, T: IntoTextStyle<'b>>(
+        &mut self,
+        data: &[(f64, bool)],
+        style: S,
+        label_style: T,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let curve = roc_curve(data.iter().cloned());
+        let label_style = label_style.into_text_style(&self.drawing_area.dim_in_pixel());
+        self.draw_evaluation_curve(curve, [(0.0, 0.0), (1.0, 1.0)], style.into(), &label_style)
+    }
+
+    /// Draw the precision-recall curve of a binary classifier with the precision of the random
+    /// classifier, which is the fraction of the positive samples, the shaded area under the
+    /// curve and the AUC value in the lower right corner
+    /// - `data`: The samples, each of them is the score and if the sample is actually positive
+    /// - `style`: The style of the curve, the shaded area uses the same color
+    /// - `label_style`: The text style of the AUC value
+    pub fn draw_precision_recall_curve<'b, S: Into<ShapeStyle>, T: IntoTextStyle<'b>>(
+        &mut self,
+        data: &[(f64, bool)],
+        style: S,
+        label_style: T,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let positives = data.iter().filter(|(_, label)| *label).count();
+        let baseline = if data.is_empty() {
+            0.0
+        } else {
+            positives as f64 / data.len() as f64
+        };
+        let curve = precision_recall_curve(data.iter().cloned());
+        let label_style = label_style.into_text_style(&self.drawing_area.dim_in_pixel());
+        self.draw_evaluation_curve(
+            curve,
+            [(0.0, baseline), (1.0, baseline)],
+            style.into(),
+            &label_style,
+        )
+    }
+
+    fn draw_evaluation_curve(
+        &mut self,
+        curve: Vec<(f64, f64)>,
+        reference: [(f64, f64); 2],
+        style: ShapeStyle,
+        label_style: &TextStyle,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        if let (Some(first), Some(last)) = (curve.first(), curve.last()) {
+            let mut area = curve.clone();
+            area.push((last.0, 0.0));
+            area.push((first.0, 0.0));
+            draw_series_on(
+                &self.drawing_area,
+                self.clip_series,
+                self.cull_margin,
+                std::iter::once(Polygon::new(area, style.color.mix(0.2).filled())),
+            )?;
+        }
+        draw_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            std::iter::once(PathElement::new(
+                reference.to_vec(),
+                BLACK.mix(0.4).stroke_width(1),
+            )),
+        )?;
+
+        if !curve.is_empty() {
+            let area = self.drawing_area.strip_coord_spec();
+            let (w, h) = area.dim_in_pixel();
+            let text = format!("AUC = {:.3}", auc(&curve));
+            let (tw, th) = area.estimate_text_size(&text, &label_style.font)?;
+            area.draw_text(
+                &text,
+                label_style,
+                (w as i32 - tw as i32 - 5, h as i32 - th as i32 - 5),
+            )?;
+        }
+
+        self.draw_series(std::iter::once(PathElement::new(curve, style)))
+    }
+}
+
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
     /// Get the range of X axis
     pub fn x_range(&self) -> Range<X::ValueType> {
         self.drawing_area.get_x_range()
     }
 
+    /// Pin the X value, typically the origin, to the given backend pixel column, so that the
+    /// charts with different ranges can share it. See `RangedCoord::pin_x_origin` for details.
+    pub fn pin_x_origin(&mut self, value: &X::ValueType, pixel: i32) -> bool {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .pin_x_origin(value, pixel)
+    }
+
+    /// Pin the Y value, typically the origin, to the given backend pixel row, so that the
+    /// charts with different ranges can share it. See `RangedCoord::pin_y_origin` for details.
+    pub fn pin_y_origin(&mut self, value: &Y::ValueType, pixel: i32) -> bool {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .pin_y_origin(value, pixel)
+    }
+
+    /// Drop the cached mesh key points and tick labels, see `MeshStyle::cache_labels`
+    pub fn clear_mesh_cache(&self) {
+        self.drawing_area.as_coord_spec().clear_mesh_cache()
+    }
+
+    /// Get the key points of X axis, where the mesh lines and the tick labels are drawn with
+    /// at most `max_points` labels, e.g. 10 as the default of `MeshStyle::x_labels`
+    pub fn x_key_points(&self, max_points: usize) -> Vec<X::ValueType>
+    where
+        X::ValueType: Clone,
+    {
+        self.drawing_area.as_coord_spec().x_key_points(max_points)
+    }
+
+    /// Get the key points of Y axis, see `x_key_points`
+    pub fn y_key_points(&self, max_points: usize) -> Vec<Y::ValueType>
+    where
+        Y::ValueType: Clone,
+    {
+        self.drawing_area.as_coord_spec().y_key_points(max_points)
+    }
+
+    /// Replace the key points of X axis before drawing the mesh, when the computed ones don't
+    /// suit the figure. See `RangedCoord::set_x_key_points` for details.
+    pub fn set_x_key_points(&mut self, points: Vec<X::ValueType>) {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_x_key_points(Some(points))
+    }
+
+    /// Replace the key points of Y axis before drawing the mesh, see `set_x_key_points`
+    pub fn set_y_key_points(&mut self, points: Vec<Y::ValueType>) {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_y_key_points(Some(points))
+    }
+
+    /// Restore the key points computed by the axes
+    pub fn reset_key_points(&mut self) {
+        let coord = self.drawing_area.as_coord_spec_mut();
+        coord.set_x_key_points(None);
+        coord.set_y_key_points(None);
+    }
+
     /// Get range of the Y axis
     pub fn y_range(&self) -> Range<Y::ValueType> {
         self.drawing_area.get_y_range()
     }
 
+    /// Check the series data before drawing it, which is useful when the data comes from an
+    /// untrusted source. The NaN and infinite values, the values out of the range of the axes
+    /// and the empty series are reported, instead of drawn as a broken chart.
+    /// - `data`: The points of the series
+    /// - **returns**: The error with all the problems found, if there's any
+    pub fn validate_series<'b, I>(&self, data: I) -> Result<(), SeriesValidationError>
+    where
+        I: IntoIterator<Item = &'b (X::ValueType, Y::ValueType)>,
+        X::ValueType: ToPrimitive + 'b,
+        Y::ValueType: ToPrimitive + 'b,
+    {
+        let points = data.into_iter().map(|(x, y)| (x, y));
+        let issues = validate_points(points, &self.x_range(), &self.y_range());
+        if issues.is_empty() {
+            return Ok(());
+        }
+        Err(SeriesValidationError { issues })
+    }
+
+    /// Check the series data given as the separate columns of the X and Y values, which is
+    /// the same as `validate_series` except that the columns of different lengths are reported
+    /// - `xs`: The X values
+    /// - `ys`: The Y values
+    /// - **returns**: The error with all the problems found, if there's any
+    pub fn validate_columns(
+        &self,
+        xs: &[X::ValueType],
+        ys: &[Y::ValueType],
+    ) -> Result<(), SeriesValidationError>
+    where
+        X::ValueType: ToPrimitive,
+        Y::ValueType: ToPrimitive,
+    {
+        let mut issues = vec![];
+        if xs.len() != ys.len() {
+            issues.push(SeriesIssue::LengthMismatch {
+                x_len: xs.len(),
+                y_len: ys.len(),
+            });
+        }
+        issues.extend(validate_points(
+            xs.iter().zip(ys.iter()),
+            &self.x_range(),
+            &self.y_range(),
+        ));
+        if issues.is_empty() {
+            return Ok(());
+        }
+        Err(SeriesValidationError { issues })
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
 
+    /// Get the length of the data span on the X axis in pixels. The length in pixels depends on
+    /// the position on the nonlinear axes, such as the logarithmic ones, so the span starts at
+    /// the given value. The result is positive if the end of the span is on the right.
+    ///
+    /// - `at`: The value where the span starts
+    /// - `length`: The length of the span in the data space, e.g. `1.5` or a `Duration`
+    pub fn x_length_in_pixels<L>(&self, at: &X::ValueType, length: L) -> i32
+    where
+        X::ValueType: Add<L, Output = X::ValueType> + Clone,
+    {
+        let coord = self.drawing_area.as_coord_spec();
+        coord.x_pixel(&(at.clone() + length)) - coord.x_pixel(at)
+    }
+
+    /// Get the length of the data span on the Y axis in pixels, see `x_length_in_pixels`. The
+    /// result is positive if the end of the span is above the start.
+    ///
+    /// - `at`: The value where the span starts
+    /// - `length`: The length of the span in the data space
+    pub fn y_length_in_pixels<L>(&self, at: &Y::ValueType, length: L) -> i32
+    where
+        Y::ValueType: Add<L, Output = Y::ValueType> + Clone,
+    {
+        let coord = self.drawing_area.as_coord_spec();
+        coord.y_pixel(at) - coord.y_pixel(&(at.clone() + length))
+    }
+
+    /// Get the distance between two data points on the screen in pixels
+    pub fn distance_in_pixels(
+        &self,
+        from: &(X::ValueType, Y::ValueType),
+        to: &(X::ValueType, Y::ValueType),
+    ) -> f64 {
+        let (a, b) = (self.backend_coord(from), self.backend_coord(to));
+        f64::from(b.0 - a.0).hypot(f64::from(b.1 - a.1))
+    }
+
     pub(super) fn draw_series_impl<E, R, S>(
         &mut self,
         series: S,
@@ -335,16 +1118,12 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
-        }
-        Ok(())
-    }
-
-    pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
-        let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
-        &mut self.series_anno[idx]
+        draw_series_on(
+            &self.drawing_area,
+            self.clip_series,
+            self.cull_margin,
+            series,
+        )
     }
 
     /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements
@@ -362,6 +1141,84 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         Ok(self.alloc_series_anno())
     }
 
+    /// Draw the legend of a style mapper. The discrete swatches are added as the series labels,
+    /// which are drawn by `configure_series_labels` as usual. The colorbar is drawn in the right
+    /// Y label area, which should be reserved with `right_y_label_area_size`, and it's omitted
+    /// if the chart doesn't have one.
+    /// - `mapper`: The style mapper used by the series
+    pub fn draw_mapper_legend<T, M: StyleMapper<T>>(
+        &mut self,
+        mapper: &M,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        match mapper.legend() {
+            MapperLegend::None => {}
+            MapperLegend::Swatches(swatches) => {
+                for (label, style) in swatches {
+                    self.alloc_series_anno()
+                        .label(label)
+                        .legend_glyph(LegendGlyph::Marker(
+                            style,
+                            MarkerShape::Circle,
+                            DEFAULT_MARKER_SIZE,
+                        ));
+                }
+            }
+            MapperLegend::ColorBar { min, max, colors } => {
+                let area = match self.y_label_area[1].as_ref() {
+                    Some(area) if !colors.is_empty() => area,
+                    _ => return Ok(()),
+                };
+                let (w, h) = area.dim_in_pixel();
+                let (h, n) = (h as i32, colors.len() as i32);
+                let bar_width = (w as i32 / 4).clamp(1, 15);
+                // The largest value is on the top, just like the Y axis
+                for (i, color) in (0..n).zip(colors.iter().rev()) {
+                    area.draw(&Rectangle::new(
+                        [(5, h * i / n), (5 + bar_width, h * (i + 1) / n)],
+                        color.filled(),
+                    ))?;
+                }
+                let style: TextStyle = ("sans-serif", 12).into_font().into();
+                area.draw_text(&format!("{}", max), &style, (bar_width + 10, 0))?;
+                area.draw_text(&format!("{}", min), &style, (bar_width + 10, h - 12))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the legend of the bubble sizes, which is the nested reference bubbles of the round
+    /// values given by `BubbleSize::reference_values`. Like the colorbar of the style mappers,
+    /// it's drawn in the right Y label area and it's omitted if the chart doesn't have one.
+    /// - `size`: The mapping from the values to the radius used by the bubble series
+    /// - `style`: The style of the reference bubbles, which are always drawn as outlines
+    pub fn draw_bubble_legend<S: Into<ShapeStyle>>(
+        &mut self,
+        size: &BubbleSize,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = match self.y_label_area[1].as_ref() {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+        let mut style = style.into();
+        style.filled = false;
+        let values = size.reference_values();
+        let max_radius = values.first().map_or(0, |v| size.radius(*v) as i32);
+        // The bubbles share the bottom point, the labels are next to their tops
+        let (cx, bottom) = (max_radius + 5, max_radius * 2 + 5);
+        let text_style: TextStyle = ("sans-serif", 12).into_font().into();
+        for value in values {
+            let radius = size.radius(value) as i32;
+            area.draw(&Circle::new((cx, bottom - radius), radius, style.clone()))?;
+            area.draw_text(
+                &format!("{}", value),
+                &text_style,
+                (cx + max_radius + 5, bottom - radius * 2 - 6),
+            )?;
+        }
+        Ok(())
+    }
+
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
@@ -370,26 +1227,29 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         (r, c): (usize, usize),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
-        mut fmt_label: FmtLabel,
+        formatter_id: Option<u64>,
+        fmt_label: FmtLabel,
     ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
-        self.drawing_area.draw_mesh(
-            |b, l| {
+        self.drawing_area.draw_mesh_with_labels(
+            |b, l, label| {
                 let draw;
                 match l {
                     MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
-                            x_labels.push((x, label_text));
+                        if let Some(label_text) = label {
+                            x_labels.push((x, label_text.to_string()));
                         }
                         draw = x_mesh;
                     }
                     MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
-                            y_labels.push((y, label_text));
+                        if let Some(label_text) = label {
+                            y_labels.push((y, label_text.to_string()));
                         }
                         draw = y_mesh;
                     }
@@ -402,6 +1262,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             },
             r,
             c,
+            formatter_id,
+            fmt_label,
         )?;
         Ok((x_labels, y_labels))
     }
@@ -457,12 +1319,13 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 }
             }
 
+            // The axis spans the whole frame, including the padding of the data
             if orientation.0 == 0 {
-                x0 = axis_range.start;
-                x1 = axis_range.end;
+                x0 = axis_range.start - self.axis_margin.0;
+                x1 = axis_range.end + self.axis_margin.0;
             } else {
-                y0 = axis_range.start;
-                y1 = axis_range.end;
+                y0 = axis_range.start - self.axis_margin.1;
+                y1 = axis_range.end + self.axis_margin.1;
             }
 
             area.draw(&PathElement::new(
@@ -474,21 +1337,47 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         Ok(axis_range)
     }
 
-    fn estimate_right_aligned_label_offset(
+    /// Estimate the sizes of the labels once, so that the layout of all the label areas
+    /// reuses them. When the labels are cached, their sizes are cached by the coordinate as
+    /// well, and the following draws don't estimate them again.
+    fn estimate_label_sizes(
         &self,
         label_style: &TextStyle,
         labels: &[(i32, String)],
-    ) -> i32 {
+        cached: bool,
+    ) -> Vec<(u32, u32)> {
+        let font = &label_style.font;
+        let transform = match font.get_transform() {
+            FontTransform::None => 0,
+            FontTransform::Rotate90 => 1,
+            FontTransform::Rotate180 => 2,
+            FontTransform::Rotate270 => 3,
+            FontTransform::Vertical => 4,
+        };
+        let font_key = format!(
+            "{} {} {} {}",
+            font.get_name(),
+            font.get_style().as_str(),
+            font.get_size(),
+            transform
+        );
+        let estimate = |t: &str| self.drawing_area.estimate_text_size(t, font);
         labels
             .iter()
             .map(|(_, t)| {
-                self.drawing_area
-                    .estimate_text_size(t, &label_style.font)
-                    .unwrap_or((0, 0))
-                    .0
+                if cached {
+                    let coord = self.drawing_area.as_coord_spec();
+                    coord.label_size(&font_key, t, || estimate(t))
+                } else {
+                    estimate(t)
+                }
+                .unwrap_or((0, 0))
             })
-            .max()
-            .unwrap_or(0) as i32
+            .collect()
+    }
+
+    fn estimate_right_aligned_label_offset(label_sizes: &[(u32, u32)]) -> i32 {
+        label_sizes.iter().map(|(w, _)| *w).max().unwrap_or(0) as i32
     }
 
     // TODO: consider make this function less complicated
@@ -499,11 +1388,13 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
         labels: &[(i32, String)],
+        label_sizes: &[(u32, u32)],
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
         tick_size: i32,
+        overflow: LabelOverflow,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -533,13 +1424,13 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
          * layout, thus in this case we need to estimate the right most position when all
          * the labels are right aligned */
         let right_alignment = if orientation.0 > 0 && orientation.1 == 0 {
-            self.estimate_right_aligned_label_offset(label_style, labels)
+            Self::estimate_right_aligned_label_offset(label_sizes)
         } else {
             0
         };
 
         /* Then we need to draw the tick mark and the label */
-        for (p, t) in labels {
+        for ((p, t), &(w, h)) in labels.iter().zip(label_sizes) {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -549,12 +1440,6 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 continue;
             }
 
-            /* Then we need to estimate the text if rendered */
-            let (w, h) = self
-                .drawing_area
-                .estimate_text_size(&t, &label_style.font)
-                .unwrap_or((0, 0));
-
             let (cx, cy) = if tick_size >= 0 {
                 match orientation {
                     // Right
@@ -585,19 +1470,28 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 }
             };
 
-            let should_draw = if orientation.0 == 0 {
+            let fits = if orientation.0 == 0 {
                 cx >= 0 && cx + label_offset + w as i32 / 2 <= tw as i32
             } else {
                 cy >= 0 && cy + label_offset + h as i32 / 2 <= th as i32
             };
 
-            if should_draw {
-                let (text_x, text_y) = if orientation.0 == 0 {
+            if fits || overflow != LabelOverflow::Hide {
+                let (mut text_x, mut text_y) = if orientation.0 == 0 {
                     (cx - w as i32 / 2 + label_offset, cy)
                 } else {
                     (cx, cy - h as i32 / 2 + label_offset)
                 };
 
+                /* Only the label moves, the tick mark stays at the key point */
+                if overflow == LabelOverflow::ShiftInward {
+                    if orientation.0 == 0 {
+                        text_x = text_x.min(tw as i32 - w as i32).max(0);
+                    } else {
+                        text_y = text_y.min(th as i32 - h as i32).max(0);
+                    }
+                }
+
                 area.draw_text(&t, label_style, (text_x, text_y))?;
 
                 if let Some(style) = axis_style {
@@ -635,7 +1529,11 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         }
 
         if let Some((text, style)) = axis_desc {
-            let actual_style = if orientation.0 == 0 {
+            let vertical = style.font.get_transform() == FontTransform::Vertical;
+            /* The vertical writing mode is kept for the Y axis, the X axis is always horizontal */
+            let actual_style = if orientation.0 == 0 && vertical {
+                style.transform(FontTransform::None)
+            } else if orientation.0 == 0 || vertical {
                 style.clone()
             } else if orientation.0 == -1 {
                 style.transform(FontTransform::Rotate270)
@@ -669,6 +1567,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
+        formatter_id: Option<u64>,
         fmt_label: FmtLabel,
         x_mesh: bool,
         y_mesh: bool,
@@ -682,34 +1581,50 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         y_desc: Option<String>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        (x_overflow, y_overflow): (LabelOverflow, LabelOverflow),
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (x_labels, y_labels) = self.draw_mesh_lines(
+            (r, c),
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            formatter_id,
+            fmt_label,
+        )?;
+
+        let cached = formatter_id.is_some();
+        let x_label_sizes = self.estimate_label_sizes(x_label_style, &x_labels, cached);
+        let y_label_sizes = self.estimate_label_sizes(y_label_style, &y_labels, cached);
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
                 &x_labels[..],
+                &x_label_sizes[..],
                 x_label_style,
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 x_tick_size[idx],
+                x_overflow,
             )?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
                 &y_labels[..],
+                &y_label_sizes[..],
                 y_label_style,
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 y_tick_size[idx],
+                y_overflow,
             )?;
         }
 
@@ -732,17 +1647,220 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         RangedCoord<X, Y>,
         RangedCoord<SX::CoordDescType, SY::CoordDescType>,
     > {
+        let (mx, my) = self.axis_margin;
         let mut pixel_range = self.drawing_area.get_pixel_range();
-        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        pixel_range.0 = (pixel_range.0.start + mx)..(pixel_range.0.end - mx);
+        pixel_range.1 = (pixel_range.1.end - my)..(pixel_range.1.start + my);
 
         DualCoordChartContext::new(self, RangedCoord::new(x_coord, y_coord, pixel_range))
     }
+
+    /// Create a chart with the independent coordinate specs overlaid on the plotting area of
+    /// this chart, e.g. the wind barbs on the pressure contours. Different from the secondary
+    /// coordinate, both charts are complete chart contexts: each of them has its own mesh and
+    /// legend, and the elements are stacked in the order they are drawn, so the z-order is
+    /// controlled by the drawing order of the charts. The overlay shares the top and the right
+    /// label areas, where its axes and labels are drawn.
+    ///
+    /// - `x_coord`: The coordinate spec for the X axis of the overlay
+    /// - `y_coord`: The coordinate spec for the Y axis of the overlay
+    /// - **returns** The overlay chart context
+    pub fn overlay<SX: AsRangedCoord, SY: AsRangedCoord>(
+        &self,
+        x_coord: SX,
+        y_coord: SY,
+    ) -> ChartContext<'a, DB, RangedCoord<SX::CoordDescType, SY::CoordDescType>> {
+        let (mx, my) = self.axis_margin;
+        let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.0 = (pixel_range.0.start + mx)..(pixel_range.0.end - mx);
+        pixel_range.1 = (pixel_range.1.end - 1 - my)..(pixel_range.1.start - 1 + my);
+
+        ChartContext {
+            x_label_area: [self.x_label_area[0].clone(), None],
+            y_label_area: [None, self.y_label_area[1].clone()],
+            drawing_area: self
+                .drawing_area
+                .strip_coord_spec()
+                .apply_coord_spec(RangedCoord::new(x_coord, y_coord, pixel_range)),
+            series_anno: vec![],
+            drawing_area_pos: self.drawing_area_pos,
+            axis_margin: self.axis_margin,
+            clip_series: self.clip_series,
+            cull_margin: self.cull_margin,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::chart::{SeriesIssue, StepDirection, ValueAxis};
     use crate::prelude::*;
 
+    #[test]
+    fn test_overlay() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .set_label_area_size(LabelAreaPosition::Top, 20)
+            .set_label_area_size(LabelAreaPosition::Right, 20)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let mut overlay = chart.overlay(-1.0..1.0, 100.0..200.0);
+        assert_eq!(
+            chart.backend_coord(&(5, 5)),
+            overlay.backend_coord(&(0.0, 150.0))
+        );
+        assert!(overlay.x_label_area[1].is_none() && overlay.y_label_area[0].is_none());
+
+        overlay
+            .configure_mesh()
+            .x_desc("U")
+            .y_desc("V")
+            .draw()
+            .expect("Draw overlay mesh");
+        overlay
+            .draw_series(std::iter::once(Circle::new((0.0, 150.0), 5, &BLUE)))
+            .expect("Draw overlay series");
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+            .expect("Draw series");
+    }
+
+    #[test]
+    fn test_cull_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2 + 2 + 101);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        let circles = || (-50..=50).map(|x| Circle::new((f64::from(x) * 10.0, 5.0), 3, &RED));
+        chart.draw_series(circles()).expect("Draw series");
+        chart
+            .draw_marker_series(
+                &Circle::new((0, 0), 3, &RED),
+                (-50..=50).map(|x| (f64::from(x) * 10.0, 5.0)),
+            )
+            .expect("Draw series");
+        chart
+            .draw_series(vec![
+                PathElement::new(vec![(-100.0, 5.0), (100.0, 5.0)], &RED),
+                PathElement::new(vec![(-100.0, 5.0), (-200.0, 50.0)], &RED),
+            ])
+            .expect("Draw series");
+        chart
+            .cull_margin(None)
+            .draw_series(circles())
+            .expect("Draw series");
+    }
+
+    #[test]
+    fn test_length_conversion() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        assert_eq!(chart.x_length_in_pixels(&2.0, 1.5), 15);
+        assert_eq!(chart.x_length_in_pixels(&2.0, -1.5), -15);
+        assert_eq!(chart.y_length_in_pixels(&2.0, 1.5), 15);
+        assert_eq!(chart.distance_in_pixels(&(0.0, 0.0), &(3.0, 4.0)), 50.0);
+
+        let dx: f64 = chart.x_pixels_to_length(&2.0, 15).unwrap();
+        let dy: f64 = chart.y_pixels_to_length(&2.0, 15).unwrap();
+        assert!((dx - 1.5).abs() < 1e-6);
+        assert!((dy - 1.5).abs() < 1e-6);
+        assert!(chart.x_pixels_to_length::<f64>(&2.0, 1000).is_none());
+    }
+
+    #[test]
+    fn test_draw_scale_bar() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path[2].0 - path[1].0, 40);
+                assert!(path[2].0 <= 190 && path[2].0 > 150);
+                assert!(path[2].1 <= 190 && path[2].1 > 150);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        chart
+            .draw_scale_bar("m", SeriesLabelPosition::LowerRight, &BLACK)
+            .expect("Draw scale bar");
+    }
+
+    #[test]
+    fn test_draw_decoration() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, upper_left, _| {
+                assert_eq!(upper_left, (25, 5));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .y_label_area_size(20)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        chart
+            .draw_decoration(
+                &Badge::new((0, 0), "(c) Plotters", ("sans-serif", 12)),
+                SeriesLabelPosition::UpperLeft,
+                5,
+            )
+            .expect("Draw decoration");
+    }
+
+    #[test]
+    fn test_validate_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        assert!(chart.validate_series(&[(1.0, 2.0), (3.0, 4.0)]).is_ok());
+        let err = chart
+            .validate_series(&[(1.0, 2.0), (f64::NAN, 40.0)])
+            .unwrap_err();
+        assert_eq!(
+            err.issues,
+            vec![
+                SeriesIssue::NotANumber {
+                    index: 1,
+                    axis: ValueAxis::X
+                },
+                SeriesIssue::OutOfRange {
+                    index: 1,
+                    axis: ValueAxis::Y
+                },
+            ]
+        );
+
+        let err = chart
+            .validate_columns(&[1.0, 2.0, 3.0], &[1.0, 2.0])
+            .unwrap_err();
+        assert_eq!(
+            err.issues,
+            vec![SeriesIssue::LengthMismatch { x_len: 3, y_len: 2 }]
+        );
+        assert!(err.to_string().contains("3 X values but 2 Y values"));
+    }
+
     #[test]
     fn test_chart_context() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -787,4 +1905,499 @@ mod test {
             .draw()
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_label_precision() {
+        assert_eq!(LabelPrecision::Auto.decimals(&[0.0, 0.25, 0.5, 0.75]), 2);
+        assert_eq!(
+            LabelPrecision::Auto.decimals(&[0.1, 0.2, 0.30000000000000004]),
+            1
+        );
+        assert_eq!(LabelPrecision::Auto.decimals(&[100.0, 200.0]), 0);
+        assert_eq!(LabelPrecision::Fixed(3).decimals(&[1.0, 2.0]), 3);
+
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(40)
+            .build_ranged(0.0..1.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .x_labels(5)
+            .y_labels(5)
+            .x_label_precision(LabelPrecision::Auto)
+            .y_label_precision(LabelPrecision::Fixed(3))
+            .draw()
+            .expect("Draw mesh");
+
+        assert!(labels.borrow().contains(&"0.4".to_string()));
+        assert!(labels.borrow().contains(&"4.000".to_string()));
+        assert!(!labels.borrow().contains(&"-0.0".to_string()));
+
+        // The precision follows the replaced key points
+        labels.borrow_mut().clear();
+        chart.set_x_key_points(vec![0.125, 0.5]);
+        chart
+            .configure_mesh()
+            .x_label_precision(LabelPrecision::Auto)
+            .draw()
+            .expect("Draw mesh");
+        assert!(labels.borrow().contains(&"0.125".to_string()));
+        assert!(labels.borrow().contains(&"0.500".to_string()));
+    }
+
+    #[test]
+    fn test_mesh_style_sheet() {
+        let lines = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let paths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let (drawn_lines, drawn_paths) = (lines.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |c, _, _, _| drawn_lines.borrow_mut().push(c.rgb()));
+            m.check_draw_path(move |c, w, _| drawn_paths.borrow_mut().push((c.rgb(), w)));
+            m.check_draw_text(|c, _, size, _, _| {
+                assert_eq!(c.rgb(), (0, 255, 0));
+                assert_eq!(size, 20.0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let sheet = MeshStyleSheet::from_theme(&Theme::light()).merge(&MeshStyleSheet {
+            axis: Some(RED.stroke_width(3)),
+            bold_grid: Some(BLUE.stroke_width(1)),
+            label_font: Some(("serif", 20).into_font().color(&GREEN)),
+            ..MeshStyleSheet::new()
+        });
+        chart
+            .configure_mesh()
+            .set_style_sheet(&sheet)
+            .draw()
+            .expect("Draw mesh");
+
+        let lines = lines.borrow();
+        assert!(lines.contains(&(0, 0, 255)));
+        assert!(lines.contains(&(204, 204, 204)));
+        assert!(paths.borrow().iter().all(|path| *path == ((255, 0, 0), 3)));
+    }
+
+    #[test]
+    fn test_label_offset_notation() {
+        let notation = AxisNotation::detect(&[4200000.1, 4200000.5, 4200000.9]);
+        assert_eq!((notation.offset, notation.exponent), (4200000.0, 0));
+        assert_eq!(notation.annotation(), Some("+ 4.2e6".to_string()));
+
+        let notation = AxisNotation::detect(&[4200001000.0, 4200003000.0, 4200005000.0]);
+        assert_eq!(notation.apply(4200003000.0), 3.0);
+        assert_eq!(notation.annotation(), Some("×10³ + 4.2e9".to_string()));
+
+        let notation = AxisNotation::detect(&[0.0, 2e-5, 4e-5]);
+        assert_eq!(notation.annotation(), Some("×10⁻⁵".to_string()));
+        assert_eq!(AxisNotation::detect(&[0.0, 0.5, 1.0]).annotation(), None);
+
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(40)
+            .build_ranged(0.0..1.0, 4200000.0..4200001.0)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .y_labels(5)
+            .y_label_offset_notation()
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"+ 4.2e6".to_string()));
+        assert!(labels.iter().all(|l| !l.starts_with("4200000")));
+    }
+
+    #[test]
+    fn test_draw_roc_curve() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+            m.drop_check(|b| {
+                // The reference line and the curve
+                assert_eq!(b.num_draw_path_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..1.0, 0.0..1.0)
+            .expect("Create chart");
+        chart
+            .draw_roc_curve(
+                &[(0.9, true), (0.8, false), (0.7, true), (0.1, false)],
+                &BLUE,
+                ("sans-serif", 12),
+            )
+            .expect("Draw curve")
+            .label("model");
+
+        assert_eq!(*labels.borrow(), vec!["AUC = 0.750".to_string()]);
+
+        // The shaded area and the reference line are culled like the other series when the
+        // chart is zoomed away from the curve
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 0);
+                assert_eq!(b.num_fill_polygon_call, 0);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(5.0..6.0, 5.0..6.0)
+            .expect("Create chart");
+        chart
+            .draw_precision_recall_curve(&[(0.9, true), (0.1, false)], &BLUE, ("sans-serif", 12))
+            .expect("Draw curve");
+    }
+
+    #[test]
+    fn test_draw_axes_and_grid_only() {
+        let count_calls = |mode: usize| {
+            let calls = std::rc::Rc::new(std::cell::Cell::new((0, 0)));
+            let counted = calls.clone();
+            {
+                let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                    m.drop_check(move |b| {
+                        counted.set((
+                            b.num_draw_line_call + b.num_draw_path_call,
+                            b.num_draw_text_call,
+                        ));
+                    });
+                });
+
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(20)
+                    .y_label_area_size(20)
+                    .build_ranged(0..10, 0..10)
+                    .expect("Create chart");
+
+                let mut mesh = chart.configure_mesh();
+                let result = match mode {
+                    0 => mesh.draw(),
+                    1 => mesh.draw_axes_only(),
+                    _ => mesh.draw_grid_only(),
+                };
+                result.expect("Draw mesh");
+            }
+            calls.get()
+        };
+
+        let (all_lines, all_text) = count_calls(0);
+        let (axes_lines, axes_text) = count_calls(1);
+        let (grid_lines, grid_text) = count_calls(2);
+
+        assert_eq!(axes_text, all_text);
+        assert_eq!(grid_text, 0);
+        assert!(axes_lines > 0 && grid_lines > 0);
+        assert_eq!(axes_lines + grid_lines, all_lines);
+    }
+
+    #[test]
+    fn test_label_overflow() {
+        let last_label = |overflow| {
+            let positions = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+            let drawn = positions.clone();
+            {
+                let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                    m.check_draw_text(move |_, _, _, pos, text| {
+                        if text == "10" && pos.1 >= 180 {
+                            drawn.borrow_mut().push(pos);
+                        }
+                    });
+                });
+
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(20)
+                    .y_label_area_size(30)
+                    .build_ranged(0..10, 0..10)
+                    .expect("Create chart");
+
+                chart
+                    .configure_mesh()
+                    .x_label_overflow(overflow)
+                    .draw()
+                    .expect("Draw mesh");
+            }
+            let positions = positions.borrow().clone();
+            positions
+        };
+
+        assert!(last_label(LabelOverflow::Hide).is_empty());
+        let shifted = last_label(LabelOverflow::ShiftInward);
+        let allowed = last_label(LabelOverflow::Allow);
+        assert_eq!(shifted.len(), 1);
+        assert_eq!(allowed.len(), 1);
+        assert!(shifted[0].0 < allowed[0].0);
+    }
+
+    #[test]
+    fn test_mapper_legend() {
+        let colors = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = colors.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_circle(move |c, _, _, _, _| {
+                drawn.borrow_mut().push(c.rgb());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 64);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .right_y_label_area_size(40)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let by_parity = CategoryStyle::<Palette99, _, _>::new(vec![0, 1], |p: &(i32, i32)| p.0 % 2);
+        chart
+            .draw_series(PointSeries::<_, _, Circle<_, _>, _>::mapped(
+                (0..4).map(|x| (x, x)),
+                3,
+                &by_parity,
+            ))
+            .expect("Drawing error");
+        chart.draw_mapper_legend(&by_parity).expect("Draw legend");
+        assert_eq!(chart.series_anno.len(), 3);
+        assert_eq!(chart.series_anno[2].get_label(), "1");
+
+        let colors = colors.borrow();
+        assert_eq!(colors.len(), 4);
+        assert_eq!(colors[0], Color::rgb(&Palette99::pick(0)));
+        assert_eq!(colors[1], Color::rgb(&Palette99::pick(1)));
+        assert_eq!(colors[2], colors[0]);
+
+        let by_value = ColorMapStyle::<Palette99, _>::new(0.0, 1.0, |v: &f64| *v);
+        chart.draw_mapper_legend(&by_value).expect("Draw colorbar");
+    }
+
+    #[test]
+    fn test_converted_series() {
+        let centers = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = centers.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_circle(move |_, _, _, center, _| {
+                drawn.borrow_mut().push(center);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..360.0, 0.0..1.0)
+            .expect("Create chart");
+
+        chart
+            .draw_converted_series(
+                |&(angle, y): &(f64, f64)| (angle.to_degrees(), y),
+                std::iter::once(Circle::new((std::f64::consts::PI, 0.5), 3, &RED)),
+            )
+            .expect("Drawing error");
+        chart
+            .draw_series(std::iter::once(Circle::new((180.0, 0.5), 3, &RED)))
+            .expect("Drawing error");
+
+        let centers = centers.borrow();
+        assert_eq!(centers.len(), 2);
+        assert_eq!(centers[0], centers[1]);
+    }
+
+    #[test]
+    fn test_legend_glyph() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // The line series, its legend glyph and the border of the area series
+                assert_eq!(b.num_draw_path_call, 3);
+                // The area series and its legend glyph, which is overridden
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+                // The cross of the point series and the cross of its legend glyph
+                assert_eq!(b.num_draw_line_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series_with_legend(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+            .expect("Drawing error")
+            .label("line");
+        chart
+            .draw_series_with_legend(AreaSeries::new((0..10).map(|x| (x, x)), 0, &BLUE))
+            .expect("Drawing error")
+            .label("area")
+            .legend(|(x, y)| Circle::new((x, y), 3, &BLUE));
+        chart
+            .draw_series_with_legend(PointSeries::<_, _, Cross<_, _>, _>::new(
+                vec![(5, 5)],
+                4,
+                &GREEN,
+            ))
+            .expect("Drawing error")
+            .label("point");
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_custom_legend_position() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let positions = Rc::new(RefCell::new(vec![]));
+        let recorded = positions.clone();
+        chart
+            .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+            .expect("Drawing error")
+            .label("line")
+            .legend(move |(x, y)| {
+                recorded.borrow_mut().push((x, y));
+                PathElement::new(vec![(x, y), (x + 20, y)], &RED)
+            });
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+
+        // The closure is called with the position of the legend entry, not the origin
+        let positions = positions.borrow();
+        assert_eq!(positions.len(), 1);
+        assert_ne!(positions[0], (0, 0));
+    }
+
+    #[test]
+    fn test_owned_chart_context() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let owned = ChartBuilder::on(&drawing_area)
+            .margin(5)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart")
+            .into_owned();
+
+        for _ in 0..2 {
+            let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+                m.drop_check(|b| {
+                    assert!(b.num_draw_text_call > 0);
+                });
+            });
+            let mut chart = owned.attach(&drawing_area);
+            assert_eq!(chart.plotting_area().get_base_pixel(), (35, 5));
+            assert_eq!(
+                chart.x_label_area[1].as_ref().unwrap().get_base_pixel(),
+                (35, 175)
+            );
+            assert_eq!(
+                chart.y_label_area[0].as_ref().unwrap().get_base_pixel(),
+                (5, 5)
+            );
+            chart.configure_mesh().draw().expect("Draw mesh");
+        }
+    }
+
+    #[test]
+    fn test_step_chart_state() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut state = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0..100)
+            .expect("Create chart")
+            .into_chart_state();
+
+        state
+            .step_x(StepDirection::Forward, 0.25)
+            .step_y(StepDirection::Backward, 0.1);
+        let chart = state.clone().restore(&drawing_area);
+        assert_eq!(chart.x_range(), 2.0..12.0);
+        assert_eq!(chart.y_range(), -10..90);
+
+        state.zoom_discrete(1);
+        let chart = state.restore(&drawing_area);
+        assert_eq!(chart.x_range(), 4.5..9.5);
+        assert_eq!(chart.y_range(), 15..65);
+    }
+
+    #[test]
+    fn test_custom_key_points() {
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.x_key_points(5), vec![0, 5, 10]);
+
+        chart.set_x_key_points(vec![0, 3, 7, 10]);
+        chart.set_y_key_points(vec![0, 5, 10]);
+        assert_eq!(chart.x_key_points(5), vec![0, 3, 7, 10]);
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        {
+            let labels = labels.borrow();
+            assert!(labels.contains(&"3".to_string()));
+            assert!(labels.contains(&"7".to_string()));
+            assert!(!labels.contains(&"1".to_string()));
+        }
+
+        chart.reset_key_points();
+        assert_eq!(chart.y_key_points(5), vec![0, 5, 10]);
+    }
+
+    #[test]
+    fn test_slot_size() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let category = Category::new("color", vec!["red", "green", "blue"]);
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(category.range(), 0..10)
+            .expect("Create chart");
+
+        let slot = chart.x_slot_size();
+        assert_eq!(slot.in_pixels(), 40.0);
+        assert_eq!(slot.width(0.5), 20);
+        assert_eq!(slot.group_offsets(2, 0.5), vec![-5.0, 5.0]);
+        assert_eq!(chart.y_slot_size().in_pixels(), 20.0);
+    }
 }
@@ -104,6 +104,9 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
 
         std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
         std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        let axis_margin = primary.axis_margin;
+        let clip_series = primary.clip_series;
+        let cull_margin = primary.cull_margin;
 
         Self {
             primary,
@@ -113,6 +116,9 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 drawing_area_pos: (0, 0),
+                axis_margin,
+                clip_series,
+                cull_margin,
             },
         }
     }
@@ -0,0 +1,167 @@
+use std::ops::Range;
+
+use super::builder::ChartBuilder;
+use super::context::ChartContext;
+use crate::coord::{RangedCoord, RangedCoordf64, RangedCoordu32};
+use crate::data::{histogram_2d, Bins};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, Rectangle};
+use crate::style::{Color, ShapeStyle};
+
+/// The fraction of the figure height and width taken by the marginal histograms
+const PANEL_SIZE: f64 = 0.2;
+
+/// The radius of the points of the joint scatter plot
+const POINT_SIZE: u32 = 2;
+
+/// How the joint distribution is drawn in the central chart of `MarginalHistograms`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JointPlot {
+    /// Draw a dot for each data point
+    Scatter,
+    /// Draw the 2D histogram, the opacity of each cell is proportional to its count
+    Density,
+}
+
+/// The scatter plot or the 2D density of a dataset with the histograms of both coordinates on
+/// the top and the right sides, which share the axes with the central chart
+pub struct MarginalHistograms<'a, DB: DrawingBackend> {
+    /// The central chart of the joint distribution
+    pub joint: ChartContext<'a, DB, RangedCoord<RangedCoordf64, RangedCoordf64>>,
+    /// The histogram of the X values on the top
+    pub top: ChartContext<'a, DB, RangedCoord<RangedCoordf64, RangedCoordu32>>,
+    /// The histogram of the Y values on the right
+    pub right: ChartContext<'a, DB, RangedCoord<RangedCoordu32, RangedCoordf64>>,
+}
+
+/// Get the range of the finite values, the empty range is widened so that it can be binned
+fn fit_values<I: Iterator<Item = f64>>(values: I) -> Range<f64> {
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        });
+    if min > max {
+        0.0..1.0
+    } else if min == max {
+        min - 0.5..max + 0.5
+    } else {
+        min..max
+    }
+}
+
+/// Get the largest count, which is at least one so that it can be used as the axis range
+fn max_count(counts: &[u32]) -> u32 {
+    counts.iter().cloned().max().unwrap_or(0).max(1)
+}
+
+impl<'a, DB: DrawingBackend> MarginalHistograms<'a, DB> {
+    /// Lay out the charts fitting the data, then draw the joint distribution and the marginal
+    /// histograms. The charts are returned for configuring the meshes and drawing more series.
+    /// - `builder`: The builder with the label areas, the margin and the caption of the figure
+    /// - `points`: The data points
+    /// - `bins`: The number of bins of each coordinate
+    /// - `joint`: How the joint distribution is drawn
+    /// - `style`: The style of the points, the density cells and the histogram bars
+    pub fn draw<S: Into<ShapeStyle>>(
+        builder: &mut ChartBuilder<'a, '_, DB>,
+        points: &[(f64, f64)],
+        bins: usize,
+        joint: JointPlot,
+        style: S,
+    ) -> Result<Self, DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let x_bins = Bins::new(fit_values(points.iter().map(|p| p.0)), bins);
+        let y_bins = Bins::new(fit_values(points.iter().map(|p| p.1)), bins);
+        let x_counts = x_bins.histogram(points.iter().map(|p| p.0));
+        let y_counts = y_bins.histogram(points.iter().map(|p| p.1));
+
+        let (mut joint_chart, mut top, mut right) = builder.build_ranged_with_marginals(
+            x_bins.range(),
+            y_bins.range(),
+            0..max_count(&x_counts),
+            0..max_count(&y_counts),
+            PANEL_SIZE,
+        )?;
+
+        match joint {
+            JointPlot::Scatter => {
+                joint_chart.draw_series(
+                    points
+                        .iter()
+                        .map(|p| Circle::new(*p, POINT_SIZE, style.clone())),
+                )?;
+            }
+            JointPlot::Density => {
+                let cells = histogram_2d(points.iter().cloned(), &x_bins, &y_bins);
+                let max = max_count(&cells.concat());
+                joint_chart.draw_series(
+                    cells
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, column)| {
+                            column.iter().enumerate().map(move |(j, c)| (i, j, *c))
+                        })
+                        .filter(|(_, _, c)| *c > 0)
+                        .map(|(i, j, c)| {
+                            let (x, y) = (x_bins.bin_range(i), y_bins.bin_range(j));
+                            Rectangle::new(
+                                [(x.start, y.start), (x.end, y.end)],
+                                style.color.mix(f64::from(c) / f64::from(max)).filled(),
+                            )
+                        }),
+                )?;
+            }
+        }
+
+        top.draw_series(x_counts.iter().enumerate().map(|(i, c)| {
+            let x = x_bins.bin_range(i);
+            Rectangle::new([(x.start, 0), (x.end, *c)], style.clone())
+        }))?;
+        right.draw_series(y_counts.iter().enumerate().map(|(j, c)| {
+            let y = y_bins.bin_range(j);
+            Rectangle::new([(0, y.start), (*c, y.end)], style.clone())
+        }))?;
+
+        Ok(Self {
+            joint: joint_chart,
+            top,
+            right,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_marginal_histograms() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // 3 non-empty density cells and 2 bars for each marginal histogram
+                assert_eq!(b.num_draw_rect_call, 7);
+            });
+        });
+
+        let points = [(0.0, 0.0), (1.0, 1.0), (1.0, 1.0), (0.2, 0.9)];
+        let figure = MarginalHistograms::draw(
+            ChartBuilder::on(&drawing_area).x_label_area_size(20),
+            &points,
+            2,
+            JointPlot::Density,
+            BLUE.filled(),
+        )
+        .expect("Draw figure");
+
+        assert_eq!(figure.joint.x_range(), 0.0..1.0);
+        assert_eq!(figure.top.y_range(), 0..2);
+        assert_eq!(figure.right.x_range(), 0..3);
+        assert_eq!(
+            figure.top.as_coord_spec().get_x_axis_pixel_range(),
+            figure.joint.as_coord_spec().get_x_axis_pixel_range()
+        );
+    }
+}
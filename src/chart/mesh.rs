@@ -3,12 +3,13 @@ use std::marker::PhantomData;
 
 use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
-use crate::coord::{MeshLine, Ranged, RangedCoord};
+use crate::coord::{format_power, MeshLine, Ranged, RangedCoord};
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Text;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, FontDesc, FontFamily, FontStyle, IntoFont, IntoTextStyle, ShapeStyle, SizeDesc,
+    TextStyle, Theme, DEFAULT_AXIS_STYLE, DEFAULT_BOLD_MESH_STYLE, DEFAULT_LIGHT_MESH_STYLE,
 };
 
 /// The style used to describe the mesh and axis for a secondary coordinate system.
@@ -51,6 +52,20 @@ where
         self
     }
 
+    /// Set what to do with the X labels which don't fit in the label area
+    /// - `overflow`: The overflow policy
+    pub fn x_label_overflow(&mut self, overflow: LabelOverflow) -> &mut Self {
+        self.style.x_label_overflow(overflow);
+        self
+    }
+
+    /// Set what to do with the Y labels which don't fit in the label area
+    /// - `overflow`: The overflow policy
+    pub fn y_label_overflow(&mut self, overflow: LabelOverflow) -> &mut Self {
+        self.style.y_label_overflow(overflow);
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -79,6 +94,13 @@ where
         self
     }
 
+    /// Cache the formatted tick labels, see `MeshStyle::cache_labels`
+    /// - `formatter_id`: The id of the label formatters
+    pub fn cache_labels(&mut self, formatter_id: u64) -> &mut Self {
+        self.style.cache_labels(formatter_id);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -87,6 +109,14 @@ where
         self
     }
 
+    /// Apply the style sheet, see `MeshStyle::set_style_sheet`, the grid styles are ignored
+    /// since the secondary axes have no grid
+    /// - `sheet`: The style sheet to apply
+    pub fn set_style_sheet(&mut self, sheet: &MeshStyleSheet<'b>) -> &mut Self {
+        self.style.set_style_sheet(sheet);
+        self
+    }
+
     /// Set the X axis's description
     /// - `desc`: The description of the X axis
     pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
@@ -136,6 +166,202 @@ where
     }
 }
 
+/// What to do with a tick label which doesn't fit in the label area, which is typically the
+/// label of the first or the last tick
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelOverflow {
+    /// Omit the label, this is the default
+    Hide,
+    /// Shift the label along the axis until it fits in the label area
+    ShiftInward,
+    /// Draw the label anyway, it may extend into the margin or the neighboring label area
+    Allow,
+}
+
+/// The max number of decimals `LabelPrecision::Auto` chooses
+const MAX_AUTO_DECIMALS: usize = 15;
+
+/// The label precision of an axis and the function converting its values into `f64`
+type AxisPrecision<T> = (LabelPrecision, fn(&T) -> f64);
+
+/// The precision of the numeric tick labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelPrecision {
+    /// Derive the number of decimals from the spacing of the key points, so that the labels
+    /// are just precise enough to tell the ticks apart
+    Auto,
+    /// Always use the given number of decimals
+    Fixed(usize),
+}
+
+impl LabelPrecision {
+    /// Get the number of decimals used for the labels of the given key points
+    pub fn decimals(self, key_points: &[f64]) -> usize {
+        match self {
+            LabelPrecision::Fixed(decimals) => decimals,
+            LabelPrecision::Auto => {
+                let step = key_points
+                    .windows(2)
+                    .map(|w| (w[1] - w[0]).abs())
+                    .filter(|d| *d > 0.0)
+                    .fold(f64::INFINITY, f64::min);
+                let eps = if step.is_finite() { step * 1e-6 } else { 1e-9 };
+                (0..MAX_AUTO_DECIMALS)
+                    .find(|&decimals| {
+                        let scale = 10f64.powi(decimals as i32);
+                        key_points
+                            .iter()
+                            .all(|v| ((v * scale).round() / scale - v).abs() <= eps)
+                    })
+                    .unwrap_or(MAX_AUTO_DECIMALS)
+            }
+        }
+    }
+
+    /// Format the value with the given number of decimals
+    pub(super) fn format(value: f64, decimals: usize) -> String {
+        // Avoid labels like "-0.0" for the key points which are zero up to the rounding error
+        let scale = 10f64.powi(decimals as i32);
+        let value = if (value * scale).round() == 0.0 {
+            0.0
+        } else {
+            value
+        };
+        format!("{:.*}", decimals, value)
+    }
+}
+
+/// The ratio of the magnitude of the key points to their spread from which the common offset
+/// is factored out of the labels
+const OFFSET_THRESHOLD: f64 = 1e4;
+
+/// The smallest magnitude of the exponent which is factored out of the labels
+const MIN_NOTATION_EXPONENT: i32 = 3;
+
+/// The padding between the notation annotation and the border of the plotting area in pixels
+const NOTATION_PADDING: i32 = 3;
+
+/// The common offset and power of ten factored out of the numeric tick labels, which keeps the
+/// labels short when the values share a large baseline. The label of the value `v` is
+/// `(v - offset) / 10^exponent` and the axis is annotated with something like `×10³ + 4.2e6`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AxisNotation {
+    /// The offset subtracted from the values
+    pub offset: f64,
+    /// The power of ten the values are divided by after the offset is subtracted
+    pub exponent: i32,
+}
+
+impl AxisNotation {
+    /// Find the offset and the exponent for the key points of an axis. The offset is only used
+    /// when the key points are far from zero compared with their spread, and the exponent is
+    /// only used when the remaining values are very large or very small.
+    pub fn detect(key_points: &[f64]) -> Self {
+        let min = key_points.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = key_points.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let spread = max - min;
+        if !spread.is_finite() || spread <= 0.0 {
+            return Self::default();
+        }
+
+        let offset = if min.abs().max(max.abs()) / spread >= OFFSET_THRESHOLD {
+            let step = 10f64.powi(spread.log10().ceil() as i32);
+            (min / step).floor() * step
+        } else {
+            0.0
+        };
+
+        let magnitude = (min - offset).abs().max((max - offset).abs());
+        let exponent = magnitude.log10().floor() as i32;
+        Self {
+            offset,
+            exponent: if exponent.abs() >= MIN_NOTATION_EXPONENT {
+                exponent
+            } else {
+                0
+            },
+        }
+    }
+
+    /// Get the value shown by the tick label
+    pub fn apply(&self, value: f64) -> f64 {
+        (value - self.offset) / 10f64.powi(self.exponent)
+    }
+
+    /// Get the annotation of the axis, e.g. `×10³ + 4.2e6`, or `None` if nothing is factored out
+    pub fn annotation(&self) -> Option<String> {
+        let mut parts = vec![];
+        if self.exponent != 0 {
+            parts.push(format!("×{}", format_power(self.exponent)));
+        }
+        if self.offset != 0.0 {
+            let sign = if self.offset < 0.0 { '-' } else { '+' };
+            parts.push(format!("{} {:e}", sign, self.offset.abs()));
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" "))
+        }
+    }
+}
+
+/// The styles of the mesh components, which are applied to a mesh with one call of
+/// `MeshStyle::set_style_sheet`. The fields left `None` keep the current style of the mesh,
+/// so a partial style sheet can be cascaded over a base one with `MeshStyleSheet::merge`, e.g.
+/// over the style sheet made from the theme.
+#[derive(Clone, Default)]
+pub struct MeshStyleSheet<'a> {
+    /// The style of the axis lines and the tick marks
+    pub axis: Option<ShapeStyle>,
+    /// The style of the coarse grid
+    pub bold_grid: Option<ShapeStyle>,
+    /// The style of the fine grid
+    pub light_grid: Option<ShapeStyle>,
+    /// The size of the tick marks in pixels, the negative size makes the labels inward
+    pub tick_size: Option<i32>,
+    /// The style of the tick labels
+    pub label_font: Option<TextStyle<'a>>,
+    /// The style of the axis descriptions
+    pub desc_font: Option<TextStyle<'a>>,
+}
+
+impl<'a> MeshStyleSheet<'a> {
+    /// Create an empty style sheet, which keeps all the styles of the mesh
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the style sheet with the colors of the theme: the axes and the text use the
+    /// foreground color and the grid uses the grid color, the fine grid is half transparent
+    /// - `theme`: The theme of the chart
+    pub fn from_theme(theme: &Theme) -> Self {
+        let font = ("sans-serif", 12).into_font().color(&theme.foreground);
+        Self {
+            axis: Some(theme.foreground.stroke_width(1)),
+            bold_grid: Some(theme.grid.stroke_width(1)),
+            light_grid: Some(theme.grid.mix(0.5).stroke_width(1)),
+            tick_size: None,
+            label_font: Some(font.clone()),
+            desc_font: Some(font),
+        }
+    }
+
+    /// Cascade the other style sheet over this one, the styles set in the other style sheet
+    /// take the precedence
+    /// - `other`: The style sheet with the overrides
+    pub fn merge(&self, other: &MeshStyleSheet<'a>) -> Self {
+        Self {
+            axis: other.axis.clone().or_else(|| self.axis.clone()),
+            bold_grid: other.bold_grid.clone().or_else(|| self.bold_grid.clone()),
+            light_grid: other.light_grid.clone().or_else(|| self.light_grid.clone()),
+            tick_size: other.tick_size.or(self.tick_size),
+            label_font: other.label_font.clone().or_else(|| self.label_font.clone()),
+            desc_font: other.desc_font.clone().or_else(|| self.desc_font.clone()),
+        }
+    }
+}
+
 /// The struct that is used for tracking the configuration of a mesh of any chart
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB>
 where
@@ -160,16 +386,25 @@ where
     pub(super) y_label_style: Option<TextStyle<'b>>,
     pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
     pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    pub(super) label_cache_id: Option<u64>,
+    pub(super) x_label_precision: Option<AxisPrecision<X::ValueType>>,
+    pub(super) y_label_precision: Option<AxisPrecision<Y::ValueType>>,
+    pub(super) x_label_notation: bool,
+    pub(super) y_label_notation: bool,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, RangedCoord<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) x_label_overflow: LabelOverflow,
+    pub(super) y_label_overflow: LabelOverflow,
 }
 
 impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
 where
     X: Ranged,
     Y: Ranged,
+    X::ValueType: Debug,
+    Y::ValueType: Debug,
     DB: DrawingBackend,
 {
     /// Set all the tick mark to the same size
@@ -216,6 +451,22 @@ where
         self
     }
 
+    /// Set what to do with the X labels which don't fit in the label area, by default they
+    /// are omitted
+    /// - `overflow`: The overflow policy
+    pub fn x_label_overflow(&mut self, overflow: LabelOverflow) -> &mut Self {
+        self.x_label_overflow = overflow;
+        self
+    }
+
+    /// Set what to do with the Y labels which don't fit in the label area, by default they
+    /// are omitted
+    /// - `overflow`: The overflow policy
+    pub fn y_label_overflow(&mut self, overflow: LabelOverflow) -> &mut Self {
+        self.y_label_overflow = overflow;
+        self
+    }
+
     /// Disable the mesh for the x axis.
     pub fn disable_x_mesh(&mut self) -> &mut Self {
         self.draw_x_mesh = false;
@@ -311,6 +562,8 @@ where
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
         self.format_x = fmt;
+        self.x_label_precision = None;
+        self.x_label_notation = false;
         self
     }
 
@@ -318,6 +571,18 @@ where
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
         self.format_y = fmt;
+        self.y_label_precision = None;
+        self.y_label_notation = false;
+        self
+    }
+
+    /// Cache the formatted tick labels and their sizes by the axis ranges, so that redrawing
+    /// the mesh with the same ranges, for example in the following animation frames or after
+    /// stepping back to a previous range, doesn't format and measure the labels again.
+    /// - `formatter_id`: The id of the label formatters, use a different id when the
+    ///   formatters are changed, or call `ChartContext::clear_mesh_cache` to invalidate the cache
+    pub fn cache_labels(&mut self, formatter_id: u64) -> &mut Self {
+        self.label_cache_id = Some(formatter_id);
         self
     }
 
@@ -328,6 +593,31 @@ where
         self
     }
 
+    /// Apply the styles of the mesh components set in the style sheet, the other components
+    /// keep their current styles
+    /// - `sheet`: The style sheet to apply
+    pub fn set_style_sheet(&mut self, sheet: &MeshStyleSheet<'b>) -> &mut Self {
+        if let Some(style) = &sheet.axis {
+            self.axis_style = Some(style.clone());
+        }
+        if let Some(style) = &sheet.bold_grid {
+            self.line_style_1 = Some(style.clone());
+        }
+        if let Some(style) = &sheet.light_grid {
+            self.line_style_2 = Some(style.clone());
+        }
+        if let Some(size) = sheet.tick_size {
+            self.set_all_tick_mark_size(size);
+        }
+        if let Some(style) = &sheet.label_font {
+            self.label_style(style.clone());
+        }
+        if let Some(style) = &sheet.desc_font {
+            self.axis_desc_style = Some(style.clone());
+        }
+        self
+    }
+
     /// Set the X axis's description
     /// - `desc`: The description of the X axis
     pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
@@ -344,31 +634,72 @@ where
 
     /// Draw the configured mesh on the target plot
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_parts(true, true)
+    }
+
+    /// Draw only the axes, tick marks, labels and axis descriptions, but not the grid lines.
+    /// Together with `draw_grid_only` this allows the static axes and the grid to be drawn on
+    /// different layers.
+    pub fn draw_axes_only(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_parts(false, true)
+    }
+
+    /// Draw only the grid lines, but not the axes, tick marks and labels
+    pub fn draw_grid_only(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_parts(true, false)
+    }
+
+    fn draw_parts(
+        &mut self,
+        grid: bool,
+        axes: bool,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let mut target = None;
         std::mem::swap(&mut target, &mut self.target);
         let target = target.unwrap();
 
-        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
-        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
-        let default_axis_color = RGBColor(0, 0, 0);
+        // The precision is derived from the labeled key points, which may be replaced by the user
+        let (x_key_points, y_key_points) = target.as_coord_spec().map_mesh_key_points(
+            self.n_y_labels,
+            self.n_x_labels,
+            |v| self.x_label_precision.map(|(_, to_f64)| to_f64(v)),
+            |v| self.y_label_precision.map(|(_, to_f64)| to_f64(v)),
+        );
+
+        let x_label_precision = self.x_label_precision.map(|(precision, to_f64)| {
+            let key_points: Vec<_> = x_key_points.iter().flatten().copied().collect();
+            let notation = if self.x_label_notation {
+                AxisNotation::detect(&key_points)
+            } else {
+                AxisNotation::default()
+            };
+            let key_points: Vec<_> = key_points.iter().map(|v| notation.apply(*v)).collect();
+            (precision.decimals(&key_points), to_f64, notation)
+        });
+
+        let y_label_precision = self.y_label_precision.map(|(precision, to_f64)| {
+            let key_points: Vec<_> = y_key_points.iter().flatten().copied().collect();
+            let notation = if self.y_label_notation {
+                AxisNotation::detect(&key_points)
+            } else {
+                AxisNotation::default()
+            };
+            let key_points: Vec<_> = key_points.iter().map(|v| notation.apply(*v)).collect();
+            (precision.decimals(&key_points), to_f64, notation)
+        });
+
         let default_label_font = FontDesc::new(
             FontFamily::SansSerif,
             f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
             FontStyle::Normal,
         );
 
-        let mesh_style_1 = self
-            .line_style_1
-            .clone()
-            .unwrap_or_else(|| (&default_mesh_color_1).into());
+        let mesh_style_1 = self.line_style_1.clone().unwrap_or(DEFAULT_BOLD_MESH_STYLE);
         let mesh_style_2 = self
             .line_style_2
             .clone()
-            .unwrap_or_else(|| (&default_mesh_color_2).into());
-        let axis_style = self
-            .axis_style
-            .clone()
-            .unwrap_or_else(|| (&default_axis_color).into());
+            .unwrap_or(DEFAULT_LIGHT_MESH_STYLE);
+        let axis_style = self.axis_style.clone().unwrap_or(DEFAULT_AXIS_STYLE);
 
         let x_label_style = self
             .x_label_style
@@ -390,19 +721,21 @@ where
             &mesh_style_2,
             &x_label_style,
             &y_label_style,
+            None,
             |_| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            grid && self.draw_x_mesh,
+            grid && self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
             false,
             &axis_style,
             &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
+            self.x_desc.clone().filter(|_| axes),
+            self.y_desc.clone().filter(|_| axes),
             self.x_tick_size,
             self.y_tick_size,
+            (self.x_label_overflow, self.y_label_overflow),
         )?;
 
         target.draw_mesh(
@@ -410,22 +743,117 @@ where
             &mesh_style_1,
             &x_label_style,
             &y_label_style,
+            self.label_cache_id.filter(|_| axes),
             |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+                _ if !axes => None,
+                MeshLine::XMesh(_, _, v) => Some(match x_label_precision {
+                    Some((decimals, to_f64, notation)) => {
+                        LabelPrecision::format(notation.apply(to_f64(v)), decimals)
+                    }
+                    None => (self.format_x)(v),
+                }),
+                MeshLine::YMesh(_, _, v) => Some(match y_label_precision {
+                    Some((decimals, to_f64, notation)) => {
+                        LabelPrecision::format(notation.apply(to_f64(v)), decimals)
+                    }
+                    None => (self.format_y)(v),
+                }),
             },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            grid && self.draw_x_mesh,
+            grid && self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
-            self.draw_x_axis,
-            self.draw_y_axis,
+            axes && self.draw_x_axis,
+            axes && self.draw_y_axis,
             &axis_style,
             &axis_desc_style,
             None,
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+            (self.x_label_overflow, self.y_label_overflow),
+        )?;
+
+        if axes {
+            let area = target.drawing_area.strip_coord_spec();
+            let (w, h) = area.dim_in_pixel();
+            let x_annotation = x_label_precision
+                .and_then(|(_, _, notation)| notation.annotation())
+                .filter(|_| self.draw_x_axis);
+            let y_annotation = y_label_precision
+                .and_then(|(_, _, notation)| notation.annotation())
+                .filter(|_| self.draw_y_axis);
+
+            // The X annotation sits at the lower right corner and the Y annotation at the
+            // upper left corner, where the ends of the axes meet the plotting area
+            if let Some(text) = x_annotation {
+                let (tw, th) = area.estimate_text_size(&text, &x_label_style.font)?;
+                let pos = (
+                    w as i32 - tw as i32 - NOTATION_PADDING,
+                    h as i32 - th as i32 - NOTATION_PADDING,
+                );
+                area.draw(&Text::new(text, pos, x_label_style.clone()))?;
+            }
+            if let Some(text) = y_annotation {
+                let pos = (NOTATION_PADDING, NOTATION_PADDING);
+                area.draw(&Text::new(text, pos, y_label_style.clone()))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
+where
+    X: Ranged,
+    X::ValueType: Into<f64> + Copy,
+    Y: Ranged,
+    DB: DrawingBackend,
+{
+    /// Format the X labels as numbers with the given precision, this replaces the X label
+    /// formatter
+    /// - `precision`: The precision of the labels
+    pub fn x_label_precision(&mut self, precision: LabelPrecision) -> &mut Self {
+        self.x_label_precision = Some((precision, |v| (*v).into()));
+        self
+    }
+
+    /// Factor the common offset and power of ten out of the X labels and show them in a single
+    /// annotation at the end of the axis, e.g. `×10³ + 4.2e6`. The labels are formatted as
+    /// numbers, with `LabelPrecision::Auto` unless `x_label_precision` is set.
+    pub fn x_label_offset_notation(&mut self) -> &mut Self {
+        if self.x_label_precision.is_none() {
+            self.x_label_precision(LabelPrecision::Auto);
+        }
+        self.x_label_notation = true;
+        self
+    }
+}
+
+impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
+where
+    X: Ranged,
+    Y: Ranged,
+    Y::ValueType: Into<f64> + Copy,
+    DB: DrawingBackend,
+{
+    /// Format the Y labels as numbers with the given precision, this replaces the Y label
+    /// formatter
+    /// - `precision`: The precision of the labels
+    pub fn y_label_precision(&mut self, precision: LabelPrecision) -> &mut Self {
+        self.y_label_precision = Some((precision, |v| (*v).into()));
+        self
+    }
+
+    /// Factor the common offset and power of ten out of the Y labels and show them in a single
+    /// annotation at the end of the axis, e.g. `×10³ + 4.2e6`. The labels are formatted as
+    /// numbers, with `LabelPrecision::Auto` unless `y_label_precision` is set.
+    pub fn y_label_offset_notation(&mut self) -> &mut Self {
+        if self.y_label_precision.is_none() {
+            self.y_label_precision(LabelPrecision::Auto);
+        }
+        self.y_label_notation = true;
+        self
     }
 }
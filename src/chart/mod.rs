@@ -12,14 +12,29 @@ In Plotters, a series is abstracted as an iterator of elements.
 detailed description for each struct.
 */
 
+mod anchor;
+mod annotation;
 mod builder;
+mod confusion;
 mod context;
 mod dual_coord;
+mod marginal;
 mod mesh;
+mod readout;
 mod series;
+mod validation;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
-pub use context::{ChartContext, ChartState, SeriesAnno};
+pub use anchor::AnchorRegistry;
+pub use annotation::{Annotation, AnnotationLayer};
+pub use builder::{AxisMargin, ChartBuilder, LabelAreaPosition};
+pub use confusion::{CellValue, ConfusionMatrix};
+pub use context::{
+    ChartContext, ChartState, OwnedChartContext, SeriesAnno, StepDirection, DEFAULT_CULL_MARGIN,
+};
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::MeshStyle;
-pub use series::{SeriesLabelPosition, SeriesLabelStyle};
+pub use marginal::{JointPlot, MarginalHistograms};
+pub use mesh::{AxisNotation, LabelOverflow, LabelPrecision, MeshStyle, MeshStyleSheet};
+pub use readout::{Readout, Sampling};
+pub(crate) use series::DEFAULT_MARKER_SIZE;
+pub use series::{HasLegendGlyph, LegendGlyph, SeriesLabelPosition, SeriesLabelStyle};
+pub use validation::{SeriesIssue, SeriesValidationError, ValueAxis};
@@ -0,0 +1,291 @@
+use super::context::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, PathElement, Rectangle};
+use crate::style::{IntoFont, ShapeStyle, TextStyle, BLACK, WHITE};
+
+/// The padding inside the readout box in pixels
+const PADDING: i32 = 4;
+
+/// The distance between the cursor and the readout box in pixels
+const CURSOR_OFFSET: i32 = 12;
+
+/// The size of the color swatch of each series in pixels
+const SWATCH_SIZE: i32 = 8;
+
+/// The radius of the markers of the sampled points
+const MARKER_SIZE: u32 = 3;
+
+/// The label, data and style of a sampled series
+type ReadoutSeries<'a> = (String, &'a [(f64, f64)], ShapeStyle);
+
+/// How the series are sampled at the X value of the cursor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sampling {
+    /// Take the data point with the nearest X value
+    Nearest,
+    /// Interpolate linearly between the data points around the X value, the series which
+    /// doesn't cover the X value is omitted
+    Interpolate,
+}
+
+/// The readout of the crosshair, which samples multiple series at the X value of the cursor and
+/// shows the values in a box next to the cursor
+pub struct Readout<'a> {
+    series: Vec<ReadoutSeries<'a>>,
+    sampling: Sampling,
+    x_formatter: Option<&'a dyn Fn(&f64) -> String>,
+    y_formatter: Option<&'a dyn Fn(&f64) -> String>,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a> Readout<'a> {
+    /// Create an empty readout
+    /// - `sampling`: How the series are sampled
+    pub fn new(sampling: Sampling) -> Self {
+        Self {
+            series: vec![],
+            sampling,
+            x_formatter: None,
+            y_formatter: None,
+            label_style: None,
+        }
+    }
+
+    /// Add a series to the readout
+    /// - `name`: The name of the series shown in the readout
+    /// - `data`: The data points of the series ordered by the X value
+    /// - `style`: The style of the series, which is used for the swatch and the marker
+    pub fn add_series<S: Into<ShapeStyle>>(
+        &mut self,
+        name: &str,
+        data: &'a [(f64, f64)],
+        style: S,
+    ) -> &mut Self {
+        self.series.push((name.to_string(), data, style.into()));
+        self
+    }
+
+    /// Set the formatter of the X value, which is usually the label formatter of the X axis
+    pub fn x_formatter(&mut self, fmt: &'a dyn Fn(&f64) -> String) -> &mut Self {
+        self.x_formatter = Some(fmt);
+        self
+    }
+
+    /// Set the formatter of the Y values, which is usually the label formatter of the Y axis
+    pub fn y_formatter(&mut self, fmt: &'a dyn Fn(&f64) -> String) -> &mut Self {
+        self.y_formatter = Some(fmt);
+        self
+    }
+
+    /// Set the style of the text in the readout box
+    /// - `style`: The text style, the default is 12px sans-serif
+    pub fn label_style<T: Into<TextStyle<'a>>>(&mut self, style: T) -> &mut Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Sample the series at the X value, the data points with a NaN coordinate are skipped
+    /// - `x`: The X value of the cursor
+    /// - **returns**: The sampled point of each series, `None` if the series has no value there
+    pub fn sample(&self, x: f64) -> Vec<Option<(f64, f64)>> {
+        self.series
+            .iter()
+            .map(|(_, data, _)| {
+                if x.is_nan() {
+                    return None;
+                }
+                let data: Vec<_> = data
+                    .iter()
+                    .filter(|p| !p.0.is_nan() && !p.1.is_nan())
+                    .cloned()
+                    .collect();
+                match self.sampling {
+                    Sampling::Nearest => data
+                        .iter()
+                        .min_by(|a, b| (a.0 - x).abs().total_cmp(&(b.0 - x).abs()))
+                        .cloned(),
+                    Sampling::Interpolate => data
+                        .windows(2)
+                        .find(|w| w[0].0 <= x && x <= w[1].0)
+                        .map(|w| {
+                            if w[1].0 == w[0].0 {
+                                return (x, w[0].1);
+                            }
+                            let t = (x - w[0].0) / (w[1].0 - w[0].0);
+                            (x, w[0].1 + t * (w[1].1 - w[0].1))
+                        })
+                        .or_else(|| data.iter().find(|p| p.0 == x).cloned()),
+                }
+            })
+            .collect()
+    }
+
+    /// Format the summary of the readout, the first line is the X value and each of the
+    /// following lines is the name and the value of a sampled series
+    /// - `x`: The X value of the cursor
+    pub fn lines(&self, x: f64) -> Vec<String> {
+        let format = |fmt: Option<&'a dyn Fn(&f64) -> String>, v: f64| match fmt {
+            Some(fmt) => fmt(&v),
+            None => format!("{}", v),
+        };
+        let samples = self.sample(x);
+        std::iter::once(format(self.x_formatter, x))
+            .chain(
+                self.series
+                    .iter()
+                    .zip(samples)
+                    .filter_map(|((name, _, _), sample)| {
+                        sample.map(|(_, y)| format!("{}: {}", name, format(self.y_formatter, y)))
+                    }),
+            )
+            .collect()
+    }
+
+    /// Draw the crosshair at the X value with the markers on the sampled points and the readout
+    /// box, which is placed next to the cursor and kept inside the plotting area
+    /// - `chart`: The chart the series are drawn on
+    /// - `x`: The X value of the cursor
+    pub fn draw<'b, DB: DrawingBackend + 'b, X, Y>(
+        &self,
+        chart: &ChartContext<'b, DB, RangedCoord<X, Y>>,
+        x: f64,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        let plotting_area = chart.plotting_area();
+        let y_range = plotting_area.get_y_range();
+        plotting_area.draw(&PathElement::new(
+            vec![(x, y_range.start), (x, y_range.end)],
+            BLACK.mix(0.4).stroke_width(1),
+        ))?;
+
+        let samples = self.sample(x);
+        let mut sampled = vec![];
+        for ((_, _, style), sample) in self.series.iter().zip(samples) {
+            if let Some(point) = sample {
+                plotting_area.draw(&Circle::new(point, MARKER_SIZE, style.color.filled()))?;
+                sampled.push((style.color.clone(), point));
+            }
+        }
+
+        let area = plotting_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let (x0, y0) = area.get_base_pixel();
+        let text_style = self
+            .label_style
+            .clone()
+            .unwrap_or_else(|| ("sans-serif", 12).into_font().into());
+        let lines = self.lines(x);
+        let mut sizes = vec![];
+        for line in &lines {
+            sizes.push(area.estimate_text_size(line, &text_style.font)?);
+        }
+        let line_height = sizes.iter().map(|s| s.1 as i32).max().unwrap_or(0);
+        let text_width = sizes.iter().map(|s| s.0 as i32).max().unwrap_or(0);
+        let box_w = text_width + SWATCH_SIZE + PADDING * 3;
+        let box_h = line_height * lines.len() as i32 + PADDING * 2;
+
+        let cursor = chart.backend_coord(&(x, y_range.start)).0 - x0;
+        let mut bx = cursor + CURSOR_OFFSET;
+        if bx + box_w > w as i32 {
+            bx = cursor - CURSOR_OFFSET - box_w;
+        }
+        let center = if sampled.is_empty() {
+            box_h / 2
+        } else {
+            sampled
+                .iter()
+                .map(|(_, p)| chart.backend_coord(p).1 - y0)
+                .sum::<i32>()
+                / sampled.len() as i32
+        };
+        let bx = bx.min(w as i32 - box_w).max(0);
+        let by = (center - box_h / 2).min(h as i32 - box_h).max(0);
+
+        area.draw(&Rectangle::new(
+            [(bx, by), (bx + box_w, by + box_h)],
+            WHITE.mix(0.9).filled(),
+        ))?;
+        area.draw(&Rectangle::new(
+            [(bx, by), (bx + box_w, by + box_h)],
+            BLACK.mix(0.5).stroke_width(1),
+        ))?;
+
+        let text_x = bx + PADDING * 2 + SWATCH_SIZE;
+        for (idx, line) in lines.iter().enumerate() {
+            let ly = by + PADDING + line_height * idx as i32;
+            if let Some((color, _)) = idx.checked_sub(1).and_then(|i| sampled.get(i)) {
+                let sy = ly + (line_height - SWATCH_SIZE) / 2;
+                area.draw(&Rectangle::new(
+                    [
+                        (bx + PADDING, sy),
+                        (bx + PADDING + SWATCH_SIZE, sy + SWATCH_SIZE),
+                    ],
+                    color.filled(),
+                ))?;
+            }
+            area.draw_text(line, &text_style, (text_x, ly))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_readout() {
+        let a = [(0.0, 0.0), (2.0, 4.0), (4.0, 0.0)];
+        let b = [(1.0, 1.0), (1.5, 2.0)];
+        let y_formatter = |y: &f64| format!("{:.1}", y);
+        let mut readout = Readout::new(Sampling::Interpolate);
+        readout
+            .add_series("a", &a, &RED)
+            .add_series("b", &b, &BLUE)
+            .y_formatter(&y_formatter);
+
+        assert_eq!(
+            readout.sample(1.0),
+            vec![Some((1.0, 2.0)), Some((1.0, 1.0))]
+        );
+        assert_eq!(readout.lines(3.0), vec!["3", "a: 2.0"]);
+
+        readout.sampling = Sampling::Nearest;
+        assert_eq!(readout.sample(3.5)[1], Some((1.5, 2.0)));
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // The crosshair
+                assert_eq!(b.num_draw_path_call, 1);
+                // The markers
+                assert_eq!(b.num_draw_circle_call, 2);
+                // The box, its border and the swatches
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..4.0, 0.0..4.0)
+            .expect("Create chart");
+        readout.draw(&chart, 3.5).expect("Draw readout");
+    }
+
+    #[test]
+    fn test_readout_skips_nan() {
+        let a = [(0.0, 0.0), (1.0, f64::NAN), (f64::NAN, 1.0), (2.0, 4.0)];
+        let mut readout = Readout::new(Sampling::Nearest);
+        readout.add_series("a", &a, &RED).label_style(("serif", 20));
+
+        assert_eq!(readout.sample(1.2), vec![Some((2.0, 4.0))]);
+        assert_eq!(readout.sample(f64::NAN), vec![None]);
+
+        readout.sampling = Sampling::Interpolate;
+        assert_eq!(readout.sample(1.0), vec![Some((1.0, 2.0))]);
+    }
+}
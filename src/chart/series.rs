@@ -1,10 +1,74 @@
-use super::ChartContext;
+use super::{AnchorRegistry, ChartContext};
 use crate::coord::CoordTranslate;
 use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
 use crate::drawing::{DrawingAreaErrorKind, DrawingBackend};
-use crate::element::{EmptyElement, IntoDynElement, MultiLineText, Rectangle};
+use crate::element::{
+    Circle, Cross, DynElement, EmptyElement, IntoDynElement, MarkerShape, MultiLineText,
+    PathElement, Pixel, PointElement, Rectangle, TriangleMarker,
+};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
+/// The size of the box the legend glyph is drawn in, the relative marker sizes are computed
+/// against it
+const LEGEND_GLYPH_SIZE: (u32, u32) = (20, 10);
+
+/// The size of the circle marker used when the series doesn't have a fixed marker size
+pub(crate) const DEFAULT_MARKER_SIZE: i32 = 3;
+
+/// The glyph drawn in the legend area, which is automatically derived from the series
+#[derive(Clone)]
+pub enum LegendGlyph {
+    /// A short line, used by the line series
+    Line(ShapeStyle),
+    /// A marker with the shape and the size in pixels, used by the scatter series
+    Marker(ShapeStyle, MarkerShape, i32),
+    /// A filled square, used by the area series
+    Area(ShapeStyle),
+}
+
+impl LegendGlyph {
+    /// Create the marker glyph of a series made of the point elements
+    /// - `style`: The style of the points
+    /// - `shape`: The shape of the points, see `LegendMarker`
+    /// - `size`: The size of the points
+    pub fn marker<S: SizeDesc>(style: ShapeStyle, shape: MarkerShape, size: &S) -> Self {
+        LegendGlyph::Marker(style, shape, size.in_pixels(&LEGEND_GLYPH_SIZE))
+    }
+
+    /// Create the element for the legend glyph
+    /// - `pos`: The left-middle point of the legend area
+    pub(crate) fn make_element<'a, DB: DrawingBackend>(
+        &self,
+        (x, y): BackendCoord,
+    ) -> DynElement<'a, DB, BackendCoord> {
+        match self {
+            LegendGlyph::Line(style) => {
+                PathElement::new(vec![(x, y), (x + 20, y)], style.clone()).into_dyn()
+            }
+            LegendGlyph::Marker(style, shape, size) => {
+                let (pos, style) = ((x + 10, y), style.clone());
+                match shape {
+                    MarkerShape::Circle => Circle::make_point(pos, *size, style).into_dyn(),
+                    MarkerShape::Cross => Cross::make_point(pos, *size, style).into_dyn(),
+                    MarkerShape::Triangle => {
+                        TriangleMarker::make_point(pos, *size, style).into_dyn()
+                    }
+                    MarkerShape::Pixel => Pixel::make_point(pos, *size, style).into_dyn(),
+                }
+            }
+            LegendGlyph::Area(style) => {
+                Rectangle::new([(x, y - 5), (x + 20, y + 5)], style.filled()).into_dyn()
+            }
+        }
+    }
+}
+
+/// The trait for the series which are able to derive the legend glyph from its style
+pub trait HasLegendGlyph {
+    /// Get the legend glyph of the series
+    fn legend_glyph(&self) -> LegendGlyph;
+}
+
 /// Describes where we want to put the series label
 pub enum SeriesLabelPosition {
     UpperLeft,
@@ -21,6 +85,34 @@ pub enum SeriesLabelPosition {
 }
 
 impl SeriesLabelPosition {
+    /// Compute the upper left corner of the box placed inside of the area at this position
+    /// - `dim`: The size of the box
+    /// - `area_dim`: The size of the area
+    /// - `margin`: The distance between the box and the border of the area
+    pub(super) fn layout_in_area(
+        &self,
+        dim: (i32, i32),
+        area_dim: (u32, u32),
+        margin: i32,
+    ) -> (i32, i32) {
+        use SeriesLabelPosition::*;
+        let (area_w, area_h) = (area_dim.0 as i32, area_dim.1 as i32);
+        (
+            match self {
+                UpperLeft | MiddleLeft | LowerLeft => margin,
+                UpperMiddle | MiddleMiddle | LowerMiddle => (area_w - dim.0) / 2,
+                UpperRight | MiddleRight | LowerRight => area_w - dim.0 - margin,
+                Coordinate(x, _) => *x,
+            },
+            match self {
+                UpperLeft | UpperMiddle | UpperRight => margin,
+                MiddleLeft | MiddleMiddle | MiddleRight => (area_h - dim.1) / 2,
+                LowerLeft | LowerMiddle | LowerRight => area_h - dim.1 - margin,
+                Coordinate(_, y) => *y,
+            },
+        )
+    }
+
     fn layout_label_area(&self, label_dim: (i32, i32), area_dim: (u32, u32)) -> (i32, i32) {
         use SeriesLabelPosition::*;
         (
@@ -53,6 +145,7 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    anchors: Option<&'b AnchorRegistry>,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -65,6 +158,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            anchors: None,
         }
     }
 
@@ -109,6 +203,15 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Register the position of each legend glyph in the anchor registry, so that it can be
+    /// connected to its series later. The anchor name is `AnchorRegistry::legend_name` of the
+    /// series label.
+    /// - `registry`: The anchor registry
+    pub fn anchors(&mut self, registry: &'b AnchorRegistry) -> &mut Self {
+        self.anchors = Some(registry);
+        self
+    }
+
     /// Set the series label font
     /// `font` - The font
     pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
@@ -133,6 +236,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
 
         let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
         let mut funcs = vec![];
+        let mut labels = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -142,9 +246,11 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 continue;
             }
 
-            funcs.push(
+            funcs.push((
                 draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
-            );
+                anno.is_legend_stamped(),
+            ));
+            labels.push(label_text);
             label_element.push_line(label_text);
         }
 
@@ -176,14 +282,31 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         ))?;
         drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
+        let (base_x, base_y) = drawing_area.get_base_pixel();
+        for ((((_, y0), (_, y1)), (make_elem, stamped)), label) in label_element
             .compute_line_layout()
             .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
             .into_iter()
             .zip(funcs.into_iter())
+            .zip(labels)
         {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+            if let Some(anchors) = self.anchors {
+                anchors.register(
+                    AnchorRegistry::legend_name(label),
+                    (base_x + label_x + margin, base_y + (y0 + y1) / 2),
+                );
+            }
+
+            let pos = (label_x + margin, (y0 + y1) / 2);
+            if stamped {
+                // The predefined glyph is stamped, so that it shares the definition with the
+                // identical markers on the backends which support it
+                let legend_element = make_elem((0, 0));
+                drawing_area.stamp_repeated(&legend_element, std::iter::once(pos))?;
+            } else {
+                let legend_element = make_elem(pos);
+                drawing_area.draw(&legend_element)?;
+            }
         }
 
         Ok(())
@@ -0,0 +1,168 @@
+use num_traits::ToPrimitive;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
+
+/// The axis of the value which has the problem
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueAxis {
+    X,
+    Y,
+}
+
+/// A problem found in the series data by `ChartContext::validate_series`
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeriesIssue {
+    /// The series has no points
+    Empty,
+    /// The columns of the X and Y values have different lengths
+    LengthMismatch { x_len: usize, y_len: usize },
+    /// The value of the point is NaN
+    NotANumber { index: usize, axis: ValueAxis },
+    /// The value of the point is infinite
+    Infinite { index: usize, axis: ValueAxis },
+    /// The value of the point is out of the range of the axis
+    OutOfRange { index: usize, axis: ValueAxis },
+}
+
+impl Display for SeriesIssue {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            SeriesIssue::Empty => write!(f, "the series is empty"),
+            SeriesIssue::LengthMismatch { x_len, y_len } => write!(
+                f,
+                "the series has {} X values but {} Y values",
+                x_len, y_len
+            ),
+            SeriesIssue::NotANumber { index, axis } => {
+                write!(f, "the {:?} value of point {} is NaN", axis, index)
+            }
+            SeriesIssue::Infinite { index, axis } => {
+                write!(f, "the {:?} value of point {} is infinite", axis, index)
+            }
+            SeriesIssue::OutOfRange { index, axis } => write!(
+                f,
+                "the {:?} value of point {} is out of the axis range",
+                axis, index
+            ),
+        }
+    }
+}
+
+/// The error returned by the series validation, which carries all the problems found
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesValidationError {
+    /// The problems in the order of the points
+    pub issues: Vec<SeriesIssue>,
+}
+
+impl Display for SeriesValidationError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "invalid series data: ")?;
+        for (idx, issue) in self.issues.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SeriesValidationError {}
+
+/// Check the value against its axis range
+fn check_value<T: ToPrimitive>(
+    value: &T,
+    range: &Range<f64>,
+    index: usize,
+    axis: ValueAxis,
+) -> Option<SeriesIssue> {
+    match value.to_f64() {
+        Some(v) if v.is_nan() => Some(SeriesIssue::NotANumber { index, axis }),
+        Some(v) if v.is_infinite() => Some(SeriesIssue::Infinite { index, axis }),
+        Some(v) if range.start <= v && v <= range.end => None,
+        _ => Some(SeriesIssue::OutOfRange { index, axis }),
+    }
+}
+
+/// Convert the axis range to the inclusive range of the floating point numbers
+fn to_f64_range<T: ToPrimitive>(range: &Range<T>) -> Range<f64> {
+    let start = range.start.to_f64().unwrap_or(f64::NAN);
+    let end = range.end.to_f64().unwrap_or(f64::NAN);
+    start.min(end)..start.max(end)
+}
+
+/// Validate the points against the ranges of the axes
+/// - `points`: The points to validate
+/// - `x_range`: The range of the X axis
+/// - `y_range`: The range of the Y axis
+/// - **returns**: The problems found in the data
+pub(super) fn validate_points<'a, X, Y, I>(
+    points: I,
+    x_range: &Range<X>,
+    y_range: &Range<Y>,
+) -> Vec<SeriesIssue>
+where
+    X: ToPrimitive + 'a,
+    Y: ToPrimitive + 'a,
+    I: IntoIterator<Item = (&'a X, &'a Y)>,
+{
+    let (x_range, y_range) = (to_f64_range(x_range), to_f64_range(y_range));
+    let mut issues = vec![];
+    let mut empty = true;
+    for (index, (x, y)) in points.into_iter().enumerate() {
+        empty = false;
+        issues.extend(check_value(x, &x_range, index, ValueAxis::X));
+        issues.extend(check_value(y, &y_range, index, ValueAxis::Y));
+    }
+    if empty {
+        issues.push(SeriesIssue::Empty);
+    }
+    issues
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_points() {
+        let xs = [0.0, f64::NAN, 2.0, 30.0];
+        let ys = [1, 2, 3, 4];
+        let issues = validate_points(xs.iter().zip(ys.iter()), &(0.0..10.0), &(0..3));
+        assert_eq!(
+            issues,
+            vec![
+                SeriesIssue::NotANumber {
+                    index: 1,
+                    axis: ValueAxis::X
+                },
+                SeriesIssue::OutOfRange {
+                    index: 3,
+                    axis: ValueAxis::X
+                },
+                SeriesIssue::OutOfRange {
+                    index: 3,
+                    axis: ValueAxis::Y
+                },
+            ]
+        );
+
+        let issues = validate_points(
+            std::iter::once((&f64::INFINITY, &1.0)),
+            &(0.0..10.0),
+            &(0.0..10.0),
+        );
+        assert_eq!(
+            issues,
+            vec![SeriesIssue::Infinite {
+                index: 0,
+                axis: ValueAxis::X
+            }]
+        );
+
+        let empty: Vec<(f64, f64)> = vec![];
+        let issues = validate_points(empty.iter().map(|(x, y)| (x, y)), &(0.0..1.0), &(0.0..1.0));
+        assert_eq!(issues, vec![SeriesIssue::Empty]);
+    }
+}
@@ -0,0 +1,221 @@
+use std::f64::consts::PI;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::{Ranged, ReversibleRanged};
+
+/// The steps of the angle key points, in the multiples of π/12
+const ANGLE_STEPS: [i64; 6] = [1, 2, 3, 6, 12, 24];
+
+/// The format of the angle labels
+pub trait AngleFormat {
+    /// Format the angle in radians as the label
+    fn format(angle: f64) -> String;
+}
+
+/// The angle labels as the fractions of π, for example "π/6" or "3π/2"
+#[derive(Clone)]
+pub struct PiFraction;
+
+/// The angle labels in degrees, for example "30°"
+#[derive(Clone)]
+pub struct Degrees;
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl AngleFormat for PiFraction {
+    fn format(angle: f64) -> String {
+        let twelfths = angle * 12.0 / PI;
+        if (twelfths - twelfths.round()).abs() > 1e-6 {
+            return format!("{:.2}", angle);
+        }
+        let twelfths = twelfths.round() as i64;
+        if twelfths == 0 {
+            return "0".to_string();
+        }
+        let divisor = gcd(twelfths, 12);
+        let (num, den) = (twelfths / divisor, 12 / divisor);
+        let num = match num {
+            1 => "".to_string(),
+            -1 => "-".to_string(),
+            num => num.to_string(),
+        };
+        if den == 1 {
+            format!("{}π", num)
+        } else {
+            format!("{}π/{}", num, den)
+        }
+    }
+}
+
+impl AngleFormat for Degrees {
+    fn format(angle: f64) -> String {
+        let degrees = angle.to_degrees();
+        if (degrees - degrees.round()).abs() < 1e-6 {
+            format!("{}°", degrees.round())
+        } else {
+            format!("{:.1}°", degrees)
+        }
+    }
+}
+
+/// The angular coordinate, such as `0.0..2π` or `-π..π`, whose values are in radians. The key
+/// points are the multiples of π/12, π/6, π/4 and so on, and the labels are the fractions of π
+/// or the degrees depending on the format `F`.
+pub struct AngleCoord<F: AngleFormat = PiFraction> {
+    start: f64,
+    end: f64,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: AngleFormat> Clone for AngleCoord<F> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            end: self.end,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F: AngleFormat> From<Range<f64>> for AngleCoord<F> {
+    fn from(range: Range<f64>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F: AngleFormat> Ranged for AngleCoord<F> {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        let actual_length = f64::from(limit.1 - limit.0);
+        if self.end == self.start {
+            return limit.0 + (actual_length / 2.0) as i32;
+        }
+        let logic_length = (value - self.start) / (self.end - self.start);
+        limit.0 + (actual_length * logic_length + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        if max_points == 0 {
+            return vec![];
+        }
+
+        // Work in the multiples of π/12, so that the key points are exact
+        let unit = PI / 12.0;
+        let (lo, hi) = (self.start.min(self.end), self.start.max(self.end));
+        let (lo, hi) = (
+            (lo / unit - 1e-9).ceil() as i64,
+            (hi / unit + 1e-9).floor() as i64,
+        );
+        let count = |step: i64| {
+            let first = lo.div_euclid(step) * step + if lo % step == 0 { 0 } else { step };
+            if first > hi {
+                (first, 0)
+            } else {
+                (first, ((hi - first) / step + 1) as usize)
+            }
+        };
+
+        let mut step = ANGLE_STEPS
+            .iter()
+            .cloned()
+            .find(|step| count(*step).1 <= max_points)
+            .unwrap_or(24);
+        while count(step).1 > max_points {
+            step *= 2;
+        }
+
+        let (first, n) = count(step);
+        (0..n as i64)
+            .map(|idx| (first + idx * step) as f64 * unit)
+            .collect()
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.start..self.end
+    }
+
+    fn format_label(value: &f64) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        F::format(*value)
+    }
+}
+
+impl<F: AngleFormat> ReversibleRanged for AngleCoord<F> {
+    fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<f64> {
+        if p < min.min(max) || p > max.max(min) || min == max {
+            return None;
+        }
+        let logical_offset = f64::from(p - min) / f64::from(max - min);
+        Some((self.end - self.start) * logical_offset + self.start)
+    }
+}
+
+/// The trait that converts a range of radians into an angular coordinate
+pub trait IntoAngleCoord {
+    /// The angular coordinate labeled with the fractions of π
+    fn pi_angles(self) -> AngleCoord<PiFraction>;
+    /// The angular coordinate labeled with the degrees
+    fn degree_angles(self) -> AngleCoord<Degrees>;
+}
+
+impl IntoAngleCoord for Range<f64> {
+    fn pi_angles(self) -> AngleCoord<PiFraction> {
+        self.into()
+    }
+
+    fn degree_angles(self) -> AngleCoord<Degrees> {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_angle_coord() {
+        let coord = (0.0..2.0 * PI).pi_angles();
+        let labels: Vec<_> = coord
+            .key_points(8)
+            .iter()
+            .map(AngleCoord::<PiFraction>::format_label)
+            .collect();
+        assert_eq!(labels, vec!["0", "π/2", "π", "3π/2", "2π"]);
+
+        let labels: Vec<_> = coord
+            .key_points(13)
+            .iter()
+            .map(AngleCoord::<PiFraction>::format_label)
+            .collect();
+        assert_eq!(labels[1], "π/6");
+        assert_eq!(labels[10], "5π/3");
+        assert_eq!(labels.len(), 13);
+
+        let coord = (-PI..PI).degree_angles();
+        let labels: Vec<_> = coord
+            .key_points(9)
+            .iter()
+            .map(AngleCoord::<Degrees>::format_label)
+            .collect();
+        assert_eq!(
+            labels,
+            vec!["-180°", "-135°", "-90°", "-45°", "0°", "45°", "90°", "135°", "180°"]
+        );
+        assert_eq!(coord.map(&0.0, (0, 100)), 50);
+    }
+}
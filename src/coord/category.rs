@@ -1,8 +1,11 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 use std::ops::Range;
 use std::rc::Rc;
 
-use super::{AsRangedCoord, Ranged};
+use super::{AsRangedCoord, DiscreteRanged, Ranged};
 
 /// The category coordinate
 pub struct Category<T: PartialEq> {
@@ -49,6 +52,134 @@ impl<T: PartialEq> Category<T> {
         }
     }
 
+    /// Create a new category coordinate from the keys of the data, e.g. the keys of the hash
+    /// maps of multiple series. The duplicated keys are removed and the first occurrence of
+    /// each key determines the order, use `sorted_by` or `with_order` to reorder the elements.
+    ///
+    /// - `name`: The name of the category
+    /// - `keys`: The keys of the data
+    /// - **returns** The newly created category coordinate
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let category = Category::from_keys("color", vec!["red", "green", "red", "blue"]);
+    /// assert_eq!(category.len(), 3);
+    /// ```
+    pub fn from_keys<S: Into<String>, I: IntoIterator<Item = T>>(name: S, keys: I) -> Self {
+        let mut elements = vec![];
+        for key in keys {
+            if !elements.contains(&key) {
+                elements.push(key);
+            }
+        }
+        Self::new(name, elements)
+    }
+
+    /// Get the index of the element on the axis by its value.
+    ///
+    /// - `val`: The value of the element
+    /// - **returns** The optional index, which is zero for the first element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let category = Category::new("color", vec!["red", "green", "blue"]);
+    /// assert_eq!(category.index_of(&"green"), Some(1));
+    /// ```
+    pub fn index_of(&self, val: &T) -> Option<usize> {
+        self.elements.iter().position(|x| x == val)
+    }
+
+    /// Get the stable map from the elements to their indices on the axis, which is useful for
+    /// aligning multiple series built from hash maps.
+    ///
+    /// - **returns** The map from the elements to the indices
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let category = Category::new("color", vec!["red", "green", "blue"]);
+    /// assert_eq!(category.key_map()["blue"], 2);
+    /// ```
+    pub fn key_map(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(idx, element)| (element.clone(), idx))
+            .collect()
+    }
+
+    /// Reorder the elements with a comparator, the elements comparing equal keep their order.
+    ///
+    /// - `compare`: The comparator of the elements
+    /// - **returns** The reordered category coordinate
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let category = Category::new("color", vec!["red", "green", "blue"]).sorted_by(|a, b| a.cmp(b));
+    /// assert_eq!(category.index_of(&"blue"), Some(0));
+    /// ```
+    pub fn sorted_by<F: FnMut(&T, &T) -> Ordering>(self, compare: F) -> Self
+    where
+        T: Clone,
+    {
+        let name = self.name.clone();
+        let mut elements = self.into_elements();
+        elements.sort_by(compare);
+        Self::new(name, elements)
+    }
+
+    /// Reorder the elements by a key, the elements with the same key keep their order.
+    ///
+    /// - `key`: The function extracting the key of an element
+    /// - **returns** The reordered category coordinate
+    pub fn sorted_by_key<K: Ord, F: FnMut(&T) -> K>(self, key: F) -> Self
+    where
+        T: Clone,
+    {
+        let name = self.name.clone();
+        let mut elements = self.into_elements();
+        elements.sort_by_key(key);
+        Self::new(name, elements)
+    }
+
+    /// Reorder the elements with an explicit ordering list. The listed elements come first in
+    /// the order of the list, followed by the rest of the elements in their original order.
+    ///
+    /// - `order`: The ordering list, the values which aren't elements are ignored
+    /// - **returns** The reordered category coordinate
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let category = Category::new("color", vec!["red", "green", "blue"]).with_order(&["blue"]);
+    /// assert_eq!(category.index_of(&"blue"), Some(0));
+    /// assert_eq!(category.index_of(&"red"), Some(1));
+    /// ```
+    pub fn with_order(self, order: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        self.sorted_by_key(|element| {
+            order
+                .iter()
+                .position(|x| x == element)
+                .unwrap_or(order.len())
+        })
+    }
+
+    fn into_elements(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        Rc::try_unwrap(self.elements).unwrap_or_else(|elements| elements.to_vec())
+    }
+
     /// Get an element reference (tick) by its value.
     ///
     /// - `val`: The value of the element
@@ -173,6 +304,24 @@ impl<T: PartialEq> Ranged for Category<T> {
     }
 }
 
+impl<T: PartialEq> DiscreteRanged for Category<T> {
+    type RangeParameter = ();
+
+    fn get_range_parameter(&self) {}
+
+    fn next_value(this: &Self::ValueType, _: &()) -> Self::ValueType {
+        let mut ret = this.clone();
+        ret.idx += 1;
+        ret
+    }
+
+    fn previous_value(this: &Self::ValueType, _: &()) -> Self::ValueType {
+        let mut ret = this.clone();
+        ret.idx -= 1;
+        ret
+    }
+}
+
 impl<T: PartialEq> AsRangedCoord for Category<T> {
     type CoordDescType = Self;
     type Value = Category<T>;
@@ -204,4 +353,28 @@ mod test {
         assert_eq!(category.map(&category.get(&"red").unwrap(), (10, 20)), 12);
         assert_eq!(category.key_points(5).len(), 3);
     }
+
+    #[test]
+    fn test_ordering() {
+        let category = Category::from_keys("size", vec![3, 1, 3, 2, 1]);
+        assert_eq!(*category.elements, vec![3, 1, 2]);
+
+        let sorted = category.clone().sorted_by(|a, b| b.cmp(a));
+        assert_eq!(*sorted.elements, vec![3, 2, 1]);
+        assert_eq!(*category.elements, vec![3, 1, 2]);
+
+        let ordered = category.with_order(&[2, 4]);
+        assert_eq!(*ordered.elements, vec![2, 3, 1]);
+        assert_eq!(ordered.index_of(&1), Some(2));
+        assert_eq!(ordered.key_map()[&3], 1);
+        assert_eq!(ordered.get(&2).unwrap().idx, 0);
+    }
+
+    #[test]
+    fn test_discrete_ranged_trait() {
+        let category = Category::new("color", vec!["red", "green", "blue"]);
+        let green = category.get(&"green").unwrap();
+        assert_eq!(Category::next_value(&green, &()).idx, 2);
+        assert_eq!(Category::previous_value(&green, &()).idx, 0);
+    }
 }
@@ -1,5 +1,5 @@
 /// The datetime coordinates
-use chrono::{Date, DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike};
+use chrono::{Date, DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike, Weekday};
 use std::ops::Range;
 
 use super::{AsRangedCoord, DiscreteRanged, Ranged};
@@ -413,6 +413,299 @@ impl<T: TimeValue> IntoYearly<T> for Range<T> {
     }
 }
 
+/// The fiscal calendar, whose fiscal years start on the first day of the given month and are
+/// named after the calendar year they end in, for example "FY24" starts on October 1st, 2023
+/// when the fiscal year starts in October.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FiscalCalendar {
+    start_month: u32,
+}
+
+impl FiscalCalendar {
+    /// Create a new fiscal calendar
+    /// - `start_month`: The first month of the fiscal year, from 1 to 12
+    pub fn new(start_month: u32) -> Self {
+        Self {
+            start_month: start_month.clamp(1, 12),
+        }
+    }
+
+    /// Get the fiscal year of the date
+    pub fn fiscal_year<D: Datelike>(&self, date: &D) -> i32 {
+        if self.start_month != 1 && date.month() >= self.start_month {
+            date.year() + 1
+        } else {
+            date.year()
+        }
+    }
+
+    /// Get the fiscal quarter of the date, from 1 to 4
+    pub fn quarter<D: Datelike>(&self, date: &D) -> u32 {
+        self.fiscal_month(date) / 3 + 1
+    }
+
+    /// Format the time value as the fiscal quarter label, for example "FY24 Q3"
+    pub fn format<T: TimeValue>(&self, value: &T) -> String {
+        let date = value.date_floor();
+        format!(
+            "FY{:02} Q{}",
+            self.fiscal_year(&date).rem_euclid(100),
+            self.quarter(&date)
+        )
+    }
+
+    /// The months since the beginning of the fiscal year
+    fn fiscal_month<D: Datelike>(&self, date: &D) -> u32 {
+        (date.month() + 12 - self.start_month) % 12
+    }
+
+    /// The index of the quarter counted from the fiscal year 0
+    fn quarter_index<D: Datelike>(&self, date: &D) -> i32 {
+        self.fiscal_year(date) * 4 + self.quarter(date) as i32 - 1
+    }
+
+    fn is_quarter_start<D: Datelike>(&self, date: &D) -> bool {
+        date.day() == 1 && self.fiscal_month(date).is_multiple_of(3)
+    }
+
+    /// The first day of the quarter with the given index
+    fn quarter_start<Z: TimeZone>(&self, idx: i32, tz: &Z) -> Date<Z> {
+        let fiscal_year = idx.div_euclid(4);
+        let mut year = if self.start_month == 1 {
+            fiscal_year
+        } else {
+            fiscal_year - 1
+        };
+        let mut month = self.start_month + 3 * idx.rem_euclid(4) as u32;
+        if month > 12 {
+            month -= 12;
+            year += 1;
+        }
+        tz.ymd(year, month, 1)
+    }
+}
+
+/// Indicates the coord has a fiscal quarter granularity. The key points are the first days of
+/// the fiscal quarters, or of the fiscal years for the longer ranges, and they can be labeled
+/// with `FiscalCalendar::format`.
+#[derive(Clone)]
+pub struct FiscalQuarterly<T: TimeValue> {
+    range: Range<T>,
+    calendar: FiscalCalendar,
+}
+
+impl<T: TimeValue + Clone> FiscalQuarterly<T> {
+    /// Get the fiscal calendar of the coordinate
+    pub fn calendar(&self) -> FiscalCalendar {
+        self.calendar
+    }
+}
+
+impl<T: TimeValue + Clone> AsRangedCoord for FiscalQuarterly<T> {
+    type CoordDescType = FiscalQuarterly<T>;
+    type Value = T;
+}
+
+/// The next number in the sequence of 1, 2 and 5 times a power of 10
+fn next_nice_number(n: i32) -> i32 {
+    let mut exp10 = 1;
+    while exp10 * 10 <= n {
+        exp10 *= 10;
+    }
+    if n / exp10 == 2 {
+        n / 2 * 5
+    } else {
+        n * 2
+    }
+}
+
+/// The next step of the fiscal quarter key points: 1 and 2 quarters, and then 1, 2 and 5 times
+/// a power of 10 fiscal years
+fn next_quarter_step(step: i32) -> i32 {
+    if step < 4 {
+        step * 2
+    } else {
+        next_nice_number(step / 4) * 4
+    }
+}
+
+impl<T: TimeValue + Clone> Ranged for FiscalQuarterly<T> {
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.range.start.clone()..self.range.end.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        T::map_coord(value, &self.range.start, &self.range.end, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        let start_date = self.range.start.date_ceil();
+        let end_date = self.range.end.date_floor();
+
+        let mut start_idx = self.calendar.quarter_index(&start_date);
+        if !self.calendar.is_quarter_start(&start_date) {
+            start_idx += 1;
+        }
+        let end_idx = self.calendar.quarter_index(&end_date);
+
+        if max_points == 0 || end_idx < start_idx {
+            return vec![];
+        }
+
+        let first_idx = |step: i32| (start_idx + step - 1).div_euclid(step) * step;
+        let count = |step: i32| (end_idx - first_idx(step) + step).max(0) as usize / step as usize;
+
+        let mut step = 1;
+        while count(step) > max_points {
+            step = next_quarter_step(step);
+        }
+
+        let tz = self.range.start.timezone();
+        (first_idx(step)..=end_idx)
+            .step_by(step as usize)
+            .map(|idx| T::earliest_after_date(self.calendar.quarter_start(idx, &tz)))
+            .collect()
+    }
+}
+
+impl<T: TimeValue + Clone> DiscreteRanged for FiscalQuarterly<T> {
+    type RangeParameter = FiscalCalendar;
+    fn get_range_parameter(&self) -> FiscalCalendar {
+        self.calendar
+    }
+
+    fn next_value(this: &T, calendar: &FiscalCalendar) -> T {
+        let idx = calendar.quarter_index(&this.date_floor()) + 1;
+        T::earliest_after_date(calendar.quarter_start(idx, &this.timezone()))
+    }
+
+    fn previous_value(this: &T, calendar: &FiscalCalendar) -> T {
+        let date = this.date_ceil();
+        let mut idx = calendar.quarter_index(&date);
+        if calendar.is_quarter_start(&date) {
+            idx -= 1;
+        }
+        T::earliest_after_date(calendar.quarter_start(idx, &this.timezone()))
+    }
+}
+
+/// The trait that converts a normal date coord into a fiscal quarterly one
+pub trait IntoFiscalQuarterly<T: TimeValue> {
+    fn fiscal_quarterly(self, calendar: FiscalCalendar) -> FiscalQuarterly<T>;
+}
+
+impl<T: TimeValue> IntoFiscalQuarterly<T> for Range<T> {
+    fn fiscal_quarterly(self, calendar: FiscalCalendar) -> FiscalQuarterly<T> {
+        FiscalQuarterly {
+            range: self,
+            calendar,
+        }
+    }
+}
+
+/// The ranged coordinate for date, whose key points are the Mondays starting the ISO weeks.
+/// The key points can be labeled like "2024-W07" with `RangedWeek::format`.
+#[derive(Clone)]
+pub struct RangedWeek<Z: TimeZone>(Date<Z>, Date<Z>);
+
+/// The index of the week since the Monday of January 1st, 1 CE
+fn week_index<D: Datelike>(date: &D) -> i32 {
+    (date.num_days_from_ce() - 1).div_euclid(7)
+}
+
+/// The next step of the week key points: 1, 2, 4, 13 and 26 weeks, and then 1, 2 and 5 times
+/// a power of 10 years of 52 weeks
+fn next_week_step(step: i32) -> i32 {
+    match step {
+        1 => 2,
+        2 => 4,
+        4 => 13,
+        13 => 26,
+        26 => 52,
+        step => next_nice_number(step / 52) * 52,
+    }
+}
+
+impl<Z: TimeZone> RangedWeek<Z> {
+    /// Format the date as the ISO week label, for example "2024-W07"
+    pub fn format(date: &Date<Z>) -> String {
+        let week = date.iso_week();
+        format!("{}-W{:02}", week.year(), week.week())
+    }
+}
+
+impl<Z: TimeZone> From<Range<Date<Z>>> for RangedWeek<Z> {
+    fn from(range: Range<Date<Z>>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl<Z: TimeZone> Ranged for RangedWeek<Z> {
+    type ValueType = Date<Z>;
+
+    fn range(&self) -> Range<Date<Z>> {
+        self.0.clone()..self.1.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        TimeValue::map_coord(value, &self.0, &self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        let mut start_week = week_index(&self.0);
+        if self.0.weekday() != Weekday::Mon {
+            start_week += 1;
+        }
+        let end_week = week_index(&self.1);
+
+        if max_points == 0 || end_week < start_week {
+            return vec![];
+        }
+
+        let first_week = |step: i32| (start_week + step - 1).div_euclid(step) * step;
+        let count =
+            |step: i32| (end_week - first_week(step) + step).max(0) as usize / step as usize;
+
+        let mut step = 1;
+        while count(step) > max_points {
+            step = next_week_step(step);
+        }
+
+        let first = self.0.clone()
+            + Duration::days(i64::from(
+                first_week(step) * 7 + 1 - self.0.num_days_from_ce(),
+            ));
+        (0..count(step))
+            .map(|idx| first.clone() + Duration::weeks(idx as i64 * i64::from(step)))
+            .collect()
+    }
+}
+
+impl<Z: TimeZone> DiscreteRanged for RangedWeek<Z> {
+    type RangeParameter = ();
+    fn get_range_parameter(&self) {}
+    fn next_value(this: &Date<Z>, _: &()) -> Date<Z> {
+        this.clone() + Duration::weeks(1)
+    }
+
+    fn previous_value(this: &Date<Z>, _: &()) -> Date<Z> {
+        this.clone() - Duration::weeks(1)
+    }
+}
+
+/// The trait that converts a date range into a weekly coord
+pub trait IntoWeekly<Z: TimeZone> {
+    fn weekly(self) -> RangedWeek<Z>;
+}
+
+impl<Z: TimeZone> IntoWeekly<Z> for Range<Date<Z>> {
+    fn weekly(self) -> RangedWeek<Z> {
+        self.into()
+    }
+}
+
 /// The ranged coordinate for the date and time
 #[derive(Clone)]
 pub struct RangedDateTime<Z: TimeZone>(DateTime<Z>, DateTime<Z>);
@@ -486,6 +779,128 @@ impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
     }
 }
 
+/// The units of the relative time labels, from the largest one
+const RELATIVE_TIME_UNITS: [(i64, &str); 5] = [
+    (86_400_000_000_000, "d"),
+    (3_600_000_000_000, "h"),
+    (60_000_000_000, "min"),
+    (1_000_000_000, "s"),
+    (1_000_000, "ms"),
+];
+
+/// The ranged coordinate for the date and time whose key points are aligned to a reference
+/// instant, such as the current time of a monitoring dashboard, so the reference itself is
+/// always a key point when it's in the range. The key points can be labeled relative to the
+/// reference like "-15 min" with `RangedRelativeTime::format`.
+#[derive(Clone)]
+pub struct RangedRelativeTime<Z: TimeZone> {
+    begin: DateTime<Z>,
+    end: DateTime<Z>,
+    reference: DateTime<Z>,
+}
+
+impl<Z: TimeZone> RangedRelativeTime<Z> {
+    /// Get the reference instant of the coordinate
+    pub fn reference(&self) -> &DateTime<Z> {
+        &self.reference
+    }
+
+    /// Format the time relative to the reference instant, for example "-15 min", "-1 h" or
+    /// "now". The label uses the largest unit which divides the offset exactly.
+    /// - `value`: The time to format
+    /// - `reference`: The reference instant
+    pub fn format(value: &DateTime<Z>, reference: &DateTime<Z>) -> String {
+        let offset = value.clone() - reference.clone();
+        let ns = match offset.num_nanoseconds() {
+            Some(ns) => ns,
+            None => return format!("{:+} d", offset.num_days()),
+        };
+        if ns == 0 {
+            return "now".to_string();
+        }
+        let sign = if ns < 0 { "-" } else { "+" };
+        let abs = ns.abs();
+        match RELATIVE_TIME_UNITS.iter().find(|(unit, _)| abs % unit == 0) {
+            Some((unit, name)) => format!("{}{} {}", sign, abs / unit, name),
+            None => format!("{}{} s", sign, abs as f64 / 1e9),
+        }
+    }
+}
+
+impl<Z: TimeZone> Ranged for RangedRelativeTime<Z> {
+    type ValueType = DateTime<Z>;
+
+    fn range(&self) -> Range<DateTime<Z>> {
+        self.begin.clone()..self.end.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        TimeValue::map_coord(value, &self.begin, &self.end, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        let total_ns = (self.end.clone() - self.begin.clone()).num_nanoseconds();
+        let begin_ns = (self.begin.clone() - self.reference.clone()).num_nanoseconds();
+        let end_ns = (self.end.clone() - self.reference.clone()).num_nanoseconds();
+
+        let (total_ns, begin_ns, end_ns) = match (total_ns, begin_ns, end_ns) {
+            (Some(total), Some(begin), Some(end)) if max_points > 0 && total > 0 => {
+                (total, begin, end)
+            }
+            _ => return vec![],
+        };
+
+        // The aligned key points may include both ends of the range, which is one point more
+        // than the period allows for, so a coarser period is picked in that case
+        let mut limit = max_points;
+        let (first, period) = loop {
+            let period = match compute_period_per_point(total_ns as u64, limit, false) {
+                Some(period) => period as i64,
+                None => return vec![],
+            };
+            let mut first = begin_ns.div_euclid(period);
+            if first * period < begin_ns {
+                first += 1;
+            }
+            let count = (end_ns.div_euclid(period) - first + 1).max(0) as usize;
+            if count <= max_points || limit == 1 {
+                break (first, period);
+            }
+            limit -= 1;
+        };
+
+        let mut ret = vec![];
+        let mut idx = first;
+        while idx * period <= end_ns && ret.len() < max_points {
+            ret.push(self.reference.clone() + Duration::nanoseconds(idx * period));
+            idx += 1;
+        }
+        ret
+    }
+}
+
+impl<Z: TimeZone> AsRangedCoord for RangedRelativeTime<Z> {
+    type CoordDescType = Self;
+    type Value = DateTime<Z>;
+}
+
+/// The trait that converts a date and time range into a coord labeled relative to a reference
+pub trait IntoRelativeTime<Z: TimeZone> {
+    /// Make the coord whose key points are aligned to the reference instant
+    /// - `reference`: The reference instant, usually the current time
+    fn relative_to(self, reference: DateTime<Z>) -> RangedRelativeTime<Z>;
+}
+
+impl<Z: TimeZone> IntoRelativeTime<Z> for Range<DateTime<Z>> {
+    fn relative_to(self, reference: DateTime<Z>) -> RangedRelativeTime<Z> {
+        RangedRelativeTime {
+            begin: self.start,
+            end: self.end,
+            reference,
+        }
+    }
+}
+
 /// The coordinate that for duration of time
 #[derive(Clone)]
 pub struct RangedDuration(Duration, Duration);
@@ -669,6 +1084,70 @@ mod test {
     use super::*;
     use chrono::{TimeZone, Utc};
 
+    #[test]
+    fn test_ranged_week() {
+        let coord = (Utc.ymd(2024, 1, 10)..Utc.ymd(2024, 3, 1)).weekly();
+
+        let labels: Vec<_> = coord
+            .key_points(10)
+            .iter()
+            .map(RangedWeek::format)
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "2024-W03", "2024-W04", "2024-W05", "2024-W06", "2024-W07", "2024-W08", "2024-W09"
+            ]
+        );
+
+        assert_eq!(
+            coord.key_points(3),
+            vec![
+                Utc.ymd(2024, 1, 22),
+                Utc.ymd(2024, 2, 5),
+                Utc.ymd(2024, 2, 19)
+            ]
+        );
+
+        assert_eq!(RangedWeek::format(&Utc.ymd(2024, 12, 30)), "2025-W01");
+    }
+
+    #[test]
+    fn test_fiscal_quarterly() {
+        let calendar = FiscalCalendar::new(10);
+        let coord = (Utc.ymd(2023, 10, 1)..Utc.ymd(2024, 12, 31)).fiscal_quarterly(calendar);
+
+        let labels: Vec<_> = coord
+            .key_points(10)
+            .iter()
+            .map(|d| calendar.format(d))
+            .collect();
+        assert_eq!(
+            labels,
+            vec!["FY24 Q1", "FY24 Q2", "FY24 Q3", "FY24 Q4", "FY25 Q1"]
+        );
+
+        assert_eq!(
+            coord.key_points(2),
+            vec![Utc.ymd(2023, 10, 1), Utc.ymd(2024, 10, 1)]
+        );
+
+        let calendar = FiscalCalendar::new(1);
+        let coord = (Utc.ymd(2000, 2, 1)..Utc.ymd(2030, 1, 1)).fiscal_quarterly(calendar);
+        let kps = coord.key_points(4);
+        assert_eq!(kps[0], Utc.ymd(2010, 1, 1));
+        assert_eq!(kps.len(), 3);
+
+        assert_eq!(
+            FiscalQuarterly::next_value(&Utc.ymd(2024, 2, 10), &calendar),
+            Utc.ymd(2024, 4, 1)
+        );
+        assert_eq!(
+            FiscalQuarterly::previous_value(&Utc.ymd(2024, 4, 1), &calendar),
+            Utc.ymd(2024, 1, 1)
+        );
+    }
+
     #[test]
     fn test_date_range_long() {
         let range = Utc.ymd(1000, 1, 1)..Utc.ymd(2999, 1, 1);
@@ -903,6 +1382,36 @@ mod test {
         assert_eq!(max, 2);
     }
 
+    #[test]
+    fn test_relative_time_range() {
+        let now = Utc.ymd(2019, 1, 1).and_hms(12, 7, 0);
+        let coord = (now - Duration::hours(1)..now).relative_to(now);
+
+        let labels: Vec<_> = coord
+            .key_points(4)
+            .iter()
+            .map(|t| RangedRelativeTime::format(t, coord.reference()))
+            .collect();
+        assert_eq!(labels, vec!["-1 h", "-40 min", "-20 min", "now"]);
+
+        for max_points in 1..20 {
+            assert!(coord.key_points(max_points).len() <= max_points);
+        }
+
+        assert_eq!(
+            RangedRelativeTime::format(&(now + Duration::seconds(90)), &now),
+            "+90 s"
+        );
+        assert_eq!(
+            RangedRelativeTime::format(&(now - Duration::days(2)), &now),
+            "-2 d"
+        );
+        assert_eq!(
+            RangedRelativeTime::format(&(now + Duration::microseconds(1500)), &now),
+            "+0.0015 s"
+        );
+    }
+
     #[test]
     fn test_duration_long_range() {
         let coord: RangedDuration = (Duration::days(-1000000)..Duration::days(1000000)).into();
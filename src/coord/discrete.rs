@@ -0,0 +1,285 @@
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::{AsRangedCoord, DiscreteRanged, Ranged};
+
+/// The types with a small fixed set of values, such as `bool` and the fieldless enums, which can
+/// be used as a discrete axis directly. The labels of the axis come from `Display`.
+///
+/// Use the `discrete_values!` macro to implement it for an enum.
+pub trait DiscreteValues: Sized + Clone + PartialEq + Display + 'static {
+    /// All the values, in the order they are placed on the axis
+    const VALUES: &'static [Self];
+
+    /// Get the position of the value in `VALUES`
+    fn value_index(&self) -> usize {
+        Self::VALUES.iter().position(|v| v == self).unwrap_or(0)
+    }
+}
+
+impl DiscreteValues for bool {
+    const VALUES: &'static [bool] = &[false, true];
+}
+
+/// Implement `DiscreteValues` for a fieldless enum, so that it can be used as a discrete axis
+///
+/// ```rust
+/// use plotters::coord::RangedDiscreteValues;
+/// use plotters::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Status {
+///     Passed,
+///     Failed,
+/// }
+///
+/// impl std::fmt::Display for Status {
+///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         write!(f, "{}", if *self == Status::Passed { "passed" } else { "failed" })
+///     }
+/// }
+///
+/// plotters::discrete_values!(Status: Status::Passed, Status::Failed);
+///
+/// let coord: RangedDiscreteValues<Status> = (Status::Passed..Status::Failed).into();
+/// assert_eq!(coord.key_points(10), vec![Status::Passed, Status::Failed]);
+/// ```
+#[macro_export]
+macro_rules! discrete_values {
+    ($type:ty : $($value:expr),+ $(,)?) => {
+        impl $crate::coord::DiscreteValues for $type {
+            const VALUES: &'static [Self] = &[$($value),+];
+        }
+    };
+}
+
+/// The discrete coordinate of a `DiscreteValues` type, each value is placed in the middle of its
+/// slot. Unlike the numeric ranges, the range includes both ends.
+pub struct RangedDiscreteValues<T: DiscreteValues> {
+    start: usize,
+    end: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: DiscreteValues> Clone for RangedDiscreteValues<T> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            end: self.end,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: DiscreteValues> From<Range<T>> for RangedDiscreteValues<T> {
+    fn from(range: Range<T>) -> Self {
+        let (start, end) = (range.start.value_index(), range.end.value_index());
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: DiscreteValues> Ranged for RangedDiscreteValues<T> {
+    type ValueType = T;
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        let count = (self.end - self.start + 1) as f64;
+        let pos = value.value_index() as f64 - self.start as f64 + 0.5;
+        limit.0 + (f64::from(limit.1 - limit.0) * pos / count) as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<T> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let count = self.end - self.start + 1;
+        let step = count.div_ceil(max_points);
+        T::VALUES[self.start..=self.end]
+            .iter()
+            .step_by(step)
+            .cloned()
+            .collect()
+    }
+
+    fn range(&self) -> Range<T> {
+        T::VALUES[self.start].clone()..T::VALUES[self.end].clone()
+    }
+
+    fn format_label(value: &T) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        value.to_string()
+    }
+}
+
+impl<T: DiscreteValues> DiscreteRanged for RangedDiscreteValues<T> {
+    type RangeParameter = ();
+
+    fn get_range_parameter(&self) {}
+
+    /// The next value, which saturates at the last value
+    fn next_value(this: &T, _: &()) -> T {
+        let idx = (this.value_index() + 1).min(T::VALUES.len() - 1);
+        T::VALUES[idx].clone()
+    }
+
+    /// The previous value, which saturates at the first value
+    fn previous_value(this: &T, _: &()) -> T {
+        let idx = this.value_index().max(1) - 1;
+        T::VALUES[idx].clone()
+    }
+}
+
+impl<T: DiscreteValues> AsRangedCoord for Range<T> {
+    type CoordDescType = RangedDiscreteValues<T>;
+    type Value = T;
+}
+
+/// The ranged coordinate for characters, such as the rows `'A'..'H'` of a plate or the columns
+/// of a grid reference. Like the integer coordinates, both ends of the range map to the edges of
+/// the axis.
+#[derive(Clone)]
+pub struct RangedCoordChar(char, char);
+
+impl From<Range<char>> for RangedCoordChar {
+    fn from(range: Range<char>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl Ranged for RangedCoordChar {
+    type ValueType = char;
+
+    fn map(&self, value: &char, limit: (i32, i32)) -> i32 {
+        let span = f64::from(self.1 as u32) - f64::from(self.0 as u32);
+        let actual_length = f64::from(limit.1 - limit.0);
+        if span == 0.0 {
+            return limit.0 + (actual_length / 2.0) as i32;
+        }
+        let logic_length = (f64::from(*value as u32) - f64::from(self.0 as u32)) / span;
+        limit.0 + (actual_length * logic_length + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<char> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let (start, end) = (self.0.min(self.1) as u32, self.0.max(self.1) as u32);
+        let count = (end - start + 1) as usize;
+        let step = count.div_ceil(max_points);
+        (start..=end)
+            .step_by(step)
+            .filter_map(std::char::from_u32)
+            .collect()
+    }
+
+    fn range(&self) -> Range<char> {
+        self.0..self.1
+    }
+}
+
+impl DiscreteRanged for RangedCoordChar {
+    type RangeParameter = ();
+
+    fn get_range_parameter(&self) {}
+
+    fn next_value(this: &char, _: &()) -> char {
+        std::char::from_u32(*this as u32 + 1).unwrap_or(*this)
+    }
+
+    fn previous_value(this: &char, _: &()) -> char {
+        std::char::from_u32((*this as u32).max(1) - 1).unwrap_or(*this)
+    }
+}
+
+impl AsRangedCoord for Range<char> {
+    type CoordDescType = RangedCoordChar;
+    type Value = char;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::fmt;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Level {
+        Low,
+        Medium,
+        High,
+    }
+
+    impl fmt::Display for Level {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let name = match self {
+                Level::Low => "low",
+                Level::Medium => "medium",
+                Level::High => "high",
+            };
+            write!(f, "{}", name)
+        }
+    }
+
+    crate::discrete_values!(Level: Level::Low, Level::Medium, Level::High);
+
+    #[test]
+    fn test_char_coord() {
+        let coord: RangedCoordChar = ('A'..'H').into();
+        assert_eq!(coord.map(&'A', (0, 70)), 0);
+        assert_eq!(coord.map(&'D', (0, 70)), 30);
+        assert_eq!(coord.map(&'H', (0, 70)), 70);
+        assert_eq!(
+            coord.key_points(8),
+            vec!['A', 'B', 'C', 'D', 'E', 'F', 'G', 'H']
+        );
+        assert_eq!(coord.key_points(3), vec!['A', 'D', 'G']);
+        assert_eq!(RangedCoordChar::next_value(&'C', &()), 'D');
+        assert_eq!(RangedCoordChar::previous_value(&'C', &()), 'B');
+    }
+
+    #[test]
+    fn test_bool_coord() {
+        let coord: RangedDiscreteValues<bool> = (false..true).into();
+        assert_eq!(coord.map(&false, (0, 100)), 25);
+        assert_eq!(coord.map(&true, (0, 100)), 75);
+        assert_eq!(coord.key_points(10), vec![false, true]);
+        assert_eq!(RangedDiscreteValues::<bool>::format_label(&true), "true");
+        assert!(RangedDiscreteValues::next_value(&false, &()));
+        assert!(RangedDiscreteValues::next_value(&true, &()));
+    }
+
+    #[test]
+    fn test_enum_coord() {
+        let coord: RangedDiscreteValues<Level> = (Level::Medium..Level::High).into();
+        assert_eq!(coord.range(), Level::Medium..Level::High);
+        assert_eq!(coord.map(&Level::High, (0, 100)), 75);
+        assert_eq!(coord.key_points(1), vec![Level::Medium]);
+
+        let labels = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn.borrow_mut().push(text.to_string());
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_ranged(Level::Low..Level::High, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(std::iter::once(Circle::new((Level::Medium, 5), 3, &RED)))
+            .expect("Drawing error");
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"medium".to_string()));
+        assert!(!labels.contains(&"Medium".to_string()));
+    }
+}
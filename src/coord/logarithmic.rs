@@ -1,4 +1,5 @@
 use super::{AsRangedCoord, Ranged, RangedCoordf64};
+use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -134,3 +135,88 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.logic.clone()
     }
 }
+
+/// Write the exponent of the power of ten with the superscript digits, e.g. `10²`
+pub(crate) fn format_power(exp: i32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let mut ret = String::from("10");
+    if exp < 0 {
+        ret.push('⁻');
+    }
+    for c in exp.abs().to_string().bytes() {
+        ret.push(DIGITS[(c - b'0') as usize]);
+    }
+    ret
+}
+
+/// A log scaled coordinate axis which labels the values as the powers of ten, e.g. `10²` or
+/// `2×10³`. This is the axis created by `ChartBuilder::build_log_x` and its friends.
+pub struct PowerLogCoord<V: LogScalable>(LogCoord<V>);
+
+impl<V: LogScalable> From<Range<V>> for PowerLogCoord<V> {
+    fn from(range: Range<V>) -> Self {
+        PowerLogCoord(LogRange(range).into())
+    }
+}
+
+impl<V: LogScalable> Ranged for PowerLogCoord<V> {
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<V> {
+        self.0.key_points(max_points)
+    }
+
+    fn range(&self) -> Range<V> {
+        self.0.range()
+    }
+
+    fn format_label(value: &V) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        let v = value.as_f64();
+        if v <= 0.0 || !v.is_finite() {
+            return format!("{:?}", value);
+        }
+        let exp = v.log10().floor() as i32;
+        let mantissa = v / 10f64.powi(exp);
+        // The values close to the next power are rounded up by floor of the log
+        let (mantissa, exp) = if (mantissa - 10.0).abs() < 1e-9 {
+            (1.0, exp + 1)
+        } else {
+            (mantissa, exp)
+        };
+        if (mantissa - 1.0).abs() < 1e-9 {
+            format_power(exp)
+        } else if (mantissa - mantissa.round()).abs() < 1e-9 {
+            format!("{}×{}", mantissa.round(), format_power(exp))
+        } else {
+            format!("{:?}", value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_labels() {
+        let coord: PowerLogCoord<f64> = (1.0..1e4).into();
+        let labels: Vec<_> = coord
+            .key_points(4)
+            .iter()
+            .map(PowerLogCoord::<f64>::format_label)
+            .collect();
+        assert_eq!(labels, vec!["10⁰", "10¹", "10²", "10³", "10⁴"]);
+
+        assert_eq!(PowerLogCoord::<f64>::format_label(&0.01), "10⁻²");
+        assert_eq!(PowerLogCoord::<u32>::format_label(&2000), "2×10³");
+        assert_eq!(PowerLogCoord::<f64>::format_label(&2.5), "2.5");
+        assert_eq!(PowerLogCoord::<u32>::format_label(&25), "25");
+    }
+}
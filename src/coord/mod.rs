@@ -22,33 +22,48 @@ Also, the ranged axis can be deserted, and this is required by the histogram ser
 */
 use crate::drawing::backend::BackendCoord;
 
+mod angle;
 mod category;
 #[cfg(feature = "chrono")]
 mod datetime;
+mod discrete;
 mod logarithmic;
+mod network;
 mod numeric;
+mod percent;
 mod ranged;
 
 #[cfg(feature = "chrono")]
-pub use datetime::{IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration};
+pub use datetime::{
+    FiscalCalendar, FiscalQuarterly, IntoFiscalQuarterly, IntoMonthly, IntoRelativeTime,
+    IntoWeekly, IntoYearly, RangedDate, RangedDateTime, RangedDuration, RangedRelativeTime,
+    RangedWeek,
+};
 pub use numeric::{
-    RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
-    RangedCoordu128, RangedCoordu32, RangedCoordu64,
+    NiceRange, RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
+    RangedCoordu128, RangedCoordu32, RangedCoordu64, SteppableRange,
 };
 pub use ranged::{
     AsRangedCoord, DiscreteRanged, IntoCentric, IntoPartialAxis, MeshLine, Ranged, RangedCoord,
-    ReversibleRanged,
+    ReversibleRanged, SlotSize,
 };
 
 pub use ranged::make_partial_axis;
 
-pub use logarithmic::{LogCoord, LogRange, LogScalable};
+pub(crate) use logarithmic::format_power;
+pub use logarithmic::{LogCoord, LogRange, LogScalable, PowerLogCoord};
+
+pub use network::{port_service_name, IntoPortRange, RangedCoordIpv4, RangedCoordPort};
 
 pub use numeric::group_integer_by::{GroupBy, ToGroupByRange};
+use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::Arc;
 
+pub use angle::{AngleCoord, AngleFormat, Degrees, IntoAngleCoord, PiFraction};
 pub use category::Category;
+pub use discrete::{DiscreteValues, RangedCoordChar, RangedDiscreteValues};
+pub use percent::PercentCoord;
 
 /// The trait that translates some customized object to the backend coordinate
 pub trait CoordTranslate {
@@ -118,3 +133,34 @@ impl<T: ReverseCoordTranslate> ReverseCoordTranslate for ShiftAndTrans<T> {
         Some(self.1.reverse_translate(self.0.reverse_translate(input)?)?)
     }
 }
+
+/// The coordinate that converts the values with a function before translating them with the
+/// underlying coordinate, for example from radians to degrees, so that the data can be drawn
+/// in the units of the axes without making a converted copy of it.
+pub struct ConvertedCoord<'a, T: CoordTranslate, V, F: Fn(&V) -> T::From> {
+    coord: &'a T,
+    convert: F,
+    _phantom: PhantomData<V>,
+}
+
+impl<'a, T: CoordTranslate, V, F: Fn(&V) -> T::From> ConvertedCoord<'a, T, V, F> {
+    /// Create a new converted coordinate
+    /// - `coord`: The underlying coordinate
+    /// - `convert`: The function that converts the values to the underlying coordinate
+    pub fn new(coord: &'a T, convert: F) -> Self {
+        Self {
+            coord,
+            convert,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: CoordTranslate, V, F: Fn(&V) -> T::From> CoordTranslate
+    for ConvertedCoord<'a, T, V, F>
+{
+    type From = V;
+    fn translate(&self, from: &V) -> BackendCoord {
+        self.coord.translate(&(self.convert)(from))
+    }
+}
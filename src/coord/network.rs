@@ -0,0 +1,241 @@
+use std::fmt::Debug;
+use std::net::Ipv4Addr;
+use std::ops::Range;
+
+use super::{AsRangedCoord, DiscreteRanged, Ranged};
+
+/// Map the value of a numeric span to the pixel, the same way as the numeric coordinates
+fn map_span(value: f64, (start, end): (f64, f64), limit: (i32, i32)) -> i32 {
+    let actual_length = f64::from(limit.1 - limit.0);
+    if end == start {
+        return limit.0 + (actual_length / 2.0) as i32;
+    }
+    limit.0 + (actual_length * (value - start) / (end - start) + 1e-3).floor() as i32
+}
+
+/// The multiples of the step within the range
+fn aligned_points((start, end): (u64, u64), step: u64) -> impl Iterator<Item = u64> {
+    let first = start.div_ceil(step) * step;
+    (0..)
+        .map(move |idx| first + idx * step)
+        .take_while(move |p| *p <= end)
+}
+
+fn aligned_count((start, end): (u64, u64), step: u64) -> u64 {
+    let first = start.div_ceil(step) * step;
+    if first > end {
+        0
+    } else {
+        (end - first) / step + 1
+    }
+}
+
+/// The ranged coordinate for the IPv4 addresses. The key points are the subnet boundaries, that
+/// is the multiples of a power of 2, and they are labeled in the dotted-quad notation.
+#[derive(Clone)]
+pub struct RangedCoordIpv4(Ipv4Addr, Ipv4Addr);
+
+impl RangedCoordIpv4 {
+    fn span(&self) -> (u64, u64) {
+        let (start, end) = (u32::from(self.0), u32::from(self.1));
+        (u64::from(start.min(end)), u64::from(start.max(end)))
+    }
+}
+
+impl From<Range<Ipv4Addr>> for RangedCoordIpv4 {
+    fn from(range: Range<Ipv4Addr>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl Ranged for RangedCoordIpv4 {
+    type ValueType = Ipv4Addr;
+
+    fn map(&self, value: &Ipv4Addr, limit: (i32, i32)) -> i32 {
+        let span = (f64::from(u32::from(self.0)), f64::from(u32::from(self.1)));
+        map_span(f64::from(u32::from(*value)), span, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Ipv4Addr> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let span = self.span();
+        let mut step = 1;
+        while aligned_count(span, step) > max_points as u64 {
+            step *= 2;
+        }
+        aligned_points(span, step)
+            .map(|p| Ipv4Addr::from(p as u32))
+            .collect()
+    }
+
+    fn range(&self) -> Range<Ipv4Addr> {
+        self.0..self.1
+    }
+
+    fn format_label(value: &Ipv4Addr) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        value.to_string()
+    }
+}
+
+impl DiscreteRanged for RangedCoordIpv4 {
+    type RangeParameter = ();
+
+    fn get_range_parameter(&self) {}
+
+    fn next_value(this: &Ipv4Addr, _: &()) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(*this).saturating_add(1))
+    }
+
+    fn previous_value(this: &Ipv4Addr, _: &()) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(*this).saturating_sub(1))
+    }
+}
+
+impl AsRangedCoord for Range<Ipv4Addr> {
+    type CoordDescType = RangedCoordIpv4;
+    type Value = Ipv4Addr;
+}
+
+/// Get the service name of the well-known port
+pub fn port_service_name(port: u16) -> Option<&'static str> {
+    Some(match port {
+        20 => "ftp-data",
+        21 => "ftp",
+        22 => "ssh",
+        23 => "telnet",
+        25 => "smtp",
+        53 => "dns",
+        67 => "dhcp",
+        80 => "http",
+        110 => "pop3",
+        123 => "ntp",
+        143 => "imap",
+        161 => "snmp",
+        389 => "ldap",
+        443 => "https",
+        465 => "smtps",
+        993 => "imaps",
+        995 => "pop3s",
+        1433 => "mssql",
+        3306 => "mysql",
+        3389 => "rdp",
+        5432 => "postgresql",
+        6379 => "redis",
+        8080 => "http-alt",
+        27017 => "mongodb",
+        _ => return None,
+    })
+}
+
+/// The ranged coordinate for the port numbers. The key points are the round numbers, and the
+/// well-known ports are labeled with their service names, for example "443/https".
+#[derive(Clone)]
+pub struct RangedCoordPort(u16, u16);
+
+impl From<Range<u16>> for RangedCoordPort {
+    fn from(range: Range<u16>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl Ranged for RangedCoordPort {
+    type ValueType = u16;
+
+    fn map(&self, value: &u16, limit: (i32, i32)) -> i32 {
+        map_span(
+            f64::from(*value),
+            (f64::from(self.0), f64::from(self.1)),
+            limit,
+        )
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<u16> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let span = (u64::from(self.0.min(self.1)), u64::from(self.0.max(self.1)));
+        let mut scale = 1;
+        let step = loop {
+            if let Some(step) = [scale, scale * 2, scale * 5]
+                .iter()
+                .find(|step| aligned_count(span, **step) <= max_points as u64)
+            {
+                break *step;
+            }
+            scale *= 10;
+        };
+        aligned_points(span, step).map(|p| p as u16).collect()
+    }
+
+    fn range(&self) -> Range<u16> {
+        self.0..self.1
+    }
+
+    fn format_label(value: &u16) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        match port_service_name(*value) {
+            Some(name) => format!("{}/{}", value, name),
+            None => value.to_string(),
+        }
+    }
+}
+
+impl DiscreteRanged for RangedCoordPort {
+    type RangeParameter = ();
+
+    fn get_range_parameter(&self) {}
+
+    fn next_value(this: &u16, _: &()) -> u16 {
+        this.saturating_add(1)
+    }
+
+    fn previous_value(this: &u16, _: &()) -> u16 {
+        this.saturating_sub(1)
+    }
+}
+
+/// The trait that converts a range of `u16` into a port coordinate
+pub trait IntoPortRange {
+    fn ports(self) -> RangedCoordPort;
+}
+
+impl IntoPortRange for Range<u16> {
+    fn ports(self) -> RangedCoordPort {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_coord() {
+        let coord: RangedCoordIpv4 =
+            (Ipv4Addr::new(10, 0, 0, 0)..Ipv4Addr::new(10, 0, 1, 0)).into();
+        assert_eq!(coord.map(&Ipv4Addr::new(10, 0, 0, 128), (0, 100)), 50);
+
+        let labels: Vec<_> = coord
+            .key_points(4)
+            .iter()
+            .map(RangedCoordIpv4::format_label)
+            .collect();
+        assert_eq!(labels, vec!["10.0.0.0", "10.0.0.128", "10.0.1.0"]);
+    }
+
+    #[test]
+    fn test_port_coord() {
+        let coord = (0u16..1000).ports();
+        assert_eq!(coord.key_points(5), vec![0, 500, 1000]);
+        assert_eq!(coord.map(&500, (0, 100)), 50);
+        assert_eq!(RangedCoordPort::format_label(&443), "443/https");
+        assert_eq!(RangedCoordPort::format_label(&1000), "1000");
+    }
+}
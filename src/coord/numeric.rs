@@ -256,6 +256,210 @@ impl_ranged_type_trait!(u128, RangedCoordu128);
 impl_ranged_type_trait!(isize, RangedCoordisize);
 impl_ranged_type_trait!(usize, RangedCoordusize);
 
+/// The default number of ticks `NiceRange::nicify` aims at, which matches the default number of
+/// the mesh labels
+pub const DEFAULT_NICE_TICKS: usize = 10;
+
+/// Multiply the nice step, dividing by the inverse of a fractional step keeps the values like 0.9
+/// exact
+fn nice_multiple(n: f64, step: f64) -> f64 {
+    if step < 1.0 {
+        n / (1.0 / step).round()
+    } else {
+        n * step
+    }
+}
+
+/// The index of the largest nice number (1, 2 or 5 times a power of 10) not exceeding the value,
+/// the nice numbers are indexed in order, so that the index of `10^k` is `3k`
+fn nice_ladder_index(value: f64) -> i32 {
+    // The logarithm of an exact power of 10 may be slightly below the integer
+    let exp = (value.log10() + 1e-9).floor() as i32;
+    let base = nice_ladder_value(exp * 3);
+    let mult = if 5.0 * base <= value * (1.0 + 1e-9) {
+        2
+    } else if 2.0 * base <= value * (1.0 + 1e-9) {
+        1
+    } else {
+        0
+    };
+    exp * 3 + mult
+}
+
+/// The nice number of the index, see `nice_ladder_index`
+fn nice_ladder_value(idx: i32) -> f64 {
+    let mult = [1.0, 2.0, 5.0][idx.rem_euclid(3) as usize];
+    let exp = idx.div_euclid(3);
+    if exp < 0 {
+        mult / (10f64).powi(-exp)
+    } else {
+        mult * (10f64).powi(exp)
+    }
+}
+
+/// Move the range by the largest nice step not exceeding the fraction of its span, the lower end
+/// is snapped to a multiple of the step and the span is kept
+fn step_bounds(start: f64, end: f64, fraction: f64, integer: bool) -> (f64, f64) {
+    let (lo, hi) = (start.min(end), start.max(end));
+    let distance = (hi - lo) * fraction.abs();
+    if !distance.is_finite() || distance <= 0.0 {
+        return (start, end);
+    }
+
+    let mut step = nice_ladder_value(nice_ladder_index(distance));
+    if integer {
+        step = step.max(1.0);
+    }
+
+    let n_lo = (lo / step).round() + fraction.signum();
+    let new_lo = nice_multiple(n_lo, step);
+    let (lo, hi) = (new_lo, new_lo + (hi - lo));
+
+    if start <= end {
+        (lo, hi)
+    } else {
+        (hi, lo)
+    }
+}
+
+/// Zoom the range around its center by the levels of the nice spans, i.e. 1, 2 or 5 times a power
+/// of 10, the positive levels zoom in and the negative levels zoom out. The ends of the range are
+/// snapped to a multiple of the tenth of the span.
+fn zoom_bounds(start: f64, end: f64, levels: i32, integer: bool) -> (f64, f64) {
+    let (lo, hi) = (start.min(end), start.max(end));
+    if levels == 0 || !(hi - lo).is_finite() || hi - lo <= 0.0 {
+        return (start, end);
+    }
+
+    let mut idx = nice_ladder_index(hi - lo) - levels;
+    if integer {
+        idx = idx.max(0);
+    }
+    let span = nice_ladder_value(idx);
+    let mut tick = nice_ladder_value(idx - 3);
+    if integer {
+        tick = tick.max(1.0);
+    }
+
+    let n_lo = (((lo + hi) / 2.0 - span / 2.0) / tick).round();
+    let n_hi = n_lo + (span / tick).round();
+    let (lo, hi) = (nice_multiple(n_lo, tick), nice_multiple(n_hi, tick));
+
+    if start <= end {
+        (lo, hi)
+    } else {
+        (hi, lo)
+    }
+}
+
+/// Expand the range outward so that both ends are multiples of a nice step (1, 2 or 5 times a
+/// power of 10) and the range holds at most `max_points` ticks of the step
+fn nice_bounds(start: f64, end: f64, max_points: usize, integer: bool) -> (f64, f64) {
+    let (lo, hi) = (start.min(end), start.max(end));
+    if max_points < 2 || !(hi - lo).is_finite() || hi - lo <= 0.0 {
+        return (start, end);
+    }
+
+    let mut scale = (10f64).powf(((hi - lo) / (max_points - 1) as f64).log10().floor());
+    if integer {
+        scale = scale.max(1.0);
+    }
+
+    let (lo, hi) = 'search: loop {
+        for mult in [1.0, 2.0, 5.0].iter() {
+            let step = scale * mult;
+            let n_lo = (lo / step + 1e-9).floor();
+            let n_hi = (hi / step - 1e-9).ceil();
+            if ((n_hi - n_lo) as usize) < max_points {
+                break 'search (nice_multiple(n_lo, step), nice_multiple(n_hi, step));
+            }
+        }
+        scale *= 10.0;
+    };
+
+    if start <= end {
+        (lo, hi)
+    } else {
+        (hi, lo)
+    }
+}
+
+/// The ranges that can be expanded to the nice tick values, so that the plot frame coincides
+/// with the ticks
+pub trait NiceRange: Sized {
+    /// Expand the range outward to the nearest nice tick values, using at most `max_points` ticks
+    fn nicify_with(self, max_points: usize) -> Self;
+
+    /// Expand the range outward to the nearest nice tick values
+    fn nicify(self) -> Self {
+        self.nicify_with(DEFAULT_NICE_TICKS)
+    }
+}
+
+macro_rules! impl_nice_range {
+    ($type:ty, $integer:expr) => {
+        impl NiceRange for Range<$type> {
+            fn nicify_with(self, max_points: usize) -> Self {
+                let (start, end) =
+                    nice_bounds(self.start as f64, self.end as f64, max_points, $integer);
+                (start as $type)..(end as $type)
+            }
+        }
+    };
+}
+
+/// The ranges that can be moved and zoomed in the nice steps, which is used to navigate the
+/// chart with the buttons or a rotary encoder, see `ChartState::step_x`
+pub trait SteppableRange: Sized {
+    /// Move the range by the largest nice step not exceeding the fraction of its span, the
+    /// positive fraction moves toward the larger values
+    fn shift_by(self, fraction: f64) -> Self;
+
+    /// Zoom the range around its center to the nice span, which is the given levels smaller,
+    /// the negative levels zoom out
+    fn zoom_by(self, levels: i32) -> Self;
+}
+
+macro_rules! impl_steppable_range {
+    ($type:ty, $integer:expr) => {
+        impl SteppableRange for Range<$type> {
+            fn shift_by(self, fraction: f64) -> Self {
+                let (start, end) =
+                    step_bounds(self.start as f64, self.end as f64, fraction, $integer);
+                (start as $type)..(end as $type)
+            }
+
+            fn zoom_by(self, levels: i32) -> Self {
+                let (start, end) =
+                    zoom_bounds(self.start as f64, self.end as f64, levels, $integer);
+                (start as $type)..(end as $type)
+            }
+        }
+    };
+}
+
+impl_steppable_range!(f32, false);
+impl_steppable_range!(f64, false);
+impl_steppable_range!(i32, true);
+impl_steppable_range!(u32, true);
+impl_steppable_range!(i64, true);
+impl_steppable_range!(u64, true);
+impl_steppable_range!(i128, true);
+impl_steppable_range!(u128, true);
+impl_steppable_range!(isize, true);
+impl_steppable_range!(usize, true);
+
+impl_nice_range!(f32, false);
+impl_nice_range!(f64, false);
+impl_nice_range!(i32, true);
+impl_nice_range!(u32, true);
+impl_nice_range!(i64, true);
+impl_nice_range!(u64, true);
+impl_nice_range!(i128, true);
+impl_nice_range!(u128, true);
+impl_nice_range!(isize, true);
+impl_nice_range!(usize, true);
+
 // TODO: Think about how to re-organize this part
 pub mod group_integer_by {
     use super::Ranged;
@@ -375,6 +579,33 @@ mod test {
         assert!(kp.len() == 0);
     }
 
+    #[test]
+    fn test_nice_range() {
+        assert_eq!((0.13..0.87).nicify(), 0.1..0.9);
+        assert_eq!((-3.2..97.0).nicify(), -20.0..100.0);
+        assert_eq!((10.0..-1.0).nicify(), 10.0..-2.0);
+        assert_eq!((3..97).nicify(), 0..100);
+        assert_eq!((3..97).nicify_with(3), 0..100);
+        assert_eq!((0..7).nicify(), 0..7);
+        assert_eq!((5.0..5.0).nicify(), 5.0..5.0);
+    }
+
+    #[test]
+    fn test_steppable_range() {
+        assert_eq!((0.0..10.0).shift_by(0.25), 2.0..12.0);
+        assert_eq!((0.0..10.0).shift_by(-0.1), -1.0..9.0);
+        assert_eq!((0.5..10.5).shift_by(0.5), 5.0..15.0);
+        assert_eq!((10.0..0.0).shift_by(0.1), 11.0..1.0);
+        assert_eq!((0..3).shift_by(0.1), 1..4);
+        assert_eq!((0.0..10.0).shift_by(0.0), 0.0..10.0);
+
+        assert_eq!((0.0..10.0).zoom_by(1), 2.5..7.5);
+        assert_eq!((0.0..10.0).zoom_by(-1), -6.0..14.0);
+        assert_eq!((0.0..7.3).zoom_by(-1), -1.0..9.0);
+        assert_eq!((0..10).zoom_by(3), 5..6);
+        assert_eq!((0.0..10.0).zoom_by(0), 0.0..10.0);
+    }
+
     #[test]
     fn test_linear_coord_map() {
         let coord: RangedCoordu32 = (0..20).into();
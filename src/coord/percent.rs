@@ -0,0 +1,133 @@
+use std::fmt::Debug;
+use std::ops::Range;
+
+use super::{Ranged, ReversibleRanged};
+
+/// The steps of the percent key points, in per mille
+const PERCENT_STEPS: [i64; 11] = [1, 2, 5, 10, 20, 50, 100, 200, 250, 500, 1000];
+
+/// The coordinate of the shares of the total, such as `0.0..1.0`, which is labeled in percent,
+/// for example "25%". The key points are the multiples of 0.1%, so that they are exact.
+#[derive(Clone)]
+pub struct PercentCoord {
+    start: f64,
+    end: f64,
+}
+
+impl PercentCoord {
+    /// Create the coordinate of the whole, i.e. from 0% to 100%
+    pub fn whole() -> Self {
+        (0.0..1.0).into()
+    }
+
+    /// Format the share as the percent label, for example "25%" or "12.5%"
+    pub fn format(value: f64) -> String {
+        let percent = value * 100.0;
+        if (percent - percent.round()).abs() < 1e-6 {
+            format!("{}%", percent.round())
+        } else {
+            format!("{:.1}%", percent)
+        }
+    }
+}
+
+impl From<Range<f64>> for PercentCoord {
+    fn from(range: Range<f64>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl Ranged for PercentCoord {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        let actual_length = f64::from(limit.1 - limit.0);
+        if self.end == self.start {
+            return limit.0 + (actual_length / 2.0) as i32;
+        }
+        let logic_length = (value - self.start) / (self.end - self.start);
+        limit.0 + (actual_length * logic_length + 1e-3).floor() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        if max_points == 0 {
+            return vec![];
+        }
+
+        // Work in per mille, so that the key points are exact
+        let (lo, hi) = (self.start.min(self.end), self.start.max(self.end));
+        let (lo, hi) = (
+            (lo * 1000.0 - 1e-9).ceil() as i64,
+            (hi * 1000.0 + 1e-9).floor() as i64,
+        );
+        let count = |step: i64| {
+            let first = lo.div_euclid(step) * step + if lo % step == 0 { 0 } else { step };
+            if first > hi {
+                (first, 0)
+            } else {
+                (first, ((hi - first) / step + 1) as usize)
+            }
+        };
+
+        let mut step = PERCENT_STEPS
+            .iter()
+            .cloned()
+            .find(|step| count(*step).1 <= max_points)
+            .unwrap_or(1000);
+        while count(step).1 > max_points {
+            step *= 2;
+        }
+
+        let (first, n) = count(step);
+        (0..n as i64)
+            .map(|idx| (first + idx * step) as f64 / 1000.0)
+            .collect()
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.start..self.end
+    }
+
+    fn format_label(value: &f64) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        Self::format(*value)
+    }
+}
+
+impl ReversibleRanged for PercentCoord {
+    fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<f64> {
+        if p < min.min(max) || p > max.max(min) || min == max {
+            return None;
+        }
+        let logical_offset = f64::from(p - min) / f64::from(max - min);
+        Some((self.end - self.start) * logical_offset + self.start)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_percent_coord() {
+        let coord = PercentCoord::whole();
+        let labels: Vec<_> = coord
+            .key_points(10)
+            .iter()
+            .map(PercentCoord::format_label)
+            .collect();
+        assert_eq!(labels, vec!["0%", "20%", "40%", "60%", "80%", "100%"]);
+        assert_eq!(coord.key_points(5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(coord.map(&0.5, (0, 100)), 50);
+
+        let coord = PercentCoord::from(0.1..0.12);
+        assert_eq!(coord.key_points(3), vec![0.1, 0.11, 0.12]);
+        assert_eq!(PercentCoord::format(0.125), "12.5%");
+        assert_eq!(PercentCoord::format(-0.05), "-5%");
+    }
+}
@@ -2,7 +2,10 @@ use super::{CoordTranslate, ReverseCoordTranslate};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::style::ShapeStyle;
 
+use std::collections::HashMap;
+use std::fmt::Debug;
 use std::ops::Range;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// The trait that indicates we have a ordered and ranged value
 /// Which is used to describe the axis
@@ -19,6 +22,15 @@ pub trait Ranged {
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
+    /// Format the value as the label of the axis, which is used by the mesh when no label
+    /// formatter is given. The `Debug` format is used by default.
+    fn format_label(value: &Self::ValueType) -> String
+    where
+        Self::ValueType: Debug,
+    {
+        format!("{:?}", value)
+    }
+
     /// This function provides the on-axis part of its range
     #[allow(clippy::range_plus_one)]
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
@@ -37,12 +49,92 @@ pub trait ReversibleRanged: Ranged {
     fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType>;
 }
 
+type SharedMeshCache<XV, YV> = Arc<Mutex<Vec<Arc<MeshCache<XV, YV>>>>>;
+
+/// The key points of the mesh and their pixel positions for the given key point limits
+struct MeshCache<XV, YV> {
+    limits: (usize, usize),
+    x_points: Vec<(i32, XV)>,
+    y_points: Vec<(i32, YV)>,
+}
+
+/// The formatted labels of the key points, produced by the formatter with the given id for the
+/// axis ranges and the key point limits
+struct MeshLabels {
+    formatter_id: u64,
+    ranges: String,
+    limits: (usize, usize),
+    x_labels: Vec<Option<String>>,
+    y_labels: Vec<Option<String>>,
+}
+
+/// The max number of the cached meshes, the chart mesh uses two of them: one for the bold lines
+/// and one for the light lines
+const MESH_CACHE_SIZE: usize = 4;
+
+/// The formatted labels and the label sizes, which are keyed by the axis ranges and the text, so
+/// they are still valid after the axes are replaced
+#[derive(Default)]
+struct LabelCache {
+    labels: Vec<Arc<MeshLabels>>,
+    sizes: HashMap<(String, String), (u32, u32)>,
+}
+
+/// The max number of the cached label sets, which allows a chart stepping or zooming back and
+/// forth between a few ranges to reuse the labels of all of them
+const LABEL_CACHE_SIZE: usize = 16;
+
+/// The max number of the cached label sizes
+const LABEL_SIZE_CACHE_SIZE: usize = 1024;
+
+/// Lock the cache, the cache is still usable if a thread panicked while holding the lock
+fn lock_cache<T>(cache: &Mutex<T>) -> MutexGuard<'_, T> {
+    cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The resolution used to find the relative position of a value within the axis
+const PIN_RESOLUTION: i32 = 1 << 20;
+
+/// Compute the pixel extent within the given one, where the value at the relative position
+/// `pos` of the axis maps to `pixel`. The extent shrinks as little as possible.
+fn pinned_extent((start, end): (i32, i32), pos: f64, pixel: i32) -> Option<(i32, i32)> {
+    let len = (end - start).abs() as f64;
+    let dir = if end >= start { 1.0 } else { -1.0 };
+    let offset = f64::from(pixel - start) * dir;
+
+    if len == 0.0 || !(0.0..=1.0).contains(&pos) || offset < 0.0 || offset > len {
+        return None;
+    }
+
+    let new_len = match (pos > 0.0, pos < 1.0) {
+        (true, true) => (offset / pos).min((len - offset) / (1.0 - pos)),
+        (true, false) => offset,
+        _ => len - offset,
+    };
+    let new_start = offset - pos * new_len;
+
+    Some((
+        start + (new_start * dir).round() as i32,
+        start + ((new_start + new_len) * dir).round() as i32,
+    ))
+}
+
 /// The coordinate described by two ranged value
 pub struct RangedCoord<X: Ranged, Y: Ranged> {
     logic_x: X,
     logic_y: Y,
     back_x: (i32, i32),
     back_y: (i32, i32),
+    // The cache is shared between the clones, since they have the same ranges and pixel extent,
+    // so that the restored chart state in the following frames reuses the key points
+    mesh_cache: SharedMeshCache<X::ValueType, Y::ValueType>,
+    // The labels are shared the same way, but they are kept when the axes are replaced
+    label_cache: Arc<Mutex<LabelCache>>,
+    // The key points set by the user, which replace the ones computed by the axes
+    x_key_points: Option<Arc<Vec<X::ValueType>>>,
+    y_key_points: Option<Arc<Vec<Y::ValueType>>>,
 }
 
 impl<X: Ranged + Clone, Y: Ranged + Clone> Clone for RangedCoord<X, Y> {
@@ -52,6 +144,10 @@ impl<X: Ranged + Clone, Y: Ranged + Clone> Clone for RangedCoord<X, Y> {
             logic_y: self.logic_y.clone(),
             back_x: self.back_x,
             back_y: self.back_y,
+            mesh_cache: Arc::clone(&self.mesh_cache),
+            label_cache: Arc::clone(&self.label_cache),
+            x_key_points: self.x_key_points.clone(),
+            y_key_points: self.y_key_points.clone(),
         }
     }
 }
@@ -68,42 +164,292 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
             logic_y: logic_y.into(),
             back_x: (actual.0.start, actual.0.end),
             back_y: (actual.1.start, actual.1.end),
+            mesh_cache: Arc::new(Mutex::new(vec![])),
+            label_cache: Arc::new(Mutex::new(LabelCache::default())),
+            x_key_points: None,
+            y_key_points: None,
         }
     }
 
-    /// Draw the mesh for the coordinate system
+    /// Draw the mesh for the coordinate system. The key points and their pixel positions are
+    /// cached, so drawing the same mesh again, for example in the following animation frames,
+    /// doesn't compute them again.
     pub fn draw_mesh<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
         &self,
         h_limit: usize,
         v_limit: usize,
         mut draw_mesh: DrawMesh,
     ) -> Result<(), E> {
-        let (xkp, ykp) = (
-            self.logic_x.key_points(v_limit),
-            self.logic_y.key_points(h_limit),
-        );
+        self.draw_cached_mesh(h_limit, v_limit, None, |_| None, |line, _| draw_mesh(line))
+    }
 
-        for logic_x in xkp {
-            let x = self.logic_x.map(&logic_x, self.back_x);
-            draw_mesh(MeshLine::XMesh(
-                (x, self.back_y.0),
-                (x, self.back_y.1),
-                &logic_x,
-            ))?;
+    /// Draw the mesh along with the labels of the mesh lines.
+    /// - `formatter_id`: When it's given, the labels are cached by the formatter id and the axis
+    ///   ranges, and they are kept when the axes are replaced. Thus the following calls with the
+    ///   same id reuse them instead of calling `fmt_label` again, including the ones after
+    ///   moving back to a previous range. Use a different id for a different formatter, or call
+    ///   `clear_mesh_cache` when the formatter changes its output.
+    /// - `fmt_label`: The function that produces the label of a mesh line
+    /// - `draw_mesh`: The function that draws the mesh line with its label
+    pub fn draw_mesh_with_labels<E, FmtLabel, DrawMesh>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        formatter_id: Option<u64>,
+        fmt_label: FmtLabel,
+        draw_mesh: DrawMesh,
+    ) -> Result<(), E>
+    where
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
+        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        DrawMesh: FnMut(MeshLine<X, Y>, Option<&str>) -> Result<(), E>,
+    {
+        let label_key = formatter_id.map(|id| {
+            let ranges = format!("{:?} {:?}", self.logic_x.range(), self.logic_y.range());
+            (id, ranges)
+        });
+        self.draw_cached_mesh(h_limit, v_limit, label_key, fmt_label, draw_mesh)
+    }
+
+    /// Get the size of a label in the font, `estimate` is only called when the size isn't in
+    /// the label cache. The cache is shared with the clones and kept when the axes are replaced.
+    /// - `font`: The key of the font, which must describe everything that changes the size
+    pub(crate) fn label_size<E, Estimate: FnOnce() -> Result<(u32, u32), E>>(
+        &self,
+        font: &str,
+        label: &str,
+        estimate: Estimate,
+    ) -> Result<(u32, u32), E> {
+        let key = (font.to_string(), label.to_string());
+        if let Some(size) = lock_cache(&self.label_cache).sizes.get(&key) {
+            return Ok(*size);
+        }
+
+        let size = estimate()?;
+        let mut cache = lock_cache(&self.label_cache);
+        if cache.sizes.len() >= LABEL_SIZE_CACHE_SIZE {
+            cache.sizes.clear();
+        }
+        cache.sizes.insert(key, size);
+        Ok(size)
+    }
+
+    fn draw_cached_mesh<E, FmtLabel, DrawMesh>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        label_key: Option<(u64, String)>,
+        mut fmt_label: FmtLabel,
+        mut draw_mesh: DrawMesh,
+    ) -> Result<(), E>
+    where
+        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        DrawMesh: FnMut(MeshLine<X, Y>, Option<&str>) -> Result<(), E>,
+    {
+        // The locks are only held to look up and insert the cache entries, so that the closures
+        // are free to draw another mesh of this coordinate
+        let mesh = self.cached_mesh(h_limit, v_limit);
+
+        let x_points: Vec<_> = match &self.x_key_points {
+            Some(points) => points
+                .iter()
+                .map(|v| (self.logic_x.map(v, self.back_x), v))
+                .collect(),
+            None => mesh.x_points.iter().map(|(x, v)| (*x, v)).collect(),
+        };
+        let y_points: Vec<_> = match &self.y_key_points {
+            Some(points) => points
+                .iter()
+                .map(|v| (self.logic_y.map(v, self.back_y), v))
+                .collect(),
+            None => mesh.y_points.iter().map(|(y, v)| (*y, v)).collect(),
+        };
+
+        let labels = label_key.map(|(formatter_id, ranges)| {
+            let limits = (h_limit, v_limit);
+            let cached = lock_cache(&self.label_cache)
+                .labels
+                .iter()
+                .find(|l| {
+                    l.formatter_id == formatter_id && l.limits == limits && l.ranges == ranges
+                })
+                .cloned();
+            cached.unwrap_or_else(|| {
+                let labels = Arc::new(MeshLabels {
+                    formatter_id,
+                    ranges,
+                    limits,
+                    x_labels: x_points
+                        .iter()
+                        .map(|(x, v)| fmt_label(&self.x_mesh_line(*x, v)))
+                        .collect(),
+                    y_labels: y_points
+                        .iter()
+                        .map(|(y, v)| fmt_label(&self.y_mesh_line(*y, v)))
+                        .collect(),
+                });
+                let mut cache = lock_cache(&self.label_cache);
+                if cache.labels.len() >= LABEL_CACHE_SIZE {
+                    cache.labels.remove(0);
+                }
+                cache.labels.push(Arc::clone(&labels));
+                labels
+            })
+        });
+
+        for (idx, (x, logic_x)) in x_points.iter().enumerate() {
+            let line = self.x_mesh_line(*x, logic_x);
+            match &labels {
+                Some(labels) => {
+                    let label = labels.x_labels[idx].as_ref().map(|l| &l[..]);
+                    draw_mesh(line, label)?;
+                }
+                None => {
+                    let label = fmt_label(&line);
+                    draw_mesh(line, label.as_ref().map(|l| &l[..]))?;
+                }
+            }
         }
 
-        for logic_y in ykp {
-            let y = self.logic_y.map(&logic_y, self.back_y);
-            draw_mesh(MeshLine::YMesh(
-                (self.back_x.0, y),
-                (self.back_x.1, y),
-                &logic_y,
-            ))?;
+        for (idx, (y, logic_y)) in y_points.iter().enumerate() {
+            let line = self.y_mesh_line(*y, logic_y);
+            match &labels {
+                Some(labels) => {
+                    let label = labels.y_labels[idx].as_ref().map(|l| &l[..]);
+                    draw_mesh(line, label)?;
+                }
+                None => {
+                    let label = fmt_label(&line);
+                    draw_mesh(line, label.as_ref().map(|l| &l[..]))?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Map the key points of the mesh drawn with the given key point limits, which are the ones
+    /// set by `set_x_key_points` and `set_y_key_points` if any. The key points are cached for
+    /// drawing the mesh afterwards.
+    pub(crate) fn map_mesh_key_points<XT, YT, MapX, MapY>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        map_x: MapX,
+        map_y: MapY,
+    ) -> (Vec<XT>, Vec<YT>)
+    where
+        MapX: FnMut(&X::ValueType) -> XT,
+        MapY: FnMut(&Y::ValueType) -> YT,
+    {
+        let mesh = self.cached_mesh(h_limit, v_limit);
+        let x_values = match &self.x_key_points {
+            Some(points) => points.iter().map(map_x).collect(),
+            None => mesh.x_points.iter().map(|(_, v)| v).map(map_x).collect(),
+        };
+        let y_values = match &self.y_key_points {
+            Some(points) => points.iter().map(map_y).collect(),
+            None => mesh.y_points.iter().map(|(_, v)| v).map(map_y).collect(),
+        };
+        (x_values, y_values)
+    }
+
+    /// Get the cached key points of the mesh, the key points are computed and cached if they
+    /// aren't. The lock isn't held while computing them.
+    fn cached_mesh(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+    ) -> Arc<MeshCache<X::ValueType, Y::ValueType>> {
+        let limits = (h_limit, v_limit);
+        if let Some(mesh) = lock_cache(&self.mesh_cache)
+            .iter()
+            .find(|m| m.limits == limits)
+        {
+            return Arc::clone(mesh);
+        }
+
+        // The key points set by the user aren't cached, since they are already known
+        let mesh = Arc::new(MeshCache {
+            limits,
+            x_points: match self.x_key_points {
+                Some(_) => vec![],
+                None => self
+                    .logic_x
+                    .key_points(v_limit)
+                    .into_iter()
+                    .map(|v| (self.logic_x.map(&v, self.back_x), v))
+                    .collect(),
+            },
+            y_points: match self.y_key_points {
+                Some(_) => vec![],
+                None => self
+                    .logic_y
+                    .key_points(h_limit)
+                    .into_iter()
+                    .map(|v| (self.logic_y.map(&v, self.back_y), v))
+                    .collect(),
+            },
+        });
+
+        let mut cache = lock_cache(&self.mesh_cache);
+        if cache.len() >= MESH_CACHE_SIZE {
+            cache.remove(0);
+        }
+        cache.push(Arc::clone(&mesh));
+        mesh
+    }
+
+    fn x_mesh_line<'b>(&self, x: i32, value: &'b X::ValueType) -> MeshLine<'b, X, Y> {
+        MeshLine::XMesh((x, self.back_y.0), (x, self.back_y.1), value)
+    }
+
+    fn y_mesh_line<'b>(&self, y: i32, value: &'b Y::ValueType) -> MeshLine<'b, X, Y> {
+        MeshLine::YMesh((self.back_x.0, y), (self.back_x.1, y), value)
+    }
+
+    /// Pin the X value, typically the origin, to the given backend pixel column. The pixel
+    /// extent of the X axis shrinks as little as possible, so that the value maps to the column
+    /// and the whole range still fits in the plotting area. This is useful to align the origins
+    /// of the subplots with different ranges.
+    /// - **returns** `false` if the value is out of the range or the column is out of the
+    ///   plotting area, in which case the coordinate is unchanged
+    pub fn pin_x_origin(&mut self, value: &X::ValueType, pixel: i32) -> bool {
+        let pos =
+            f64::from(self.logic_x.map(value, (0, PIN_RESOLUTION))) / f64::from(PIN_RESOLUTION);
+        match pinned_extent(self.back_x, pos, pixel) {
+            Some(extent) => {
+                self.back_x = extent;
+                self.mesh_cache = Arc::new(Mutex::new(vec![]));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pin the Y value, typically the origin, to the given backend pixel row, see
+    /// `pin_x_origin` for the details
+    pub fn pin_y_origin(&mut self, value: &Y::ValueType, pixel: i32) -> bool {
+        let pos =
+            f64::from(self.logic_y.map(value, (0, PIN_RESOLUTION))) / f64::from(PIN_RESOLUTION);
+        match pinned_extent(self.back_y, pos, pixel) {
+            Some(extent) => {
+                self.back_y = extent;
+                self.mesh_cache = Arc::new(Mutex::new(vec![]));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop the cached mesh key points, labels and label sizes. This is needed when the label
+    /// formatter produces different labels for the same formatter id.
+    pub fn clear_mesh_cache(&self) {
+        lock_cache(&self.mesh_cache).clear();
+        *lock_cache(&self.label_cache) = LabelCache::default();
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -122,6 +468,16 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Get the pixel position of the value on the X axis
+    pub fn x_pixel(&self, value: &X::ValueType) -> i32 {
+        self.logic_x.map(value, self.back_x)
+    }
+
+    /// Get the pixel position of the value on the Y axis
+    pub fn y_pixel(&self, value: &Y::ValueType) -> i32 {
+        self.logic_y.map(value, self.back_y)
+    }
+
     pub fn x_spec(&self) -> &X {
         &self.logic_x
     }
@@ -129,6 +485,99 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
     pub fn y_spec(&self) -> &Y {
         &self.logic_y
     }
+
+    /// Replace the X axis, the pixel extent is kept and the clones no longer share the mesh
+    /// key points with this coordinate. The cached labels are kept, since they are keyed by
+    /// the axis ranges.
+    pub fn set_x_spec<IntoX: Into<X>>(&mut self, logic_x: IntoX) {
+        self.logic_x = logic_x.into();
+        self.mesh_cache = Arc::new(Mutex::new(vec![]));
+    }
+
+    /// Replace the Y axis, see `set_x_spec`
+    pub fn set_y_spec<IntoY: Into<Y>>(&mut self, logic_y: IntoY) {
+        self.logic_y = logic_y.into();
+        self.mesh_cache = Arc::new(Mutex::new(vec![]));
+    }
+
+    /// Replace the key points of X axis, which are used by all the meshes instead of the ones
+    /// computed by the axis, thus the light mesh lines are drawn at the same points. `None`
+    /// restores the computed key points.
+    pub fn set_x_key_points(&mut self, points: Option<Vec<X::ValueType>>) {
+        self.x_key_points = points.map(Arc::new);
+        self.mesh_cache = Arc::new(Mutex::new(vec![]));
+        self.label_cache = Arc::new(Mutex::new(LabelCache::default()));
+    }
+
+    /// Replace the key points of Y axis, see `set_x_key_points`
+    pub fn set_y_key_points(&mut self, points: Option<Vec<Y::ValueType>>) {
+        self.y_key_points = points.map(Arc::new);
+        self.mesh_cache = Arc::new(Mutex::new(vec![]));
+        self.label_cache = Arc::new(Mutex::new(LabelCache::default()));
+    }
+}
+
+impl<X: Ranged, Y: Ranged> RangedCoord<X, Y>
+where
+    X::ValueType: Clone,
+{
+    /// Get the key points of X axis, which are the ones set by `set_x_key_points` or the ones
+    /// computed by the axis with at most `max_points` points
+    pub fn x_key_points(&self, max_points: usize) -> Vec<X::ValueType> {
+        match &self.x_key_points {
+            Some(points) => points.to_vec(),
+            None => self.logic_x.key_points(max_points),
+        }
+    }
+}
+
+impl<X: Ranged, Y: Ranged> RangedCoord<X, Y>
+where
+    Y::ValueType: Clone,
+{
+    /// Get the key points of Y axis, see `x_key_points`
+    pub fn y_key_points(&self, max_points: usize) -> Vec<Y::ValueType> {
+        match &self.y_key_points {
+            Some(points) => points.to_vec(),
+            None => self.logic_y.key_points(max_points),
+        }
+    }
+}
+
+impl<X: ReversibleRanged, Y: Ranged> RangedCoord<X, Y> {
+    /// Get the value on the X axis at the pixel position
+    pub fn x_value_at(&self, pixel: i32) -> Option<X::ValueType> {
+        self.logic_x.unmap(pixel, self.back_x)
+    }
+}
+
+impl<X: Ranged, Y: ReversibleRanged> RangedCoord<X, Y> {
+    /// Get the value on the Y axis at the pixel position
+    pub fn y_value_at(&self, pixel: i32) -> Option<Y::ValueType> {
+        self.logic_y.unmap(pixel, self.back_y)
+    }
+}
+
+impl<X: DiscreteRanged, Y: Ranged> RangedCoord<X, Y> {
+    /// Get the size of a single slot on the X axis
+    pub fn x_slot_size(&self) -> SlotSize {
+        let start = self.logic_x.range().start;
+        let next = X::next_value(&start, &self.logic_x.get_range_parameter());
+        SlotSize::new(f64::from(
+            self.logic_x.map(&next, self.back_x) - self.logic_x.map(&start, self.back_x),
+        ))
+    }
+}
+
+impl<X: Ranged, Y: DiscreteRanged> RangedCoord<X, Y> {
+    /// Get the size of a single slot on the Y axis
+    pub fn y_slot_size(&self) -> SlotSize {
+        let start = self.logic_y.range().start;
+        let next = Y::next_value(&start, &self.logic_y.get_range_parameter());
+        SlotSize::new(f64::from(
+            self.logic_y.map(&next, self.back_y) - self.logic_y.map(&start, self.back_y),
+        ))
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for RangedCoord<X, Y> {
@@ -188,6 +637,48 @@ where
     fn previous_value(this: &Self::ValueType, param: &Self::RangeParameter) -> Self::ValueType;
 }
 
+/// The size of a single slot of a discrete coordinate in pixels, which is the distance between
+/// two adjacent values on the axis. It's used to express the size and offset of elements, like
+/// boxplots or grouped bars, as a fraction of the slot, so that the chart scales with the output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlotSize(f64);
+
+impl SlotSize {
+    /// Create a slot size from the pixel distance between two adjacent values
+    pub fn new(pixels: f64) -> Self {
+        SlotSize(pixels.abs())
+    }
+
+    /// Get the size of the slot in pixels
+    pub fn in_pixels(&self) -> f64 {
+        self.0
+    }
+
+    /// Get the pixel size of the fraction of the slot
+    /// - `fraction`: The fraction, for example `-0.25` means a quarter of the slot backward
+    pub fn fraction(&self, fraction: f64) -> f64 {
+        self.0 * fraction
+    }
+
+    /// Get the pixel width of the fraction of the slot, suitable for the element width
+    /// - `fraction`: The fraction of the slot
+    pub fn width(&self, fraction: f64) -> u32 {
+        self.fraction(fraction).abs().round() as u32
+    }
+
+    /// Compute the offsets of the centers of `n` elements placed side by side in the slot
+    /// - `n`: The number of elements in the group
+    /// - `fill`: The fraction of the slot that the whole group occupies
+    /// - **returns**: The pixel offsets relative to the center of the slot
+    pub fn group_offsets(&self, n: usize, fill: f64) -> Vec<f64> {
+        let total = self.fraction(fill);
+        let member = total / n.max(1) as f64;
+        (0..n)
+            .map(|i| -total / 2.0 + member * (i as f64 + 0.5))
+            .collect()
+    }
+}
+
 /// The trait for the type that can be converted into a ranged coordinate axis
 pub trait AsRangedCoord: Sized {
     type CoordDescType: Ranged<ValueType = Self::Value> + From<Self>;
@@ -395,3 +886,277 @@ where
 
     Some(PartialAxis(full_range.into(), axis_range.range()))
 }
+
+#[cfg(test)]
+// The pixel ranges of Y axis are reversed on purpose
+#[allow(clippy::reversed_empty_ranges)]
+mod test {
+    use super::*;
+    use crate::coord::RangedCoordf64;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Clone)]
+    struct CountingRange(Rc<Cell<usize>>);
+
+    impl Ranged for CountingRange {
+        type ValueType = i32;
+
+        fn map(&self, value: &i32, limit: (i32, i32)) -> i32 {
+            limit.0 + (limit.1 - limit.0) * value / 10
+        }
+
+        fn key_points(&self, max_points: usize) -> Vec<i32> {
+            self.0.set(self.0.get() + 1);
+            (0..=10).step_by(10 / max_points.clamp(1, 10)).collect()
+        }
+
+        fn range(&self) -> Range<i32> {
+            0..10
+        }
+    }
+
+    #[test]
+    fn test_mesh_cache() {
+        let counter = Rc::new(Cell::new(0));
+        let coord: RangedCoord<CountingRange, CountingRange> = RangedCoord::new(
+            CountingRange(counter.clone()),
+            CountingRange(counter.clone()),
+            (0..100, 100..0),
+        );
+
+        let count_lines = |coord: &RangedCoord<CountingRange, CountingRange>, limit| {
+            let mut lines = vec![];
+            coord
+                .draw_mesh(limit, limit, |line| {
+                    lines.push(match line {
+                        MeshLine::XMesh(from, _, v) => (from, *v),
+                        MeshLine::YMesh(from, _, v) => (from, *v),
+                    });
+                    Ok::<(), ()>(())
+                })
+                .unwrap();
+            lines
+        };
+
+        let bold = count_lines(&coord, 2);
+        assert_eq!(counter.get(), 2);
+        assert_eq!(bold[1], ((50, 100), 5));
+        assert_eq!(bold[4], ((0, 50), 5));
+
+        let light = count_lines(&coord, 10);
+        assert_eq!(counter.get(), 4);
+        assert_eq!(light.len(), 22);
+
+        // The clone, e.g. a restored chart state, shares the cache
+        let restored = coord.clone();
+        assert_eq!(count_lines(&restored, 2), bold);
+        assert_eq!(count_lines(&restored, 10), light);
+        assert_eq!(counter.get(), 4);
+    }
+
+    #[test]
+    fn test_custom_key_points() {
+        let counter = Rc::new(Cell::new(0));
+        let mut coord: RangedCoord<CountingRange, CountingRange> = RangedCoord::new(
+            CountingRange(counter.clone()),
+            CountingRange(counter.clone()),
+            (0..100, 100..0),
+        );
+        coord.set_x_key_points(Some(vec![3, 7]));
+
+        let mut lines = vec![];
+        coord
+            .draw_mesh(2, 2, |line| {
+                lines.push(match line {
+                    MeshLine::XMesh(from, _, v) => (from, *v),
+                    MeshLine::YMesh(from, _, v) => (from, *v),
+                });
+                Ok::<(), ()>(())
+            })
+            .unwrap();
+        assert_eq!(counter.get(), 1);
+        assert_eq!(&lines[..2], &[((30, 100), 3), ((70, 100), 7)]);
+        assert_eq!(lines.len(), 5);
+
+        assert_eq!(coord.x_key_points(2), vec![3, 7]);
+        assert_eq!(coord.y_key_points(2), vec![0, 5, 10]);
+
+        coord.set_x_key_points(None);
+        assert_eq!(coord.x_key_points(2), vec![0, 5, 10]);
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn test_mesh_label_cache() {
+        let coord: RangedCoord<CountingRange, CountingRange> = RangedCoord::new(
+            CountingRange(Rc::new(Cell::new(0))),
+            CountingRange(Rc::new(Cell::new(0))),
+            (0..100, 100..0),
+        );
+
+        let fmt_calls = Cell::new(0);
+        let draw_labels = |formatter_id| {
+            let mut labels = vec![];
+            coord
+                .draw_mesh_with_labels(
+                    2,
+                    2,
+                    formatter_id,
+                    |line| {
+                        fmt_calls.set(fmt_calls.get() + 1);
+                        match line {
+                            MeshLine::XMesh(_, _, v) => Some(format!("x={}", v)),
+                            MeshLine::YMesh(_, _, v) => Some(format!("y={}", v)),
+                        }
+                    },
+                    |_, label| {
+                        labels.push(label.unwrap().to_string());
+                        Ok::<(), ()>(())
+                    },
+                )
+                .unwrap();
+            labels
+        };
+
+        let labels = draw_labels(Some(1));
+        assert_eq!(labels[1], "x=5");
+        assert_eq!(labels[5], "y=10");
+        assert_eq!(fmt_calls.get(), 6);
+
+        assert_eq!(draw_labels(Some(1)), labels);
+        assert_eq!(fmt_calls.get(), 6);
+
+        // A different formatter id or no id at all formats the labels again
+        draw_labels(Some(2));
+        assert_eq!(fmt_calls.get(), 12);
+        draw_labels(None);
+        assert_eq!(fmt_calls.get(), 18);
+
+        coord.clear_mesh_cache();
+        draw_labels(Some(1));
+        assert_eq!(fmt_calls.get(), 24);
+    }
+
+    #[test]
+    fn test_label_size_cache() {
+        let coord: RangedCoord<RangedCoordf64, RangedCoordf64> =
+            RangedCoord::new(0.0..1.0, 0.0..1.0, (0..100, 100..0));
+
+        let estimates = Cell::new(0);
+        let estimate = || {
+            estimates.set(estimates.get() + 1);
+            Ok::<_, ()>((10, 5))
+        };
+        assert_eq!(coord.label_size("sans-serif", "0.5", estimate), Ok((10, 5)));
+        assert_eq!(coord.label_size("sans-serif", "0.5", estimate), Ok((10, 5)));
+        assert_eq!(coord.label_size("serif", "0.5", estimate), Ok((10, 5)));
+        assert_eq!(estimates.get(), 2);
+
+        // The clones share the cache
+        let restored = coord.clone();
+        assert_eq!(restored.label_size("serif", "0.5", estimate), Ok((10, 5)));
+        assert_eq!(estimates.get(), 2);
+
+        coord.clear_mesh_cache();
+        coord.label_size("sans-serif", "0.5", estimate).unwrap();
+        assert_eq!(estimates.get(), 3);
+    }
+
+    #[test]
+    fn test_label_cache_across_ranges() {
+        let mut coord: RangedCoord<RangedCoordf64, RangedCoordf64> =
+            RangedCoord::new(0.0..1.0, 0.0..1.0, (0..100, 100..0));
+
+        let fmt_calls = Cell::new(0);
+        let draw_labels = |coord: &RangedCoord<RangedCoordf64, RangedCoordf64>| {
+            coord
+                .draw_mesh_with_labels(
+                    2,
+                    2,
+                    Some(1),
+                    |_| {
+                        fmt_calls.set(fmt_calls.get() + 1);
+                        None
+                    },
+                    |_, _| Ok::<(), ()>(()),
+                )
+                .unwrap();
+            fmt_calls.get()
+        };
+
+        let calls = draw_labels(&coord);
+        coord.set_x_spec(0.0..2.0);
+        assert_eq!(draw_labels(&coord), calls * 2);
+
+        // Moving back to a previous range reuses its labels
+        coord.set_x_spec(0.0..1.0);
+        assert_eq!(draw_labels(&coord), calls * 2);
+        assert_eq!(draw_labels(&coord.clone()), calls * 2);
+
+        let estimates = Cell::new(0);
+        let estimate = || {
+            estimates.set(estimates.get() + 1);
+            Ok::<_, ()>((10, 5))
+        };
+        coord.label_size("sans-serif", "0.5", estimate).unwrap();
+        coord.label_size("sans-serif", "0.5", estimate).unwrap();
+        assert_eq!(estimates.get(), 1);
+
+        // The replaced key points have different labels
+        coord.set_x_key_points(Some(vec![0.25, 0.75]));
+        draw_labels(&coord);
+        assert!(fmt_calls.get() > calls * 2);
+        coord.label_size("sans-serif", "0.5", estimate).unwrap();
+        assert_eq!(estimates.get(), 2);
+    }
+
+    #[test]
+    fn test_reentrant_mesh_drawing() {
+        let coord: RangedCoord<CountingRange, CountingRange> = RangedCoord::new(
+            CountingRange(Rc::new(Cell::new(0))),
+            CountingRange(Rc::new(Cell::new(0))),
+            (0..100, 100..0),
+        );
+
+        // Drawing a mesh of the same coordinate within the closures must not deadlock
+        let mut inner_lines = 0;
+        coord
+            .draw_mesh_with_labels(
+                2,
+                2,
+                Some(1),
+                |_| {
+                    coord.draw_mesh(10, 10, |_| Ok::<(), ()>(())).unwrap();
+                    None
+                },
+                |_, _| {
+                    coord.draw_mesh(2, 2, |_| {
+                        inner_lines += 1;
+                        Ok::<(), ()>(())
+                    })
+                },
+            )
+            .unwrap();
+        assert_eq!(inner_lines, 36);
+    }
+
+    #[test]
+    fn test_pin_origin() {
+        let mut coord: RangedCoord<RangedCoordf64, RangedCoordf64> =
+            RangedCoord::new(0.0..1.0, -50.0..50.0, (0..100, 100..0));
+
+        assert!(coord.pin_y_origin(&0.0, 80));
+        assert_eq!(coord.translate(&(0.0, 0.0)).1, 80);
+        assert_eq!(coord.translate(&(0.0, 50.0)).1, 60);
+        assert_eq!(coord.translate(&(0.0, -50.0)).1, 100);
+
+        assert!(!coord.pin_y_origin(&80.0, 70));
+        assert!(!coord.pin_x_origin(&0.5, 120));
+        assert_eq!(coord.translate(&(0.5, 0.0)), (50, 80));
+
+        assert!(coord.pin_x_origin(&0.0, 30));
+        assert_eq!(coord.translate(&(0.0, 0.0)), (30, 80));
+        assert_eq!(coord.translate(&(1.0, 0.0)), (100, 80));
+    }
+}
@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+/// The equal-width bins dividing a range of the continuous values, which are used to count
+/// the values for the histograms and the 2D densities
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bins {
+    range: Range<f64>,
+    count: usize,
+}
+
+impl Bins {
+    /// Create the bins, the upper bound of the range belongs to the last bin
+    /// - `range`: The range of the values
+    /// - `count`: The number of bins, at least one bin is created
+    pub fn new(range: Range<f64>, count: usize) -> Self {
+        Self {
+            range,
+            count: count.max(1),
+        }
+    }
+
+    /// Get the range covered by the bins
+    pub fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+
+    /// Get the number of bins
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the width of each bin
+    pub fn width(&self) -> f64 {
+        (self.range.end - self.range.start) / self.count as f64
+    }
+
+    /// Get the range of the values in a bin
+    /// - `idx`: The index of the bin
+    pub fn bin_range(&self, idx: usize) -> Range<f64> {
+        let width = self.width();
+        let start = self.range.start + width * idx as f64;
+        start..start + width
+    }
+
+    /// Get the index of the bin containing the value, `None` if the value is out of the range
+    /// - `value`: The value
+    pub fn index_of(&self, value: f64) -> Option<usize> {
+        if !value.is_finite() || value < self.range.start || value > self.range.end {
+            return None;
+        }
+        let width = self.width();
+        if width <= 0.0 {
+            return Some(0);
+        }
+        let idx = ((value - self.range.start) / width).floor() as usize;
+        Some(idx.min(self.count - 1))
+    }
+
+    /// Count the values in each bin, the values out of the range are ignored
+    /// - `values`: The values
+    /// - **returns**: The counts indexed by the bin
+    pub fn histogram<I: IntoIterator<Item = f64>>(&self, values: I) -> Vec<u32> {
+        let mut counts = vec![0; self.count];
+        for idx in values.into_iter().filter_map(|v| self.index_of(v)) {
+            counts[idx] += 1;
+        }
+        counts
+    }
+}
+
+/// Count the points in each cell of the grid made of two sets of bins, the points out of the
+/// grid are ignored
+/// - `points`: The data points
+/// - `x_bins`: The bins of the X values
+/// - `y_bins`: The bins of the Y values
+/// - **returns**: The counts indexed by the X bin and then by the Y bin
+pub fn histogram_2d<I: IntoIterator<Item = (f64, f64)>>(
+    points: I,
+    x_bins: &Bins,
+    y_bins: &Bins,
+) -> Vec<Vec<u32>> {
+    let mut counts = vec![vec![0; y_bins.count()]; x_bins.count()];
+    for (x, y) in points {
+        if let (Some(i), Some(j)) = (x_bins.index_of(x), y_bins.index_of(y)) {
+            counts[i][j] += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bins() {
+        let bins = Bins::new(0.0..1.0, 4);
+        assert_eq!(bins.width(), 0.25);
+        assert_eq!(bins.bin_range(1), 0.25..0.5);
+        assert_eq!(bins.index_of(0.3), Some(1));
+        assert_eq!(bins.index_of(1.0), Some(3));
+        assert_eq!(bins.index_of(1.5), None);
+        assert_eq!(bins.index_of(f64::NAN), None);
+        assert_eq!(
+            bins.histogram(vec![0.1, 0.2, 0.9, 1.0, -1.0]),
+            vec![2, 0, 0, 2]
+        );
+
+        let grid = histogram_2d(
+            vec![(0.1, 0.1), (0.9, 0.9), (0.9, 0.8)],
+            &Bins::new(0.0..1.0, 2),
+            &Bins::new(0.0..1.0, 2),
+        );
+        assert_eq!(grid, vec![vec![1, 0], vec![0, 2]]);
+    }
+}
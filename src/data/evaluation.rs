@@ -0,0 +1,101 @@
+/// Count the true and the false positives at each distinct threshold, from the highest score to
+/// the lowest one. The samples with the same score are counted at the same threshold.
+fn confusion_counts<I: IntoIterator<Item = (f64, bool)>>(
+    data: I,
+) -> (Vec<(usize, usize)>, usize, usize) {
+    let mut data: Vec<_> = data.into_iter().filter(|(s, _)| !s.is_nan()).collect();
+    data.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    let mut counts = vec![];
+    let (mut tp, mut fp) = (0, 0);
+    for (idx, (score, label)) in data.iter().enumerate() {
+        if *label {
+            tp += 1;
+        } else {
+            fp += 1;
+        }
+        if data.get(idx + 1).map(|next| next.0) != Some(*score) {
+            counts.push((tp, fp));
+        }
+    }
+    (counts, tp, fp)
+}
+
+/// Compute the receiver operating characteristic (ROC) curve of a binary classifier
+/// - `data`: The samples, each of them is the score given by the classifier and if the sample
+///   is actually positive
+/// - **returns**: The points of the curve, each of them is the false positive rate and the true
+///   positive rate, from `(0, 0)` to `(1, 1)`. The curve is empty if either class is missing.
+pub fn roc_curve<I: IntoIterator<Item = (f64, bool)>>(data: I) -> Vec<(f64, f64)> {
+    let (counts, positives, negatives) = confusion_counts(data);
+    if positives == 0 || negatives == 0 {
+        return vec![];
+    }
+    let (p, n) = (positives as f64, negatives as f64);
+    std::iter::once((0.0, 0.0))
+        .chain(
+            counts
+                .into_iter()
+                .map(|(tp, fp)| (fp as f64 / n, tp as f64 / p)),
+        )
+        .collect()
+}
+
+/// Compute the precision-recall curve of a binary classifier
+/// - `data`: The samples, each of them is the score given by the classifier and if the sample
+///   is actually positive
+/// - **returns**: The points of the curve, each of them is the recall and the precision,
+///   starting from `(0, 1)`. The curve is empty if there's no positive sample.
+pub fn precision_recall_curve<I: IntoIterator<Item = (f64, bool)>>(data: I) -> Vec<(f64, f64)> {
+    let (counts, positives, _) = confusion_counts(data);
+    if positives == 0 {
+        return vec![];
+    }
+    let p = positives as f64;
+    std::iter::once((0.0, 1.0))
+        .chain(
+            counts
+                .into_iter()
+                .map(|(tp, fp)| (tp as f64 / p, tp as f64 / (tp + fp) as f64)),
+        )
+        .collect()
+}
+
+/// Compute the area under a curve with the trapezoidal rule
+/// - `points`: The points of the curve, ordered by the X value
+pub fn auc(points: &[(f64, f64)]) -> f64 {
+    points
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) / 2.0)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_evaluation_curves() {
+        let data = vec![(0.9, true), (0.8, false), (0.7, true), (0.1, false)];
+
+        let roc = roc_curve(data.clone());
+        assert_eq!(
+            roc,
+            vec![(0.0, 0.0), (0.0, 0.5), (0.5, 0.5), (0.5, 1.0), (1.0, 1.0)]
+        );
+        assert_eq!(auc(&roc), 0.75);
+
+        let pr = precision_recall_curve(data);
+        assert_eq!(pr[1], (0.5, 1.0));
+        assert_eq!(pr[2], (0.5, 0.5));
+        assert_eq!(pr.last(), Some(&(1.0, 0.5)));
+
+        // The tied scores give a single point
+        assert_eq!(
+            roc_curve(vec![(0.5, true), (0.5, false)]),
+            vec![(0.0, 0.0), (1.0, 1.0)]
+        );
+        assert!(roc_curve(vec![(0.5, true)]).is_empty());
+        assert_eq!(auc(&[(0.0, 0.0), (1.0, 1.0)]), 0.5);
+    }
+}
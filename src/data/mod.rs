@@ -3,8 +3,28 @@ The data processing module, which implements algorithms related to visualization
 Such as, down-sampling, etc.
 */
 
+mod binning;
+pub use binning::{histogram_2d, Bins};
+
 mod data_range;
 pub use data_range::fitting_range;
 
+mod evaluation;
+pub use evaluation::{auc, precision_recall_curve, roc_curve};
+
 mod quartiles;
 pub use quartiles::Quartiles;
+
+mod residual;
+pub use residual::{residuals, Residual};
+
+mod shares;
+pub use shares::stack_shares;
+
+mod survival;
+pub use survival::{KaplanMeier, SurvivalStep};
+
+mod table;
+#[cfg(feature = "svg")]
+pub(crate) use table::escape_html;
+pub use table::DataTable;
@@ -0,0 +1,67 @@
+/// The way to compare the data with the model in the residual panel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Residual {
+    /// The difference between the data and the model, `data - model`
+    Difference,
+    /// The ratio of the data to the model, `data / model`
+    Ratio,
+}
+
+impl Residual {
+    /// Get the value meaning the data matches the model, which is usually drawn as the
+    /// reference line of the residual panel
+    pub fn reference(&self) -> f64 {
+        match self {
+            Residual::Difference => 0.0,
+            Residual::Ratio => 1.0,
+        }
+    }
+}
+
+/// Compute the residuals or the ratios of the data to the model, the points of both series are
+/// paired by their order and the X values of the data are kept. The points whose ratio isn't
+/// finite are skipped.
+/// - `data`: The measured data points
+/// - `model`: The model values at the same points
+/// - `kind`: The way to compare the data with the model
+/// - **returns**: The points of the residual panel
+pub fn residuals<X, XM, Y, YM, D, M>(data: D, model: M, kind: Residual) -> Vec<(X, f64)>
+where
+    Y: Into<f64>,
+    YM: Into<f64>,
+    D: IntoIterator<Item = (X, Y)>,
+    M: IntoIterator<Item = (XM, YM)>,
+{
+    data.into_iter()
+        .zip(model)
+        .map(|((x, y), (_, m))| {
+            let (y, m) = (y.into(), m.into());
+            match kind {
+                Residual::Difference => (x, y - m),
+                Residual::Ratio => (x, y / m),
+            }
+        })
+        .filter(|(_, r)| r.is_finite())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_residuals() {
+        let data = vec![(0, 1.0), (1, 3.0), (2, 4.0)];
+        let model = vec![(0, 1.0), (1, 2.0), (2, 0.0)];
+
+        assert_eq!(
+            residuals(data.clone(), model.clone(), Residual::Difference),
+            vec![(0, 0.0), (1, 1.0), (2, 4.0)]
+        );
+        assert_eq!(
+            residuals(data, model, Residual::Ratio),
+            vec![(0, 1.0), (1, 1.5)]
+        );
+        assert_eq!(Residual::Ratio.reference(), 1.0);
+    }
+}
@@ -0,0 +1,57 @@
+/// Normalize the series to the shares of the total at each index and stack them, which is the
+/// data of the percent stacked area or bar chart. The values at the same index of all the
+/// series are summed up, and each series is mapped to the upper bound of its band, thus the
+/// last series is always 1.0. The missing, negative and non-finite values are taken as zero,
+/// and the index without any positive value has all the bands empty.
+/// - `series`: The values of each series, paired by their index
+/// - **returns**: The stacked shares of each series, which have the length of the longest series
+pub fn stack_shares<V: Into<f64> + Copy>(series: &[Vec<V>]) -> Vec<Vec<f64>> {
+    let len = series.iter().map(Vec::len).max().unwrap_or(0);
+    let value = |s: &Vec<V>, idx: usize| {
+        s.get(idx)
+            .map(|v| (*v).into())
+            .filter(|v: &f64| v.is_finite() && *v > 0.0)
+            .unwrap_or(0.0)
+    };
+
+    let totals: Vec<f64> = (0..len)
+        .map(|idx| series.iter().map(|s| value(s, idx)).sum())
+        .collect();
+
+    let mut acc = vec![0.0; len];
+    series
+        .iter()
+        .map(|s| {
+            (0..len)
+                .map(|idx| {
+                    if totals[idx] > 0.0 {
+                        acc[idx] += value(s, idx) / totals[idx];
+                    }
+                    acc[idx]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stack_shares() {
+        let shares = stack_shares(&[vec![1, 2, 0], vec![3, 2], vec![0, 4, 0]]);
+        assert_eq!(
+            shares,
+            vec![
+                vec![0.25, 0.25, 0.0],
+                vec![1.0, 0.5, 0.0],
+                vec![1.0, 1.0, 0.0],
+            ]
+        );
+
+        let shares = stack_shares(&[vec![-1.0, 1.0], vec![2.0, f64::NAN]]);
+        assert_eq!(shares, vec![vec![0.0, 1.0], vec![1.0, 1.0]]);
+        assert!(stack_shares::<f64>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+/// The quantile of the standard normal distribution for the 95% confidence interval
+const Z_95: f64 = 1.96;
+
+/// A step of the survival curve, the survival probability drops to `survival` at `time`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurvivalStep {
+    /// The time of the step
+    pub time: f64,
+    /// The estimated survival probability from this time on
+    pub survival: f64,
+    /// The lower bound of the 95% confidence interval
+    pub lower: f64,
+    /// The upper bound of the 95% confidence interval
+    pub upper: f64,
+}
+
+/// The Kaplan–Meier estimator of the survival function, which is computed from the times of the
+/// events and the censored observations. The confidence interval uses Greenwood's formula.
+#[derive(Clone, Debug)]
+pub struct KaplanMeier {
+    steps: Vec<SurvivalStep>,
+    censored: Vec<(f64, f64)>,
+    end: f64,
+}
+
+impl KaplanMeier {
+    /// Estimate the survival function, the observations with the same time are treated as
+    /// the events happening before the censoring
+    /// - `data`: The observations, each of them is the time and if the event is observed,
+    ///   otherwise the observation is censored at the time
+    pub fn estimate<I: IntoIterator<Item = (f64, bool)>>(data: I) -> Self {
+        let mut data: Vec<_> = data.into_iter().filter(|(t, _)| t.is_finite()).collect();
+        data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let start = data.first().map_or(0.0, |(t, _)| t.min(0.0));
+        let end = data.last().map_or(start, |(t, _)| *t);
+        let mut steps = vec![SurvivalStep {
+            time: start,
+            survival: 1.0,
+            lower: 1.0,
+            upper: 1.0,
+        }];
+        let mut censored = vec![];
+
+        let (mut at_risk, mut survival, mut greenwood) = (data.len(), 1.0, 0.0);
+        let mut idx = 0;
+        while idx < data.len() {
+            let time = data[idx].0;
+            let same_time = data[idx..].iter().take_while(|(t, _)| *t == time).count();
+            let events = data[idx..idx + same_time]
+                .iter()
+                .filter(|(_, event)| *event)
+                .count();
+
+            if events > 0 {
+                let (n, d) = (at_risk as f64, events as f64);
+                survival *= 1.0 - d / n;
+                greenwood += if n > d { d / (n * (n - d)) } else { 0.0 };
+                let se = survival * greenwood.sqrt();
+                steps.push(SurvivalStep {
+                    time,
+                    survival,
+                    lower: (survival - Z_95 * se).max(0.0),
+                    upper: (survival + Z_95 * se).min(1.0),
+                });
+            }
+            if same_time > events {
+                censored.push((time, survival));
+            }
+
+            at_risk -= same_time;
+            idx += same_time;
+        }
+
+        Self {
+            steps,
+            censored,
+            end,
+        }
+    }
+
+    /// Get the steps of the survival curve, the first step is the survival of 1 at time zero
+    /// or at the earliest observation
+    pub fn steps(&self) -> &[SurvivalStep] {
+        &self.steps
+    }
+
+    /// Get the censored observations, each of them is the time and the survival at the time
+    pub fn censored(&self) -> &[(f64, f64)] {
+        &self.censored
+    }
+
+    /// Get the time of the last observation, where the curve ends
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+
+    /// Get the estimated survival probability at the given time
+    pub fn survival_at(&self, time: f64) -> f64 {
+        self.steps
+            .iter()
+            .take_while(|step| step.time <= time)
+            .last()
+            .map_or(1.0, |step| step.survival)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kaplan_meier() {
+        let km = KaplanMeier::estimate(vec![
+            (1.0, true),
+            (1.0, true),
+            (2.0, false),
+            (2.0, false),
+            (3.0, true),
+            (3.0, true),
+            (4.0, false),
+            (5.0, true),
+        ]);
+
+        let survival: Vec<_> = km.steps().iter().map(|s| (s.time, s.survival)).collect();
+        assert_eq!(
+            survival,
+            vec![(0.0, 1.0), (1.0, 0.75), (3.0, 0.375), (5.0, 0.0)]
+        );
+        assert_eq!(km.censored(), &[(2.0, 0.75), (4.0, 0.375)]);
+        assert_eq!(km.end(), 5.0);
+        assert_eq!(km.survival_at(3.5), 0.375);
+        assert_eq!(km.survival_at(-1.0), 1.0);
+
+        let step = km.steps()[1];
+        assert!(step.lower < step.survival && step.survival < step.upper);
+        assert!(step.upper <= 1.0);
+    }
+}
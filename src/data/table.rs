@@ -0,0 +1,209 @@
+use crate::chart::ChartContext;
+use crate::coord::CoordTranslate;
+use crate::drawing::backend::DrawingBackend;
+
+use std::fmt::Display;
+use std::path::Path;
+
+/// Escape the special characters of HTML in the text
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// The table of the data points drawn by a chart, which can be published alongside the chart
+/// as an HTML table or a CSV file for the readers who can't see the chart.
+pub struct DataTable {
+    caption: String,
+    x_name: String,
+    y_name: String,
+    rows: Vec<[String; 3]>,
+}
+
+impl DataTable {
+    /// Create an empty table
+    /// - `caption`: The caption of the table, usually the title of the chart
+    pub fn new<S: Into<String>>(caption: S) -> Self {
+        Self {
+            caption: caption.into(),
+            x_name: "x".to_string(),
+            y_name: "y".to_string(),
+            rows: vec![],
+        }
+    }
+
+    /// Create the table from the series of the chart which have both the label and the data
+    /// points recorded with `SeriesAnno::data`. The drawn elements aren't read back, so only the
+    /// data recorded manually for each series is exported.
+    /// - `caption`: The caption of the table, usually the title of the chart
+    /// - `chart`: The chart the series are drawn on
+    pub fn from_chart<S: Into<String>, DB: DrawingBackend, CT: CoordTranslate>(
+        caption: S,
+        chart: &ChartContext<DB, CT>,
+    ) -> Self {
+        let mut table = Self::new(caption);
+        for anno in chart.get_series_anno() {
+            let label = anno.get_label();
+            if label.is_empty() {
+                continue;
+            }
+            table.add_series(label, anno.get_data().iter().map(|(x, y)| (x, y)));
+        }
+        table
+    }
+
+    /// Set the column names of the values, usually the axis descriptions
+    /// - `x_name`: The name of the X values
+    /// - `y_name`: The name of the Y values
+    pub fn column_names<X: Into<String>, Y: Into<String>>(mut self, x_name: X, y_name: Y) -> Self {
+        self.x_name = x_name.into();
+        self.y_name = y_name.into();
+        self
+    }
+
+    /// Add the data points of a series
+    /// - `name`: The name of the series, usually the label of the series in the legend
+    /// - `data`: The data points
+    pub fn add_series<N, X, Y, I>(&mut self, name: N, data: I) -> &mut Self
+    where
+        N: Into<String>,
+        X: Display,
+        Y: Display,
+        I: IntoIterator<Item = (X, Y)>,
+    {
+        let name = name.into();
+        for (x, y) in data {
+            self.rows
+                .push([name.clone(), format!("{}", x), format!("{}", y)]);
+        }
+        self
+    }
+
+    fn header(&self) -> [&str; 3] {
+        ["series", &self.x_name, &self.y_name]
+    }
+
+    /// Render the table as CSV, one data point per line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in std::iter::once(self.header()).chain(
+            self.rows
+                .iter()
+                .map(|row| [row[0].as_str(), row[1].as_str(), row[2].as_str()]),
+        ) {
+            let line: Vec<_> = row.iter().map(|field| escape_csv(field)).collect();
+            csv.push_str(&line.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Render the table as an HTML `<table>` element
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<table>\n");
+        if !self.caption.is_empty() {
+            html.push_str(&format!(
+                "<caption>{}</caption>\n",
+                escape_html(&self.caption)
+            ));
+        }
+        html.push_str("<thead>\n<tr>");
+        for name in self.header().iter() {
+            html.push_str(&format!("<th scope=\"col\">{}</th>", escape_html(name)));
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+        for row in &self.rows {
+            html.push_str(&format!(
+                "<tr><th scope=\"row\">{}</th><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&row[0]),
+                escape_html(&row[1]),
+                escape_html(&row[2])
+            ));
+        }
+        html.push_str("</tbody>\n</table>\n");
+        html
+    }
+
+    /// Write the table into a CSV file
+    /// - `path`: The path of the CSV file
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_csv())
+    }
+
+    /// Write the table into a standalone HTML file
+    /// - `path`: The path of the HTML file
+    pub fn save_html<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             </head>\n<body>\n{}</body>\n</html>\n",
+            escape_html(&self.caption),
+            self.to_html()
+        );
+        std::fs::write(path, html)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_data_table() {
+        let mut table = DataTable::new("Sales <2024>").column_names("month", "revenue, $");
+        table
+            .add_series("north", vec![(1, 10.5), (2, 12.0)])
+            .add_series("south \"B\"", vec![(1, 7.25)]);
+
+        assert_eq!(
+            table.to_csv(),
+            "series,month,\"revenue, $\"\nnorth,1,10.5\nnorth,2,12\n\"south \"\"B\"\"\",1,7.25\n"
+        );
+
+        let html = table.to_html();
+        assert!(html.contains("<caption>Sales &lt;2024&gt;</caption>"));
+        assert!(html.contains("<th scope=\"col\">revenue, $</th>"));
+        assert!(html.contains("<tr><th scope=\"row\">north</th><td>2</td><td>12</td></tr>"));
+        assert_eq!(html.matches("<tr>").count(), 4);
+    }
+
+    #[test]
+    fn test_data_table_from_chart() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+        let data = vec![(1, 2), (3, 4)];
+        chart
+            .draw_series(LineSeries::new(data.clone(), &RED))
+            .expect("Drawing error")
+            .label("line")
+            .data(data.clone());
+        // The series without a label is omitted
+        chart
+            .draw_series(LineSeries::new(data.clone(), &BLUE))
+            .expect("Drawing error")
+            .data(data);
+
+        let table = DataTable::from_chart("Chart", &chart);
+        assert_eq!(table.to_csv(), "series,x,y\nline,1,2\nline,3,4\n");
+    }
+}
@@ -7,6 +7,7 @@ use crate::style::{Color, FontDesc, SizeDesc, TextAlignment, TextStyle};
 use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::error::Error;
+use std::fmt::Debug;
 use std::iter::{once, repeat};
 use std::ops::Range;
 use std::rc::Rc;
@@ -105,6 +106,16 @@ impl Rect {
     }
 }
 
+/// The largest absolute pixel coordinate passed to the backend when it clips the drawing, which
+/// keeps the far out of range points from overflowing the rasterization
+const CLIPPED_COORD_LIMIT: i32 = 1 << 24;
+
+/// Limit the point passed to the backend which clips the drawing, see `CLIPPED_COORD_LIMIT`
+fn limit_clipped_coord((x, y): BackendCoord) -> BackendCoord {
+    let limit = |v: i32| v.clamp(-CLIPPED_COORD_LIMIT, CLIPPED_COORD_LIMIT);
+    (limit(x), limit(y))
+}
+
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
 /// high level drawing API. The major functionality provided by the drawing area is
 ///     1. Layout specification - Split the parent drawing area into sub-drawing-areas
@@ -197,6 +208,37 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
         })
     }
 
+    /// Draw the mesh along with the labels of the mesh lines, see
+    /// `RangedCoord::draw_mesh_with_labels` for how the labels are cached
+    pub fn draw_mesh_with_labels<DrawFunc, FmtLabel>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_count_max: usize,
+        x_count_max: usize,
+        formatter_id: Option<u64>,
+        fmt_label: FmtLabel,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(
+            &mut DB,
+            MeshLine<X, Y>,
+            Option<&str>,
+        ) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        X::ValueType: Debug,
+        Y::ValueType: Debug,
+    {
+        self.backend_ops(move |b| {
+            self.coord.draw_mesh_with_labels(
+                y_count_max,
+                x_count_max,
+                formatter_id,
+                fmt_label,
+                |line, label| draw_func(b, line, label),
+            )
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -231,6 +273,11 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         }
     }
 
+    /// Get the resolution of the underlying backend in pixels per inch
+    pub fn get_dpi(&self) -> f64 {
+        RefCell::borrow(&*self.backend).get_dpi()
+    }
+
     /// Get the area dimension in pixel
     pub fn dim_in_pixel(&self) -> (u32, u32) {
         (
@@ -300,6 +347,21 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.present())
     }
 
+    /// Start an opacity group, the following drawing is blended onto the image as a whole with
+    /// the opacity at the matching `pop_opacity` call, see `DrawingBackend::push_opacity`
+    ///
+    /// - `opacity`: The opacity of the group, from 0 to 1
+    /// - **returns**: If the backend supports the opacity groups, `pop_opacity` should be
+    ///   called only if it does
+    pub fn push_opacity(&self, opacity: f64) -> Result<bool, DrawingAreaError<DB>> {
+        self.backend_ops(|b| b.push_opacity(opacity))
+    }
+
+    /// Blend the opacity group started by the last successful `push_opacity` call
+    pub fn pop_opacity(&self) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| b.pop_opacity())
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
@@ -313,6 +375,179 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
     }
 
+    /// Draw the elements clipped to this drawing area. Different from `draw`, which moves the
+    /// points out of the area onto its border, the geometry of the elements is kept and the
+    /// parts out of the area are cut away by the backend. If the backend doesn't support
+    /// clipping, the elements are drawn in the same way as `draw`.
+    ///
+    /// - `elements`: The elements to draw
+    pub fn draw_clipped<E, R, I>(&self, elements: I) -> Result<(), DrawingAreaError<DB>>
+    where
+        for<'a> &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        I: IntoIterator<Item = R>,
+    {
+        let dim = self.dim_in_pixel();
+        self.backend_ops(move |b| {
+            let rect = &self.rect;
+            let clipped = b.push_clip((rect.x0, rect.y0), (rect.x1 - 1, rect.y1 - 1))?;
+            let result = elements.into_iter().try_for_each(|element| {
+                let element: &E = element.borrow();
+                let backend_coords = element.point_iter().into_iter().map(|p| {
+                    let p = self.coord.translate(p.borrow());
+                    if clipped {
+                        limit_clipped_coord(p)
+                    } else {
+                        rect.truncate(p)
+                    }
+                });
+                element.draw(backend_coords, b, dim)
+            });
+            if clipped {
+                b.pop_clip()?;
+            }
+            result
+        })
+    }
+
+    /// Check if the element is entirely out of this drawing area, that is all of its points are
+    /// beyond the same side of the area by more than the margin. Such an element is invisible
+    /// unless its shape extends farther than the margin from its points.
+    ///
+    /// - `element`: The element to check
+    /// - `margin`: The margin around the area in pixels
+    /// - **returns**: If the element is out of the area, an element without points never is
+    pub fn is_out_of_area<'a, E>(&self, element: &'a E, margin: u32) -> bool
+    where
+        &'a E: PointCollection<'a, CT::From>,
+    {
+        let ((x0, y0), (x1, y1)) = self.rect_with_margin(margin);
+
+        // If all the points seen so far are on the left, right, top and bottom of the area
+        let mut outside = [true; 4];
+        let mut empty = true;
+        for p in element.point_iter() {
+            let (x, y) = self.coord.translate(p.borrow());
+            empty = false;
+            outside[0] &= x < x0;
+            outside[1] &= x > x1;
+            outside[2] &= y < y0;
+            outside[3] &= y > y1;
+            if !outside.iter().any(|&o| o) {
+                return false;
+            }
+        }
+        !empty
+    }
+
+    /// Check if the point is out of this drawing area by more than the margin
+    ///
+    /// - `point`: The point to check
+    /// - `margin`: The margin around the area in pixels
+    /// - **returns**: If the point is out of the area
+    pub fn is_point_out_of_area(&self, point: &CT::From, margin: u32) -> bool {
+        let ((x0, y0), (x1, y1)) = self.rect_with_margin(margin);
+        let (x, y) = self.coord.translate(point);
+        x < x0 || x > x1 || y < y0 || y > y1
+    }
+
+    /// Get the corners of this drawing area expanded by the margin in pixels
+    fn rect_with_margin(&self, margin: u32) -> (BackendCoord, BackendCoord) {
+        let margin = margin.min(i32::MAX as u32) as i32;
+        let rect = &self.rect;
+        (
+            (
+                rect.x0.saturating_sub(margin),
+                rect.y0.saturating_sub(margin),
+            ),
+            (
+                (rect.x1 - 1).saturating_add(margin),
+                (rect.y1 - 1).saturating_add(margin),
+            ),
+        )
+    }
+
+    /// Draw the same element at each of the positions. The element is defined in the pixel
+    /// coordinate relative to the position, for example `Circle::new((0, 0), 3, &RED)`.
+    /// Backends which are able to render the element only once and reuse it, such as the bitmap
+    /// and SVG backends, draw a large number of markers much faster than `draw` does.
+    ///
+    /// - `element`: The element to stamp
+    /// - `positions`: The positions in the guest coordinate
+    pub fn stamp_repeated<'a, E, P, I>(
+        &self,
+        element: &'a E,
+        positions: I,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, BackendCoord>,
+        E: Drawable<DB>,
+        P: Borrow<CT::From>,
+        I: IntoIterator<Item = P>,
+    {
+        self.stamp_impl(element, positions, false)
+    }
+
+    /// Draw the same element at each of the positions clipped to this drawing area. Different
+    /// from `stamp_repeated`, the positions out of the area aren't moved onto its border, so the
+    /// elements crossing the border are cut away by the backend. If the backend doesn't support
+    /// clipping, the elements are stamped in the same way as `stamp_repeated`.
+    ///
+    /// - `element`: The element to stamp
+    /// - `positions`: The positions in the guest coordinate
+    pub fn stamp_repeated_clipped<'a, E, P, I>(
+        &self,
+        element: &'a E,
+        positions: I,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, BackendCoord>,
+        E: Drawable<DB>,
+        P: Borrow<CT::From>,
+        I: IntoIterator<Item = P>,
+    {
+        self.stamp_impl(element, positions, true)
+    }
+
+    fn stamp_impl<'a, E, P, I>(
+        &self,
+        element: &'a E,
+        positions: I,
+        clip: bool,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, BackendCoord>,
+        E: Drawable<DB>,
+        P: Borrow<CT::From>,
+        I: IntoIterator<Item = P>,
+    {
+        let dim = self.dim_in_pixel();
+        self.backend_ops(move |b| {
+            let rect = &self.rect;
+            let clipped = clip && b.push_clip((rect.x0, rect.y0), (rect.x1 - 1, rect.y1 - 1))?;
+            let positions = positions.into_iter().map(|p| {
+                let p = self.coord.translate(p.borrow());
+                if clipped {
+                    limit_clipped_coord(p)
+                } else {
+                    rect.truncate(p)
+                }
+            });
+            let result = b.stamp_repeated(positions, |b, (x0, y0)| {
+                let points = element.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + x0, p.1 + y0)
+                });
+                element.draw(points, b, dim)
+            });
+            if clipped {
+                b.pop_clip()?;
+            }
+            result
+        })
+    }
+
     /// Map coordinate to the backend coordinate
     pub fn map_coordinate(&self, coord: &CT::From) -> BackendCoord {
         self.coord.translate(coord)
@@ -524,6 +759,10 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     pub fn as_coord_spec(&self) -> &CT {
         &self.coord
     }
+
+    pub(crate) fn as_coord_spec_mut(&mut self) -> &mut CT {
+        &mut self.coord
+    }
 }
 
 #[cfg(test)]
@@ -848,4 +1087,31 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[test]
+    fn test_stamp_repeated() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            let mut expected = vec![(15, 5), (35, 25), (55, 45)].into_iter();
+            m.check_draw_circle(move |_, _, _, center, radius| {
+                assert_eq!(center, expected.next().unwrap());
+                assert_eq!(radius, 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let drawing_area = drawing_area.apply_coord_spec(RangedCoord::<
+            RangedCoordi32,
+            RangedCoordi32,
+        >::new(
+            0..100, 0..100, (0..100, 0..100)
+        ));
+        drawing_area
+            .stamp_repeated(
+                &Circle::new((5, 5), 3, &RED),
+                vec![(10, 0), (30, 20), (50, 40)],
+            )
+            .unwrap();
+    }
 }
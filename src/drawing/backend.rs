@@ -1,4 +1,6 @@
-use crate::style::{Color, FontDesc, FontError, RGBAColor, ShapeStyle, TextStyle};
+use crate::style::{
+    Color, FontDesc, FontError, FontTransform, RGBAColor, ShapeStyle, TextAlignment, TextStyle,
+};
 use std::error::Error;
 
 /// A coordinate in the image
@@ -58,6 +60,94 @@ impl BackendStyle for ShapeStyle {
     }
 }
 
+/// The affine transformation of the backend coordinate, which maps the point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackendTransform {
+    matrix: [f64; 6],
+}
+
+impl BackendTransform {
+    /// The transformation that does nothing
+    pub const fn identity() -> Self {
+        Self {
+            matrix: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        }
+    }
+
+    /// Create a transformation from the matrix coefficients `[a, b, c, d, e, f]`
+    pub const fn from_matrix(matrix: [f64; 6]) -> Self {
+        Self { matrix }
+    }
+
+    /// Create a translation
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self::from_matrix([1.0, 0.0, 0.0, 1.0, dx, dy])
+    }
+
+    /// Create a scaling around the origin
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::from_matrix([sx, 0.0, 0.0, sy, 0.0, 0.0])
+    }
+
+    /// Create a rotation around the origin, the positive angle rotates clockwise on the screen
+    /// - `angle`: The angle in radians
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_matrix([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Get the matrix coefficients `[a, b, c, d, e, f]`
+    pub fn matrix(&self) -> [f64; 6] {
+        self.matrix
+    }
+
+    /// Combine two transformations, the result applies this transformation first and then `next`
+    pub fn then(&self, next: &Self) -> Self {
+        let [a, b, c, d, e, f] = self.matrix;
+        let [na, nb, nc, nd, ne, nf] = next.matrix;
+        Self::from_matrix([
+            na * a + nc * b,
+            nb * a + nd * b,
+            na * c + nc * d,
+            nb * c + nd * d,
+            na * e + nc * f + ne,
+            nb * e + nd * f + nf,
+        ])
+    }
+
+    /// Transform a point
+    pub fn apply(&self, (x, y): BackendCoord) -> BackendCoord {
+        let [a, b, c, d, e, f] = self.matrix;
+        let (x, y) = (f64::from(x), f64::from(y));
+        (
+            (a * x + c * y + e).round() as i32,
+            (b * x + d * y + f).round() as i32,
+        )
+    }
+
+    /// Get the factor by which the transformation scales the lengths on average, this is used
+    /// for the sizes that can't be transformed exactly, such as the circle radius
+    pub fn scale_factor(&self) -> f64 {
+        let [a, b, c, d, _, _] = self.matrix;
+        (a * d - b * c).abs().sqrt()
+    }
+
+    /// Check if this is the identity transformation
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+}
+
+impl Default for BackendTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// The default resolution of the drawing backends, which is 96 pixels per inch
+pub const DEFAULT_DPI: f64 = 96.0;
+
 ///  The drawing backend trait, which implements the low-level drawing APIs.
 ///  This trait has a set of default implementation. And the minimal requirement of
 ///  implementing a drawing backend is implementing the `draw_pixel` function.
@@ -72,6 +162,12 @@ pub trait DrawingBackend: Sized {
     /// Get the dimension of the drawing backend in pixel
     fn get_size(&self) -> (u32, u32);
 
+    /// Get the resolution of the drawing backend in pixels per inch, which is used to resolve
+    /// the physical sizes, e.g. the stroke width in points. The default is the CSS pixel.
+    fn get_dpi(&self) -> f64 {
+        DEFAULT_DPI
+    }
+
     /// Ensure the backend is ready to draw
     fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
@@ -82,6 +178,87 @@ pub trait DrawingBackend: Sized {
     /// pending changes on the screen.
     fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Push a transformation onto the transformation stack of the backend. All the following
+    /// drawing operations are transformed until the matching `pop_transform` call, and the
+    /// pushed transformation is applied in the local coordinate of the previously pushed one.
+    ///
+    /// - `transform`: The transformation to push
+    /// - **returns**: If the backend applies the transformation. The default implementation
+    ///   doesn't support transformations and returns `false`, in this case `pop_transform`
+    ///   shouldn't be called and the caller is responsible for transforming the coordinates.
+    fn push_transform(
+        &mut self,
+        _transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
+    /// Pop the transformation pushed by the last successful `push_transform` call
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Push a clip rectangle onto the clip stack of the backend. All the following drawing
+    /// operations are restricted to the rectangle, intersected with the previously pushed ones,
+    /// until the matching `pop_clip` call. The rectangle is in the pixel coordinate of the
+    /// backend, regardless of the pushed transformations.
+    ///
+    /// - `upper_left`: The upper left corner of the clip rectangle
+    /// - `bottom_right`: The bottom right corner of the clip rectangle, which is inclusive
+    /// - **returns**: If the backend clips the drawing. The default implementation doesn't
+    ///   support clipping and returns `false`, in this case `pop_clip` shouldn't be called and the
+    ///   caller is responsible for keeping the drawing inside the rectangle.
+    fn push_clip(
+        &mut self,
+        _upper_left: BackendCoord,
+        _bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
+    /// Pop the clip rectangle pushed by the last successful `push_clip` call
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Start an opacity group. All the following drawing operations are composited with each
+    /// other first, and the result is blended onto the image with the opacity at the matching
+    /// `pop_opacity` call, so the overlapping shapes in the group don't look darker.
+    ///
+    /// - `opacity`: The opacity of the group, from 0 to 1
+    /// - **returns**: If the backend supports the opacity groups. The default implementation
+    ///   doesn't and returns `false`, in this case `pop_opacity` shouldn't be called and the
+    ///   shapes are drawn opaque.
+    fn push_opacity(&mut self, _opacity: f64) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
+    /// Blend the opacity group started by the last successful `push_opacity` call
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Draw the same shape repeatedly at the given positions. The backend may render the shape
+    /// only once and reuse the result for each position, so `draw` should produce the same
+    /// output regardless of the origin.
+    ///
+    /// - `positions`: The positions to place the shape
+    /// - `draw`: The function that draws the shape with the given origin
+    fn stamp_repeated<I, F>(
+        &mut self,
+        positions: I,
+        draw: F,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>
+    where
+        I: IntoIterator<Item = BackendCoord>,
+        F: Fn(&mut Self, BackendCoord) -> Result<(), DrawingErrorKind<Self::ErrorType>>,
+    {
+        for pos in positions {
+            draw(self, pos)?;
+        }
+        Ok(())
+    }
+
     /// Draw a pixel on the drawing backend
     /// - `point`: The backend pixel-based coordinate to draw
     /// - `color`: The color of the pixel
@@ -185,6 +362,10 @@ pub trait DrawingBackend: Sized {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let FontTransform::Vertical = style.font.get_transform() {
+            return draw_vertical_text(self, text, style, pos);
+        }
+
         let font = &style.font;
         let color = &style.color;
         if color.alpha() == 0.0 {
@@ -255,3 +436,34 @@ pub trait DrawingBackend: Sized {
         Ok(())
     }
 }
+
+/// Draw the text in the vertical writing mode, see `FontTransform::Vertical`. The characters
+/// are drawn one by one without the transformation, thus every backend supports the vertical
+/// text as long as it's able to draw the horizontal text.
+///
+/// - `backend`: The backend to draw on
+/// - `text`: The text to draw
+/// - `style`: The text style with the vertical writing mode
+/// - `pos`: The upper left corner of the column
+pub(crate) fn draw_vertical_text<DB: DrawingBackend>(
+    backend: &mut DB,
+    text: &str,
+    style: &TextStyle,
+    pos: BackendCoord,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let (column_width, _) = backend.estimate_text_size(text, &style.font)?;
+    let upright = style
+        .transform(FontTransform::None)
+        .alignment(TextAlignment::Left);
+    let cell = style.font.get_size();
+
+    for (idx, c) in text.chars().enumerate() {
+        let c = c.encode_utf8(&mut [0; 4]).to_string();
+        let (w, _) = backend.estimate_text_size(&c, &upright.font)?;
+        let x = pos.0 + (column_width as i32 - w as i32) / 2;
+        let y = pos.1 + (cell * idx as f64).round() as i32;
+        backend.draw_text(&c, &upright, (x, y))?;
+    }
+
+    Ok(())
+}
@@ -1,10 +1,15 @@
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, BackendTransform, DrawingBackend, DrawingErrorKind, DEFAULT_DPI,
+};
+use crate::geometry;
 use crate::style::{Color, RGBAColor};
 use std::marker::PhantomData;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod image_encoding_support {
+    pub(super) use crate::drawing::OutputHook;
     pub(super) use image::{ImageBuffer, ImageError, Rgb};
+    pub(super) use std::io::Write;
     pub(super) use std::path::Path;
     pub(super) type BorrowedImage<'a> = ImageBuffer<Rgb<u8>, &'a mut [u8]>;
 }
@@ -98,6 +103,8 @@ mod gif_support {
 enum Target<'a> {
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     File(&'a Path),
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    Hooked(&'a Path, OutputHook<'a>),
     Buffer(PhantomData<&'a u32>),
     #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
     Gif(Box<gif_support::GifFile>),
@@ -699,12 +706,32 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// The resolution of the image in pixels per inch
+    dpi: f64,
+    /// The stack of the accumulated transformations
+    transform: Vec<BackendTransform>,
+    /// The stack of the clip rectangles intersected with the previous ones
+    clip: Vec<(BackendCoord, BackendCoord)>,
+    /// The pixels recorded for the stamp, which is rendered once and reused
+    recording: Option<Vec<(BackendCoord, RGBAColor)>>,
+    /// The stack of the opacity groups, with the copy of the image before each group started
+    opacity: Vec<(Vec<u8>, f64)>,
     _pantomdata: PhantomData<P>,
 }
 
 impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
     /// The number of bytes per pixel
     const PIXEL_SIZE: usize = P::PIXEL_SIZE;
+
+    /// Set the resolution of the image, e.g. 300 for a print, which is used to resolve the
+    /// physical sizes such as the stroke width in points
+    ///
+    /// - `dpi`: The number of pixels per inch
+    /// - **returns**: The backend with the resolution
+    pub fn with_dpi(mut self, dpi: f64) -> Self {
+        self.dpi = dpi;
+        self
+    }
 }
 
 impl<'a> BitMapBackend<'a, RGBPixel> {
@@ -716,6 +743,37 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            dpi: DEFAULT_DPI,
+            transform: vec![],
+            clip: vec![],
+            recording: None,
+            opacity: vec![],
+            _pantomdata: PhantomData,
+        }
+    }
+
+    /// Create a new bitmap backend which encodes the image as PNG and writes it through the
+    /// output hook rather than into the file, see `OutputHook`
+    ///
+    /// - `path`: The path passed to the hook
+    /// - `dimension`: The size of the image
+    /// - `hook`: The hook opening the output stream
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn with_output_hook<T, H>(path: &'a T, (w, h): (u32, u32), hook: H) -> Self
+    where
+        T: AsRef<Path> + ?Sized,
+        H: FnMut(&Path) -> std::io::Result<Box<dyn Write + 'a>> + Send + 'a,
+    {
+        Self {
+            target: Target::Hooked(path.as_ref(), Box::new(hook)),
+            size: (w, h),
+            buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
+            saved: false,
+            dpi: DEFAULT_DPI,
+            transform: vec![],
+            clip: vec![],
+            recording: None,
+            opacity: vec![],
             _pantomdata: PhantomData,
         }
     }
@@ -744,6 +802,11 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            dpi: DEFAULT_DPI,
+            transform: vec![],
+            clip: vec![],
+            recording: None,
+            opacity: vec![],
             _pantomdata: PhantomData,
         })
     }
@@ -787,6 +850,11 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
             saved: false,
+            dpi: DEFAULT_DPI,
+            transform: vec![],
+            clip: vec![],
+            recording: None,
+            opacity: vec![],
             _pantomdata: PhantomData,
         })
     }
@@ -796,6 +864,61 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
         self.buffer.borrow_buffer()
     }
 
+    /// Get the current clip rectangle, which is the whole image if there's none
+    fn clip_rect(&self) -> (BackendCoord, BackendCoord) {
+        let (w, h) = self.size;
+        self.clip
+            .last()
+            .copied()
+            .unwrap_or(((0, 0), (w as i32 - 1, h as i32 - 1)))
+    }
+
+    /// Check if the pixel is inside the current clip rectangle
+    fn in_clip(&self, (x, y): BackendCoord) -> bool {
+        match self.clip.last() {
+            Some(&((x0, y0), (x1, y1))) => x0 <= x && x <= x1 && y0 <= y && y <= y1,
+            None => true,
+        }
+    }
+
+    /// Intersect the rectangle with the current clip rectangle
+    /// - **returns**: The intersection, `None` if it's empty
+    fn clip_box(&self, a: BackendCoord, b: BackendCoord) -> Option<(BackendCoord, BackendCoord)> {
+        if self.clip.is_empty() {
+            return Some((a, b));
+        }
+        let ((cx0, cy0), (cx1, cy1)) = self.clip_rect();
+        let (x0, x1) = (a.0.min(b.0).max(cx0), a.0.max(b.0).min(cx1));
+        let (y0, y1) = (a.1.min(b.1).max(cy0), a.1.max(b.1).min(cy1));
+        if x0 > x1 || y0 > y1 {
+            return None;
+        }
+        Some(((x0, y0), (x1, y1)))
+    }
+
+    /// Get the current clip rectangle grown by the margin, which is used to cut the geometry
+    /// before rasterization, `None` while the pixels are recorded, since they are moved later
+    fn geometry_clip_rect(&self, margin: i32) -> Option<(BackendCoord, BackendCoord)> {
+        if self.recording.is_some() {
+            return None;
+        }
+        let ((x0, y0), (x1, y1)) = self.clip_rect();
+        Some(((x0 - margin, y0 - margin), (x1 + margin, y1 + margin)))
+    }
+
+    /// Get the current transformation if there's any
+    fn current_transform(&self) -> Option<BackendTransform> {
+        self.transform.last().copied()
+    }
+
+    /// Run the drawing operation in the device coordinate, with the transformation disabled
+    fn in_device_space<R>(&mut self, op: impl FnOnce(&mut Self) -> R) -> R {
+        let stack = std::mem::take(&mut self.transform);
+        let ret = op(self);
+        self.transform = stack;
+        ret
+    }
+
     /// Split a bitmap backend vertically into several sub drawing area which allows
     /// multi-threading rendering.
     ///
@@ -826,7 +949,7 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
                         ((end - begin) * w) as usize * Self::PIXEL_SIZE,
                     )
                 };
-                Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
+                BitMapBackend::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
             })
             .collect()
     }
@@ -839,6 +962,10 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         self.size
     }
 
+    fn get_dpi(&self) -> f64 {
+        self.dpi
+    }
+
     fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
         self.saved = false;
         Ok(())
@@ -869,6 +996,16 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
                     ))
                 }
             }
+            Target::Hooked(path, hook) => {
+                let io_error = |e| DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e));
+                let mut writer = hook(path).map_err(io_error)?;
+                image::png::PNGEncoder::new(&mut writer)
+                    .encode(self.buffer.borrow_buffer(), w, h, image::ColorType::RGB(8))
+                    .and_then(|_| writer.flush())
+                    .map_err(io_error)?;
+                self.saved = true;
+                Ok(())
+            }
             Target::Buffer(_) => Ok(()),
 
             #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
@@ -882,12 +1019,72 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         }
     }
 
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<BitMapBackendError>> {
+        let parent = self.current_transform().unwrap_or_default();
+        self.transform.push(transform.then(&parent));
+        Ok(true)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.transform.pop();
+        Ok(())
+    }
+
+    fn push_clip(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<BitMapBackendError>> {
+        let ((x0, y0), (x1, y1)) = self.clip_rect();
+        self.clip.push((
+            (upper_left.0.max(x0), upper_left.1.max(y0)),
+            (bottom_right.0.min(x1), bottom_right.1.min(y1)),
+        ));
+        Ok(true)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.clip.pop();
+        Ok(())
+    }
+
+    /// Copy the image, so that the group drawn on the top of it can be faded at the end
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<BitMapBackendError>> {
+        if self.recording.is_some() {
+            return Ok(false);
+        }
+        let snapshot = self.get_raw_pixel_buffer().to_vec();
+        self.opacity.push((snapshot, opacity.clamp(0.0, 1.0)));
+        Ok(true)
+    }
+
+    /// Blend the image with the group onto the copy taken before the group started. Since the
+    /// shapes in the group are already composited with each other, fading their change of the
+    /// image is the same as blending the group as a single layer.
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if let Some((snapshot, opacity)) = self.opacity.pop() {
+            for (pixel, before) in self.get_raw_pixel_buffer().iter_mut().zip(snapshot) {
+                let (after, before) = (f64::from(*pixel), f64::from(before));
+                *pixel = (before + (after - before) * opacity).round() as u8;
+            }
+        }
+        Ok(())
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
         color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        if point.0 < 0 || point.1 < 0 {
+        let point = self.current_transform().map_or(point, |t| t.apply(point));
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push((point, color.clone()));
+            return Ok(());
+        }
+        if point.0 < 0 || point.1 < 0 || !self.in_clip(point) {
             return Ok(());
         }
 
@@ -905,10 +1102,19 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         to: (i32, i32),
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(t) = self.current_transform() {
+            let (from, to) = (t.apply(from), t.apply(to));
+            return self.in_device_space(|b| b.draw_line(from, to, style));
+        }
+
         let alpha = style.as_color().alpha();
         let (r, g, b) = style.as_color().rgb();
 
-        if from.0 == to.0 || from.1 == to.1 {
+        if self.recording.is_none() && (from.0 == to.0 || from.1 == to.1) {
+            let (from, to) = match self.clip_box(from, to) {
+                Some(clipped) => clipped,
+                None => return Ok(()),
+            };
             if alpha >= 1.0 {
                 if from.1 == to.1 {
                     P::fill_rect_fast(self, from, to, r, g, b);
@@ -921,6 +1127,19 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
             return Ok(());
         }
 
+        let (from, to) = match self.geometry_clip_rect(1) {
+            Some(rect)
+                if style.stroke_width() == 1
+                    && !(geometry::is_inside(from, rect) && geometry::is_inside(to, rect)) =>
+            {
+                match geometry::clip_line(from, to, rect) {
+                    Some(clipped) => clipped,
+                    None => return Ok(()),
+                }
+            }
+            _ => (from, to),
+        };
+
         crate::drawing::rasterizer::draw_line(self, from, to, style)
     }
 
@@ -931,9 +1150,37 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(t) = self.current_transform() {
+            let [_, skew_y, skew_x, _, _, _] = t.matrix();
+            if skew_x == 0.0 && skew_y == 0.0 {
+                let (a, b) = (t.apply(upper_left), t.apply(bottom_right));
+                let (a, b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+                return self.in_device_space(|back| back.draw_rect(a, b, style, fill));
+            }
+            let corners = [
+                upper_left,
+                (bottom_right.0, upper_left.1),
+                bottom_right,
+                (upper_left.0, bottom_right.1),
+                upper_left,
+            ];
+            let corners: Vec<_> = corners.iter().map(|&p| t.apply(p)).collect();
+            return self.in_device_space(|back| {
+                if fill {
+                    back.fill_polygon(corners, style)
+                } else {
+                    back.draw_path(corners, style)
+                }
+            });
+        }
+
         let alpha = style.as_color().alpha();
         let (r, g, b) = style.as_color().rgb();
-        if fill {
+        if fill && self.recording.is_none() {
+            let (upper_left, bottom_right) = match self.clip_box(upper_left, bottom_right) {
+                Some(clipped) => clipped,
+                None => return Ok(()),
+            };
             if alpha >= 1.0 {
                 P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
             } else {
@@ -944,20 +1191,118 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         crate::drawing::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    /// Rasterize the shape once and copy the rasterized pixels to each position
+    fn stamp_repeated<I, F>(
+        &mut self,
+        positions: I,
+        draw: F,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>
+    where
+        I: IntoIterator<Item = BackendCoord>,
+        F: Fn(&mut Self, BackendCoord) -> Result<(), DrawingErrorKind<Self::ErrorType>>,
+    {
+        if self.current_transform().is_some() || self.recording.is_some() {
+            for pos in positions {
+                draw(self, pos)?;
+            }
+            return Ok(());
+        }
+
+        self.recording = Some(vec![]);
+        let result = draw(self, (0, 0));
+        let pixels = self.recording.take().unwrap_or_default();
+        result?;
+
+        let (w, h) = self.get_size();
+        for (x0, y0) in positions {
+            for ((x, y), color) in pixels.iter() {
+                let (x, y) = (x + x0, y + y0);
+                if x < 0 || y < 0 || x >= w as i32 || y >= h as i32 || !self.in_clip((x, y)) {
+                    continue;
+                }
+                P::draw_pixel(self, (x, y), color.rgb(), color.alpha());
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(t) = self.current_transform() {
+            let center = t.apply(center);
+            let radius = (f64::from(radius) * t.scale_factor()).round() as u32;
+            return self.in_device_space(|b| b.draw_circle(center, radius, style, fill));
+        }
+        if let Some(((x0, y0), (x1, y1))) = self.geometry_clip_rect(1) {
+            let r = i64::from(radius) + i64::from(style.stroke_width());
+            let (cx, cy) = (i64::from(center.0), i64::from(center.1));
+            if cx + r < i64::from(x0)
+                || cx - r > i64::from(x1)
+                || cy + r < i64::from(y0)
+                || cy - r > i64::from(y1)
+            {
+                return Ok(());
+            }
+        }
+        crate::drawing::rasterizer::draw_circle(self, center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(t) = self.current_transform() {
+            let vert: Vec<_> = vert.into_iter().map(|p| t.apply(p)).collect();
+            return self.in_device_space(|b| b.fill_polygon(vert, style));
+        }
+        let mut vert_buf: Vec<_> = vert.into_iter().collect();
+        if let Some(rect) = self.geometry_clip_rect(1) {
+            if vert_buf.iter().any(|&p| !geometry::is_inside(p, rect)) {
+                vert_buf = geometry::clip_polygon(&vert_buf, rect);
+                if vert_buf.len() < 3 {
+                    return Ok(());
+                }
+            }
+        }
+        crate::drawing::rasterizer::fill_polygon(self, &vert_buf[..], style)
+    }
+
+    /// Blit the bitmap, note that the transformation only moves the bitmap but it doesn't
+    /// scale or rotate the bitmap
     fn blit_bitmap<'b>(
         &mut self,
         pos: BackendCoord,
         (sw, sh): (u32, u32),
         src: &'b [u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let (dw, dh) = self.get_size();
+        let pos = self.current_transform().map_or(pos, |t| t.apply(pos));
+
+        if let Some(recording) = self.recording.as_mut() {
+            for dy in 0..sh as i32 {
+                for dx in 0..sw as i32 {
+                    let idx = (dy as usize * sw as usize + dx as usize) * Self::PIXEL_SIZE;
+                    let (r, g, b, _) = P::decode_pixel(&src[idx..]);
+                    recording.push(((pos.0 + dx, pos.1 + dy), RGBAColor::new(r, g, b, 1.0)));
+                }
+            }
+            return Ok(());
+        }
+
+        let (dw, _) = self.get_size();
+        let ((cx0, cy0), (cx1, cy1)) = self.clip_rect();
 
         let (x0, y0) = pos;
         let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
 
-        let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
+        let (x0, y0, x1, y1) = (x0.max(cx0), y0.max(cy0), x1.min(cx1 + 1), y1.min(cy1 + 1));
 
-        if x0 == x1 || y0 == y1 {
+        if x0 >= x1 || y0 >= y1 {
             return Ok(());
         }
 
@@ -970,8 +1315,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start =
-            Self::PIXEL_SIZE * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = Self::PIXEL_SIZE * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
@@ -1353,3 +1697,153 @@ fn test_bitmap_blit() {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_transform_stack() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 10 * 10 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        assert!(back
+            .push_transform(BackendTransform::translate(4.0, 2.0))
+            .unwrap());
+        back.push_transform(BackendTransform::scale(2.0, 2.0))
+            .unwrap();
+        back.draw_rect((0, 0), (1, 1), &RED, true).unwrap();
+        back.pop_transform().unwrap();
+        back.draw_pixel((0, 0), &BLUE.to_rgba()).unwrap();
+        back.pop_transform().unwrap();
+        back.draw_pixel((0, 0), &GREEN.to_rgba()).unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 10 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    assert_eq!(pixel(0, 0), (0, 255, 0));
+    assert_eq!(pixel(4, 2), (0, 0, 255));
+    assert_eq!(pixel(5, 3), (255, 0, 0));
+    assert_eq!(pixel(6, 4), (255, 0, 0));
+    assert_eq!(pixel(7, 5), (255, 255, 255));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_stamp_repeated() {
+    use crate::prelude::*;
+    let mut stamped = vec![255; 20 * 20 * 3];
+    let mut drawn = vec![255; 20 * 20 * 3];
+    let positions = vec![(3, 3), (10, 12), (15, 5)];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut stamped, (20, 20));
+        back.stamp_repeated(positions.clone(), |b, (x, y)| {
+            b.draw_circle((x, y), 2, &RED, true)?;
+            b.draw_rect((x - 1, y - 1), (x + 1, y + 1), &BLUE, false)
+        })
+        .unwrap();
+    }
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut drawn, (20, 20));
+        for (x, y) in positions {
+            back.draw_circle((x, y), 2, &RED, true).unwrap();
+            back.draw_rect((x - 1, y - 1), (x + 1, y + 1), &BLUE, false)
+                .unwrap();
+        }
+    }
+
+    assert_eq!(stamped, drawn);
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_clip() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 10 * 10 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        assert!(back.push_clip((2, 2), (7, 7)).unwrap());
+        back.push_clip((0, 0), (5, 5)).unwrap();
+        back.draw_rect((0, 0), (9, 9), &RED, true).unwrap();
+        back.pop_clip().unwrap();
+        back.draw_line((0, 6), (9, 6), &BLUE).unwrap();
+        back.draw_circle((7, 7), 3, &GREEN, true).unwrap();
+        back.pop_clip().unwrap();
+        back.draw_pixel((0, 0), &BLACK.to_rgba()).unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 10 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    assert_eq!(pixel(0, 0), (0, 0, 0));
+    assert_eq!(pixel(1, 1), (255, 255, 255));
+    assert_eq!(pixel(2, 2), (255, 0, 0));
+    assert_eq!(pixel(3, 3), (255, 0, 0));
+    assert_eq!(pixel(6, 2), (255, 255, 255));
+    assert_eq!(pixel(1, 6), (255, 255, 255));
+    assert_eq!(pixel(2, 6), (0, 0, 255));
+    assert_eq!(pixel(8, 6), (255, 255, 255));
+    assert_eq!(pixel(7, 7), (0, 255, 0));
+    assert_eq!(pixel(8, 8), (255, 255, 255));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_huge_coordinates() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 10 * 10 * 3];
+    let far = 1_000_000_000;
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        back.fill_polygon(vec![(-far, -far), (far, -far), (0, far)], &RED)
+            .unwrap();
+        back.draw_line((-far, -far), (far, far), &BLUE).unwrap();
+        back.draw_line((9 - far, far), (9 + far, -far), &BLUE.stroke_width(3))
+            .unwrap();
+        back.draw_circle((far, 0), 10, &GREEN, true).unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 10 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    assert_eq!(pixel(4, 0), (255, 0, 0));
+    assert_eq!(pixel(0, 4), (255, 0, 0));
+    assert_eq!(pixel(5, 5), (0, 0, 255));
+    assert_eq!(pixel(9, 0), (0, 0, 255));
+    assert_eq!(pixel(1, 8), (0, 0, 255));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_opacity_group() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 10 * 10 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        assert!(back.push_opacity(0.5).unwrap());
+        back.draw_rect((0, 0), (5, 5), &RED, true).unwrap();
+        back.draw_rect((3, 3), (9, 9), &RED, true).unwrap();
+        back.pop_opacity().unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * 10 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    assert_eq!(pixel(1, 1), (255, 128, 128));
+    assert_eq!(pixel(4, 4), (255, 128, 128));
+    assert_eq!(pixel(8, 8), (255, 128, 128));
+    assert_eq!(pixel(9, 0), (255, 255, 255));
+}
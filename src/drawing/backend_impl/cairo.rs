@@ -1,7 +1,10 @@
-use cairo::{Context as CairoContext, FontSlant, FontWeight, Status as CairoStatus};
+use cairo::{Context as CairoContext, FontSlant, FontWeight, Matrix, Status as CairoStatus};
 
 #[allow(unused_imports)]
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    draw_vertical_text, BackendCoord, BackendStyle, BackendTransform, DrawingBackend,
+    DrawingErrorKind,
+};
 #[allow(unused_imports)]
 use crate::style::{Color, FontStyle, FontTransform, RGBAColor, TextStyle};
 
@@ -11,6 +14,8 @@ pub struct CairoBackend<'a> {
     width: u32,
     height: u32,
     init_flag: bool,
+    /// The opacity of the groups pushed onto the Cairo context
+    opacity: Vec<f64>,
 }
 
 #[derive(Debug)]
@@ -58,6 +63,7 @@ impl<'a> CairoBackend<'a> {
             width: w,
             height: h,
             init_flag: false,
+            opacity: vec![],
         };
         Ok(ret)
     }
@@ -88,6 +94,38 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
         Ok(())
     }
 
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let [a, b, c, d, e, f] = transform.matrix();
+        self.call_cairo(|ctx| {
+            ctx.save();
+            ctx.transform(Matrix::new(a, b, c, d, e, f));
+        })?;
+        Ok(true)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.call_cairo(|c| c.restore())
+    }
+
+    /// Redirect the drawing to an intermediate surface, which is painted with the opacity when
+    /// the group is popped
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.call_cairo(|c| c.push_group())?;
+        self.opacity.push(opacity.max(0.0).min(1.0));
+        Ok(true)
+    }
+
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let opacity = self.opacity.pop().unwrap_or(1.0);
+        self.call_cairo(|c| {
+            c.pop_group_to_source();
+            c.paint_with_alpha(opacity);
+        })
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -233,12 +271,16 @@ impl<'a> DrawingBackend for CairoBackend<'a> {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let FontTransform::Vertical = style.font.get_transform() {
+            return draw_vertical_text(self, text, style, pos);
+        }
+
         let font = &style.font;
         let color = &style.color;
         let (mut x, mut y) = (pos.0, pos.1);
 
         let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
+            FontTransform::None | FontTransform::Vertical => 0.0,
             FontTransform::Rotate90 => 90.0,
             FontTransform::Rotate180 => 180.0,
             FontTransform::Rotate270 => 270.0,
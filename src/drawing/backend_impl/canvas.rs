@@ -2,7 +2,10 @@ use js_sys::JSON;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
 
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    draw_vertical_text, BackendCoord, BackendStyle, BackendTransform, DrawingBackend,
+    DrawingErrorKind,
+};
 use crate::style::{Color, FontTransform, RGBAColor, TextStyle};
 
 /// The backend that is drawing on the HTML canvas
@@ -85,6 +88,21 @@ impl DrawingBackend for CanvasBackend {
         Ok(())
     }
 
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<CanvasError>> {
+        let [a, b, c, d, e, f] = transform.matrix();
+        self.context.save();
+        self.context.transform(a, b, c, d, e, f)?;
+        Ok(true)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
+        self.context.restore();
+        Ok(())
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -236,6 +254,10 @@ impl DrawingBackend for CanvasBackend {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let FontTransform::Vertical = style.font.get_transform() {
+            return draw_vertical_text(self, text, style, pos);
+        }
+
         let font = &style.font;
         let color = &style.color;
         if color.alpha() == 0.0 {
@@ -245,7 +267,7 @@ impl DrawingBackend for CanvasBackend {
         let (mut x, mut y) = (pos.0, pos.1);
 
         let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
+            FontTransform::None | FontTransform::Vertical => 0.0,
             FontTransform::Rotate90 => 90.0,
             FontTransform::Rotate180 => 180.0,
             FontTransform::Rotate270 => 270.0,
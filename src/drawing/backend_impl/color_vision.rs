@@ -0,0 +1,298 @@
+/*!
+The color vision deficiency simulation backend, which transforms every color drawn onto the
+underlying backend into the color perceived by a color-blind reader.
+
+This is useful for checking that the palette of a chart is still distinguishable: render the
+chart with each `ColorVision` and compare the series by eye.
+*/
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, BackendTransform, DrawingBackend, DrawingErrorKind,
+};
+use crate::style::{Color, FontDesc, RGBAColor, RGBColor, ShapeStyle, TextStyle};
+
+/// The kind of the color vision deficiency to simulate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorVision {
+    /// The missing long-wavelength (red) cones
+    Protanopia,
+    /// The missing medium-wavelength (green) cones
+    Deuteranopia,
+    /// The missing short-wavelength (blue) cones
+    Tritanopia,
+}
+
+/// Convert the sRGB component to the linear intensity
+fn to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert the linear intensity back to the sRGB component
+fn from_linear(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+impl ColorVision {
+    /// The simulation matrix in the linear RGB space, from Machado, Oliveira and Fernandes,
+    /// "A Physiologically-based Model for Simulation of Color Vision Deficiency" (2009)
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            ColorVision::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorVision::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorVision::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// Get the color perceived with the color vision deficiency, the alpha channel is kept
+    pub fn simulate<C: Color>(self, color: &C) -> RGBAColor {
+        let (r, g, b) = color.rgb();
+        let rgb = [to_linear(r), to_linear(g), to_linear(b)];
+        let m = self.matrix();
+        let channel =
+            |row: [f64; 3]| from_linear(row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]);
+        RGBColor(channel(m[0]), channel(m[1]), channel(m[2])).mix(color.alpha())
+    }
+}
+
+/// The backend wrapper that simulates the color vision deficiency by transforming the colors of
+/// all the drawing primitives, including the text and the blitted bitmaps
+pub struct ColorVisionBackend<DB: DrawingBackend> {
+    inner: DB,
+    vision: ColorVision,
+}
+
+impl<DB: DrawingBackend> ColorVisionBackend<DB> {
+    /// Create a new color vision deficiency simulation backend
+    ///
+    /// - `inner`: The backend that actually draws the simulated colors
+    /// - `vision`: The color vision deficiency to simulate
+    /// - **returns**: The newly created backend
+    pub fn new(inner: DB, vision: ColorVision) -> Self {
+        Self { inner, vision }
+    }
+
+    /// Get the simulated color vision deficiency
+    pub fn vision(&self) -> ColorVision {
+        self.vision
+    }
+
+    /// Unwrap the simulation and get the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn simulate_style<S: BackendStyle>(&self, style: &S) -> ShapeStyle {
+        ShapeStyle {
+            color: self.vision.simulate(&style.as_color()),
+            filled: false,
+            stroke_width: style.stroke_width(),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ColorVisionBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn get_dpi(&self) -> f64 {
+        self.inner.get_dpi()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_transform(transform)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_transform()
+    }
+
+    fn push_clip(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_clip(upper_left, bottom_right)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_clip()
+    }
+
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_opacity(opacity)
+    }
+
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_opacity()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.vision.simulate(color);
+        self.inner.draw_pixel(point, &color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.simulate_style(style);
+        self.inner.draw_line(from, to, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.simulate_style(style);
+        self.inner.draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.simulate_style(style);
+        self.inner.draw_path(path, &style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.simulate_style(style);
+        self.inner.draw_circle(center, radius, &style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.simulate_style(style);
+        self.inner.fill_polygon(vert, &style)
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut style = style.clone();
+        style.color = self.vision.simulate(&style.color);
+        self.inner.draw_text(text, &style, pos)
+    }
+
+    fn estimate_text_size<'a>(
+        &self,
+        text: &str,
+        font: &FontDesc<'a>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut simulated = src.to_vec();
+        for pixel in simulated.chunks_mut(3) {
+            if let [r, g, b] = pixel {
+                let (sr, sg, sb) = self.vision.simulate(&RGBColor(*r, *g, *b)).rgb();
+                *r = sr;
+                *g = sg;
+                *b = sb;
+            }
+        }
+        self.inner.blit_bitmap(pos, size, &simulated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_simulate() {
+        for vision in &[
+            ColorVision::Protanopia,
+            ColorVision::Deuteranopia,
+            ColorVision::Tritanopia,
+        ] {
+            assert_eq!(vision.simulate(&WHITE).rgb(), (255, 255, 255));
+            assert_eq!(vision.simulate(&BLACK).rgb(), (0, 0, 0));
+        }
+        // Both red and green are perceived as the shades of yellow without the red cones
+        let red = ColorVision::Protanopia.simulate(&RED).rgb();
+        let green = ColorVision::Protanopia.simulate(&GREEN).rgb();
+        assert!(red.0 >= red.1 && red.2 == 0);
+        assert!(green.0 >= green.1 && green.2 == 0);
+        assert_eq!(ColorVision::Tritanopia.simulate(&RED.mix(0.5)).alpha(), 0.5);
+    }
+
+    #[test]
+    fn test_color_vision_backend() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_circle(|color, _, _, _, _| {
+            assert_eq!(color.rgb(), ColorVision::Deuteranopia.simulate(&RED).rgb());
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+        let root = ColorVisionBackend::new(backend, ColorVision::Deuteranopia).into_drawing_area();
+        root.draw(&Circle::new((50, 50), 10, RED.filled()))
+            .expect("Draw circle");
+    }
+}
@@ -0,0 +1,405 @@
+/*!
+The contrast backend, which re-maps the styles of all the drawing primitives to a monochrome or
+a high-contrast scheme for the print-friendly and the accessible variants of the same figure.
+
+The styles are re-mapped at draw time, thus the chart code is unchanged: only the backend is
+wrapped with `ContrastBackend`.
+*/
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, BackendTransform, DrawingBackend, DrawingErrorKind,
+};
+use crate::style::{Color, FontDesc, RGBAColor, RGBColor, ShapeStyle, TextStyle};
+
+/// The factor applied to the width of the strokes
+const STROKE_SCALE: u32 = 2;
+
+/// The colors at least as light as this are kept as the white background in the high-contrast
+/// mode, and the other colors are drawn black
+const LIGHT_THRESHOLD: f64 = 0.9;
+
+/// The colors at most as light as this are filled solid in the high-contrast mode, and the
+/// other colors are filled with the hatch patterns
+const DARK_THRESHOLD: f64 = 0.1;
+
+/// The hatch patterns of the hue sectors of 60 degrees, starting from red. Each pattern is the
+/// spacing of the lines in pixels, if the horizontal lines are drawn and if the vertical lines
+/// are drawn.
+const HATCH_PATTERNS: [(i32, bool, bool); 6] = [
+    (4, true, false),
+    (8, false, true),
+    (6, true, true),
+    (8, true, false),
+    (4, false, true),
+    (10, true, true),
+];
+
+/// The scheme the styles are re-mapped to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContrastMode {
+    /// Every color is replaced with the gray of the same luminance and the strokes are thicker
+    Monochrome,
+    /// Every color is replaced with black, except the light colors which are replaced with
+    /// white. The filled shapes are distinguished by the hatch patterns derived from the hue,
+    /// and the strokes are thicker.
+    HighContrast,
+}
+
+/// Get the relative luminance of the color, from 0 for black to 1 for white
+fn luminance<C: Color>(color: &C) -> f64 {
+    let (r, g, b) = color.rgb();
+    (0.2126 * f64::from(r) + 0.7152 * f64::from(g) + 0.0722 * f64::from(b)) / 255.0
+}
+
+/// Get the hatch pattern of the color, see `HATCH_PATTERNS`
+fn hatch_pattern<C: Color>(color: &C) -> (i32, bool, bool) {
+    let (r, g, b) = color.rgb();
+    let (r, g, b) = (i32::from(r), i32::from(g), i32::from(b));
+    let max = r.max(g).max(b);
+    let delta = f64::from(max - r.min(g).min(b));
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        (f64::from(g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        f64::from(b - r) / delta + 2.0
+    } else {
+        f64::from(r - g) / delta + 4.0
+    };
+    HATCH_PATTERNS[hue.floor() as usize % HATCH_PATTERNS.len()]
+}
+
+/// Get the segments of the parallel lines with the given spacing clipped to the polygon, the
+/// lines are horizontal unless `vertical` is set. The lines are aligned to the image rather
+/// than the polygon, so the patterns of the adjacent shapes are continuous.
+fn hatch_segments(
+    vert: &[BackendCoord],
+    spacing: i32,
+    vertical: bool,
+) -> Vec<(BackendCoord, BackendCoord)> {
+    let swap = |(x, y): BackendCoord| if vertical { (y, x) } else { (x, y) };
+    let points: Vec<_> = vert.iter().map(|p| swap(*p)).collect();
+    let (min, max) = match (
+        points.iter().map(|p| p.1).min(),
+        points.iter().map(|p| p.1).max(),
+    ) {
+        (Some(min), Some(max)) if points.len() >= 3 => (min, max),
+        _ => return vec![],
+    };
+
+    let mut segments = vec![];
+    let mut y = min - min.rem_euclid(spacing) + spacing;
+    while y < max {
+        let mut xs = vec![];
+        for (idx, a) in points.iter().enumerate() {
+            let b = points[(idx + 1) % points.len()];
+            if (a.1 <= y) != (b.1 <= y) {
+                let t = f64::from(y - a.1) / f64::from(b.1 - a.1);
+                xs.push(f64::from(a.0) + t * f64::from(b.0 - a.0));
+            }
+        }
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for pair in xs.chunks(2) {
+            if let [x0, x1] = pair {
+                segments.push((swap((x0.round() as i32, y)), swap((x1.round() as i32, y))));
+            }
+        }
+        y += spacing;
+    }
+    segments
+}
+
+/// The backend wrapper that re-maps the styles of all the drawing primitives, including the
+/// text and the blitted bitmaps, to the monochrome or the high-contrast scheme
+pub struct ContrastBackend<DB: DrawingBackend> {
+    inner: DB,
+    mode: ContrastMode,
+}
+
+impl<DB: DrawingBackend> ContrastBackend<DB> {
+    /// Create a new contrast backend
+    ///
+    /// - `inner`: The backend that actually draws the re-mapped styles
+    /// - `mode`: The scheme the styles are re-mapped to
+    /// - **returns**: The newly created backend
+    pub fn new(inner: DB, mode: ContrastMode) -> Self {
+        Self { inner, mode }
+    }
+
+    /// Get the scheme the styles are re-mapped to
+    pub fn mode(&self) -> ContrastMode {
+        self.mode
+    }
+
+    /// Unwrap the contrast backend and get the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    /// Re-map the color, the alpha channel is kept
+    fn map_color<C: Color>(&self, color: &C) -> RGBAColor {
+        let l = luminance(color);
+        let value = match self.mode {
+            ContrastMode::Monochrome => (l * 255.0).round() as u8,
+            ContrastMode::HighContrast if l >= LIGHT_THRESHOLD => 255,
+            ContrastMode::HighContrast => 0,
+        };
+        RGBColor(value, value, value).mix(color.alpha())
+    }
+
+    /// Re-map the style of a stroke
+    fn map_stroke<S: BackendStyle>(&self, style: &S) -> ShapeStyle {
+        ShapeStyle {
+            color: self.map_color(&style.as_color()),
+            filled: false,
+            stroke_width: style.stroke_width() * STROKE_SCALE,
+        }
+    }
+
+    /// Check if the filled shape of the color is drawn with the hatch pattern
+    fn is_hatched<C: Color>(&self, color: &C) -> bool {
+        let l = luminance(color);
+        self.mode == ContrastMode::HighContrast && l > DARK_THRESHOLD && l < LIGHT_THRESHOLD
+    }
+
+    /// Fill the polygon with the hatch pattern of the color. The opaque shapes are cleared
+    /// first, thus they hide the shapes below them like the solid fill does.
+    fn fill_hatched(
+        &mut self,
+        vert: Vec<BackendCoord>,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let line = self.map_color(color);
+        if color.alpha() >= 1.0 {
+            self.inner
+                .fill_polygon(vert.iter().cloned(), &RGBColor(255, 255, 255))?;
+        }
+
+        let (spacing, horizontal, vertical) = hatch_pattern(color);
+        let mut segments = vec![];
+        if horizontal {
+            segments.extend(hatch_segments(&vert, spacing, false));
+        }
+        if vertical {
+            segments.extend(hatch_segments(&vert, spacing, true));
+        }
+        for (from, to) in segments {
+            self.inner.draw_line(from, to, &line)?;
+        }
+
+        let outline = vert.first().cloned();
+        self.inner.draw_path(vert.into_iter().chain(outline), &line)
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ContrastBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn get_dpi(&self) -> f64 {
+        self.inner.get_dpi()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_transform(transform)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_transform()
+    }
+
+    fn push_clip(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_clip(upper_left, bottom_right)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_clip()
+    }
+
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_opacity(opacity)
+    }
+
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_opacity()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.map_color(color);
+        self.inner.draw_pixel(point, &color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.map_stroke(style);
+        self.inner.draw_line(from, to, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if fill && self.is_hatched(&color) {
+            let (x0, y0) = upper_left;
+            let (x1, y1) = bottom_right;
+            return self.fill_hatched(vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)], &color);
+        }
+        let style = self.map_stroke(style);
+        self.inner.draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.map_stroke(style);
+        self.inner.draw_path(path, &style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let style = self.map_stroke(style);
+        self.inner.draw_circle(center, radius, &style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if self.is_hatched(&color) {
+            return self.fill_hatched(vert.into_iter().collect(), &color);
+        }
+        let color = self.map_color(&color);
+        self.inner.fill_polygon(vert, &color)
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut style = style.clone();
+        style.color = self.map_color(&style.color);
+        self.inner.draw_text(text, &style, pos)
+    }
+
+    fn estimate_text_size<'a>(
+        &self,
+        text: &str,
+        font: &FontDesc<'a>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut mapped = src.to_vec();
+        for pixel in mapped.chunks_mut(3) {
+            if let [r, g, b] = pixel {
+                let (value, _, _) = self.map_color(&RGBColor(*r, *g, *b)).rgb();
+                *r = value;
+                *g = value;
+                *b = value;
+            }
+        }
+        self.inner.blit_bitmap(pos, size, &mapped)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hatch_segments() {
+        let square = [(0, 0), (10, 0), (10, 10), (0, 10)];
+        assert_eq!(
+            hatch_segments(&square, 4, false),
+            vec![((0, 4), (10, 4)), ((0, 8), (10, 8))]
+        );
+        assert_eq!(
+            hatch_segments(&square, 4, true),
+            vec![((4, 0), (4, 10)), ((8, 0), (8, 10))]
+        );
+        assert_ne!(hatch_pattern(&RED), hatch_pattern(&BLUE));
+    }
+
+    #[test]
+    fn test_monochrome() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_path(|color, width, _| {
+            let (r, g, b) = color.rgb();
+            assert!(r == g && g == b);
+            assert_eq!(width, 2);
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+        let root = ContrastBackend::new(backend, ContrastMode::Monochrome).into_drawing_area();
+        root.draw(&PathElement::new(vec![(0, 0), (50, 50)], &RED))
+            .expect("Draw path");
+    }
+
+    #[test]
+    fn test_high_contrast() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_line(|color, _, _, _| {
+            assert_eq!(color.rgb(), (0, 0, 0));
+        });
+        backend.drop_check(|b| {
+            // The background of the hatch pattern and the outline
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+            assert!(b.num_draw_line_call > 0);
+            assert_eq!(b.num_draw_rect_call, 1);
+        });
+        let root = ContrastBackend::new(backend, ContrastMode::HighContrast).into_drawing_area();
+        root.draw(&Rectangle::new([(10, 10), (50, 50)], RED.filled()))
+            .expect("Draw hatched rectangle");
+        root.draw(&Rectangle::new([(60, 60), (90, 90)], BLACK.filled()))
+            .expect("Draw solid rectangle");
+    }
+}
@@ -0,0 +1,508 @@
+/*!
+The Encapsulated PostScript drawing backend
+*/
+use super::OutputHook;
+use crate::drawing::backend::{
+    draw_vertical_text, BackendCoord, BackendStyle, BackendTransform, DrawingBackend,
+    DrawingErrorKind,
+};
+use crate::style::{Color, FontStyle, FontTransform, RGBAColor, TextAlignment, TextStyle};
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::io::Error;
+use std::path::Path;
+
+/// The procedures shared by the drawing operations, `reencode` defines a copy of a standard
+/// font with the Latin-1 encoding, `rshow` and `cshow` show the right and center aligned text
+const EPS_PROLOG: &str = "/reencode { findfont dup length dict begin \
+{ 1 index /FID ne { def } { pop pop } ifelse } forall \
+/Encoding ISOLatin1Encoding def currentdict end definefont pop } bind def
+/rshow { dup stringwidth pop neg 0 rmoveto show } bind def
+/cshow { dup stringwidth pop -2 div 0 rmoveto show } bind def
+";
+
+fn make_eps_color<C: Color>(color: &C) -> String {
+    let (r, g, b) = color.rgb();
+    format!(
+        "{:.3} {:.3} {:.3} setrgbcolor",
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0
+    )
+}
+
+/// Map the font to one of the standard PostScript fonts, so that the text stays as vector text
+/// which every PostScript interpreter is able to render
+fn make_eps_font(family: &str, style: FontStyle) -> &'static str {
+    match (family, style) {
+        ("serif", FontStyle::Normal) => "Times-Roman",
+        ("serif", FontStyle::Bold) => "Times-Bold",
+        ("serif", _) => "Times-Italic",
+        ("monospace", FontStyle::Normal) => "Courier",
+        ("monospace", FontStyle::Bold) => "Courier-Bold",
+        ("monospace", _) => "Courier-Oblique",
+        (_, FontStyle::Normal) => "Helvetica",
+        (_, FontStyle::Bold) => "Helvetica-Bold",
+        (_, _) => "Helvetica-Oblique",
+    }
+}
+
+/// Make a PostScript string literal, the characters out of Latin-1 are replaced with `?`
+fn make_eps_string(text: &str) -> String {
+    let mut result = String::from("(");
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            ' '..='~' => result.push(c),
+            c if (c as u32) < 256 => {
+                let _ = write!(result, "\\{:03o}", c as u32);
+            }
+            _ => result.push('?'),
+        }
+    }
+    result.push(')');
+    result
+}
+
+enum Target<'a> {
+    File(&'a Path),
+    Buffer(&'a mut Vec<u8>),
+    Hooked(&'a Path, OutputHook<'a>),
+}
+
+/// The Encapsulated PostScript drawing backend. The shapes and the text are written as vector
+/// graphics with the standard PostScript fonts, and one pixel is mapped to one point.
+///
+/// Note: PostScript has no transparency, so the semi-transparent colors are drawn opaque and
+/// the fully transparent ones are skipped.
+pub struct EpsBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    body: String,
+    fonts: HashSet<&'static str>,
+    transforms: usize,
+    saved: bool,
+}
+
+impl<'a> EpsBackend<'a> {
+    /// Create a new EPS drawing backend
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::File(path.as_ref()), size)
+    }
+
+    /// Create a new EPS drawing backend and store the document into a u8 buffer
+    pub fn with_buffer(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    /// Create a new EPS drawing backend which writes the document through the output hook
+    /// rather than into the file, see `OutputHook`
+    /// - `path`: The path passed to the hook
+    /// - `size`: The size of the image
+    /// - `hook`: The hook opening the output stream
+    pub fn with_output_hook<T, H>(path: &'a T, size: (u32, u32), hook: H) -> Self
+    where
+        T: AsRef<Path> + ?Sized,
+        H: FnMut(&Path) -> std::io::Result<Box<dyn std::io::Write + 'a>> + Send + 'a,
+    {
+        Self::with_target(Target::Hooked(path.as_ref(), Box::new(hook)), size)
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        Self {
+            target,
+            size,
+            body: String::new(),
+            fonts: HashSet::new(),
+            transforms: 0,
+            saved: false,
+        }
+    }
+
+    fn add_command(&mut self, command: &str) {
+        self.body.push_str(command);
+        self.body.push('\n');
+    }
+
+    /// Select the Latin-1 encoded copy of the standard font, which is defined on the first use
+    fn set_font(&mut self, name: &'static str, size: f64) {
+        if self.fonts.insert(name) {
+            let command = format!("/{}-Latin1 /{} reencode", name, name);
+            self.add_command(&command);
+        }
+        let command = format!("/{}-Latin1 findfont {} scalefont setfont", name, size);
+        self.add_command(&command);
+    }
+
+    fn add_path<I: IntoIterator<Item = BackendCoord>>(&mut self, path: I) -> bool {
+        let mut command = String::from("newpath");
+        let mut empty = true;
+        for (x, y) in path {
+            let op = if empty { "moveto" } else { "lineto" };
+            let _ = write!(command, " {} {} {}", x, y, op);
+            empty = false;
+        }
+        if !empty {
+            self.add_command(&command);
+        }
+        !empty
+    }
+
+    fn make_document(&self) -> String {
+        let (w, h) = self.size;
+        let mut document = String::new();
+        document.push_str("%!PS-Adobe-3.0 EPSF-3.0\n");
+        document.push_str("%%Creator: Plotters\n");
+        let _ = writeln!(document, "%%BoundingBox: 0 0 {} {}", w, h);
+        let _ = writeln!(document, "%%HiResBoundingBox: 0 0 {} {}", w, h);
+        document.push_str("%%LanguageLevel: 2\n");
+        document.push_str("%%EndComments\n");
+        document.push_str("%%BeginProlog\n");
+        document.push_str(EPS_PROLOG);
+        document.push_str("%%EndProlog\n");
+        // The backend coordinate has the origin at the upper left corner
+        let _ = writeln!(document, "gsave\n0 {} translate 1 -1 scale", h);
+        document.push_str("1 setlinejoin 1 setlinecap\n");
+        document.push_str(&self.body);
+        for _ in 0..self.transforms {
+            document.push_str("grestore\n");
+        }
+        document.push_str("grestore\nshowpage\n%%EOF\n");
+        document
+    }
+}
+
+impl<'a> DrawingBackend for EpsBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn get_dpi(&self) -> f64 {
+        // The unit of the PostScript coordinate is the point
+        72.0
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.saved {
+            let document = self.make_document();
+            match self.target {
+                Target::File(path) => {
+                    std::fs::write(path, document).map_err(DrawingErrorKind::DrawingError)?
+                }
+                Target::Buffer(ref mut buf) => buf.extend_from_slice(document.as_bytes()),
+                Target::Hooked(path, ref mut hook) => {
+                    let mut writer = hook(path).map_err(DrawingErrorKind::DrawingError)?;
+                    writer
+                        .write_all(document.as_bytes())
+                        .and_then(|_| writer.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+            }
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Error>> {
+        let [a, b, c, d, e, f] = transform.matrix();
+        let command = format!("gsave [{} {} {} {} {} {}] concat", a, b, c, d, e, f);
+        self.add_command(&command);
+        self.transforms += 1;
+        Ok(true)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if self.transforms > 0 {
+            self.add_command("grestore");
+            self.transforms -= 1;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let command = format!(
+            "{} {} {} 1 1 rectfill",
+            make_eps_color(color),
+            point.0,
+            point.1
+        );
+        self.add_command(&command);
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let command = format!(
+            "{} {} setlinewidth newpath {} {} moveto {} {} lineto stroke",
+            make_eps_color(&style.as_color()),
+            style.stroke_width(),
+            from.0,
+            from.1,
+            to.0,
+            to.1
+        );
+        self.add_command(&command);
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let command = format!(
+            "{} {} {} {} {} {}",
+            make_eps_color(&style.as_color()),
+            upper_left.0,
+            upper_left.1,
+            bottom_right.0 - upper_left.0,
+            bottom_right.1 - upper_left.1,
+            if fill {
+                "rectfill".to_string()
+            } else {
+                format!("{} setlinewidth rectstroke", style.stroke_width())
+            }
+        );
+        self.add_command(&command);
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        if self.add_path(path) {
+            let command = format!(
+                "{} {} setlinewidth stroke",
+                make_eps_color(&style.as_color()),
+                style.stroke_width()
+            );
+            self.add_command(&command);
+        }
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        if self.add_path(path) {
+            let command = format!("closepath {} fill", make_eps_color(&style.as_color()));
+            self.add_command(&command);
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let command = format!(
+            "{} newpath {} {} {} 0 360 arc closepath {}",
+            make_eps_color(&style.as_color()),
+            center.0,
+            center.1,
+            radius,
+            if fill {
+                "fill".to_string()
+            } else {
+                format!("{} setlinewidth stroke", style.stroke_width())
+            }
+        );
+        self.add_command(&command);
+        Ok(())
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let FontTransform::Vertical = style.font.get_transform() {
+            return draw_vertical_text(self, text, style, pos);
+        }
+
+        let font = &style.font;
+        let color = &style.color;
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+
+        let trans = font.get_transform();
+        let offset = trans.offset(layout);
+        let x0 = pos.0 + offset.0;
+        let y0 = pos.1 + offset.1;
+
+        let max_x = (layout.1).0;
+        let (dx, show) = match style.alignment {
+            TextAlignment::Left => (0, "show"),
+            TextAlignment::Right => (max_x, "rshow"),
+            TextAlignment::Center => (max_x / 2, "cshow"),
+        };
+
+        // The text is drawn in a local coordinate with the Y axis pointing up, thus the
+        // clockwise rotations have negative angles
+        let rotate = match trans {
+            FontTransform::Rotate90 => " -90 rotate",
+            FontTransform::Rotate180 => " 180 rotate",
+            FontTransform::Rotate270 => " 90 rotate",
+            _ => "",
+        };
+
+        self.set_font(
+            make_eps_font(font.get_name(), font.get_style()),
+            font.get_size(),
+        );
+        let command = format!(
+            "gsave {} {} {} translate 1 -1 scale{} {} {} moveto {} {} grestore",
+            make_eps_color(color),
+            x0,
+            y0,
+            rotate,
+            dx,
+            (layout.0).1,
+            make_eps_string(text),
+            show
+        );
+        self.add_command(&command);
+        Ok(())
+    }
+}
+
+impl Drop for EpsBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            self.present().expect("Unable to save the EPS image");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::fs;
+    use std::path::Path;
+
+    static DST_DIR: &str = "target/test/eps";
+
+    fn save_file(name: &str, content: &str) {
+        /*
+          Please use the EPS file to manually verify the results.
+        */
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_name = format!("{}.eps", name);
+        let file_path = Path::new(DST_DIR).join(file_name);
+        println!("{:?} created", file_path);
+        fs::write(file_path, content).unwrap();
+    }
+
+    #[test]
+    fn test_draw_mesh() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let root = EpsBackend::with_buffer(&mut buffer, (500, 400)).into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("This is a (test)", ("sans-serif", 20))
+                .x_label_area_size(40)
+                .y_label_area_size(40)
+                .build_ranged(0..100, 0..100)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series(LineSeries::new((0..100).map(|x| (x, x)), &RED))
+                .unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_draw_mesh", &content);
+
+        assert!(content.starts_with("%!PS-Adobe-3.0 EPSF-3.0\n"));
+        assert!(content.contains("%%BoundingBox: 0 0 500 400\n"));
+        assert!(content.contains("(This is a \\(test\\))"));
+        assert!(content.contains("1.000 0.000 0.000 setrgbcolor"));
+        assert_eq!(
+            content
+                .matches("/Helvetica-Latin1 /Helvetica reencode")
+                .count(),
+            1
+        );
+        assert!(content.ends_with("showpage\n%%EOF\n"));
+    }
+
+    #[test]
+    fn test_transform_and_text() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut root = EpsBackend::with_buffer(&mut buffer, (100, 100));
+            root.push_transform(BackendTransform::translate(10.0, 20.0))
+                .unwrap();
+            root.draw_circle((0, 0), 5, &BLUE, true).unwrap();
+            let style = TextStyle::from(("serif", 10).into_font()).alignment(TextAlignment::Center);
+            root.draw_text("90°", &style, (50, 50)).unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_transform_and_text", &content);
+
+        assert!(content.contains("gsave [1 0 0 1 10 20] concat\n"));
+        assert!(content.contains("0 0 5 0 360 arc closepath fill"));
+        assert!(content.contains("(90\\260) cshow"));
+        assert!(content.contains("/Times-Roman-Latin1 findfont 10 scalefont setfont"));
+        // The unclosed transformation is closed when the document is saved
+        assert_eq!(
+            content.matches("gsave").count(),
+            content.matches("grestore").count()
+        );
+    }
+}
@@ -0,0 +1,179 @@
+/*!
+The extent backend, which doesn't draw anything but measures the bounding box of everything
+drawn on it. It's used to find the real extent of an element, including the radius of a circle,
+the width of a stroke and the size of a text, which isn't known from the points of the element.
+*/
+use super::DummyBackendError;
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::style::{RGBAColor, TextStyle};
+
+/// The backend measuring the bounding box of the drawing, see the module level documentation
+pub struct ExtentBackend {
+    size: (u32, u32),
+    extent: Option<(BackendCoord, BackendCoord)>,
+}
+
+impl ExtentBackend {
+    /// Create a new extent backend
+    /// - `size`: The size reported by the backend, which the relative sizes are resolved against
+    pub fn new(size: (u32, u32)) -> Self {
+        Self { size, extent: None }
+    }
+
+    /// Get the bounding box of the drawing, as the upper-left and the bottom-right corner. It's
+    /// `None` if nothing has been drawn.
+    pub fn extent(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.extent
+    }
+
+    fn include(&mut self, (x0, y0): BackendCoord, (x1, y1): BackendCoord) {
+        let (l, r, t, b) = (x0.min(x1), x0.max(x1), y0.min(y1), y0.max(y1));
+        self.extent = Some(match self.extent {
+            None => ((l, t), (r, b)),
+            Some(((el, et), (er, eb))) => ((el.min(l), et.min(t)), (er.max(r), eb.max(b))),
+        });
+    }
+
+    fn include_points<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        points: I,
+        style: &S,
+    ) {
+        let half_width = style.stroke_width() as i32 / 2;
+        for (x, y) in points {
+            self.include(
+                (x - half_width, y - half_width),
+                (x + half_width, y + half_width),
+            );
+        }
+    }
+}
+
+impl DrawingBackend for ExtentBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        _: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.include(point, point);
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.include_points(vec![from, to], style);
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        _: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.include_points(vec![upper_left, bottom_right], style);
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.include_points(path, style);
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        (x, y): BackendCoord,
+        radius: u32,
+        style: &S,
+        _: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let radius = radius as i32;
+        self.include_points(
+            vec![(x - radius, y - radius), (x + radius, y + radius)],
+            style,
+        );
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        _: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        for point in vert {
+            self.include(point, point);
+        }
+        Ok(())
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        (x, y): BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        let (w, h) = self.estimate_text_size(text, &style.font)?;
+        self.include((x, y), (x + w as i32, y + h as i32));
+        Ok(())
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        (x, y): BackendCoord,
+        (w, h): (u32, u32),
+        _: &[u8],
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.include((x, y), (x + w as i32, y + h as i32));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::{Circle, Drawable, Rectangle};
+    use crate::style::{RED, WHITE};
+
+    #[test]
+    fn test_extent_backend() {
+        let mut backend = ExtentBackend::new((100, 100));
+        assert_eq!(backend.extent(), None);
+
+        Circle::new((10, 10), 5, &RED)
+            .draw(std::iter::once((10, 10)), &mut backend, (100, 100))
+            .expect("Measure circle");
+        assert_eq!(backend.extent(), Some(((5, 5), (15, 15))));
+
+        Rectangle::new([(0, 0), (0, 0)], WHITE.stroke_width(4))
+            .draw(
+                vec![(20, 30), (25, 40)].into_iter(),
+                &mut backend,
+                (100, 100),
+            )
+            .expect("Measure rectangle");
+        assert_eq!(backend.extent(), Some(((5, 5), (27, 42))));
+    }
+}
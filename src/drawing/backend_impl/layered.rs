@@ -0,0 +1,251 @@
+/*!
+The layer filtering backend, which only passes a subset of the drawing primitives to the
+underlying backend.
+
+This is useful for image regression tests: render the same chart twice, once with
+`RenderLayer::Geometry` and once with `RenderLayer::Text`, to two different outputs. The
+geometry layer can then be compared strictly, while the text layer, which depends on the
+fonts available on the platform, can be compared with a tolerance.
+*/
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, BackendTransform, DrawingBackend, DrawingErrorKind,
+};
+use crate::style::{FontDesc, RGBAColor, TextStyle};
+
+/// Describes which layer of the image should be rendered
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderLayer {
+    /// Everything except the text
+    Geometry,
+    /// Only the text
+    Text,
+}
+
+/// The backend wrapper that only renders the selected layer onto the underlying backend.
+/// The text size estimation is always forwarded, thus the layout of both layers is identical
+/// to the layout of the full image.
+pub struct LayeredBackend<DB: DrawingBackend> {
+    inner: DB,
+    layer: RenderLayer,
+}
+
+impl<DB: DrawingBackend> LayeredBackend<DB> {
+    /// Create a new layer filtering backend
+    ///
+    /// - `inner`: The backend that actually draws the selected layer
+    /// - `layer`: The layer to render
+    /// - **returns**: The newly created backend
+    pub fn new(inner: DB, layer: RenderLayer) -> Self {
+        Self { inner, layer }
+    }
+
+    /// Get the layer this backend renders
+    pub fn layer(&self) -> RenderLayer {
+        self.layer
+    }
+
+    /// Unwrap the layer filter and get the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn draws_geometry(&self) -> bool {
+        self.layer == RenderLayer::Geometry
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for LayeredBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn get_dpi(&self) -> f64 {
+        self.inner.get_dpi()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_transform(transform)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_transform()
+    }
+
+    fn push_clip(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_clip(upper_left, bottom_right)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_clip()
+    }
+
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.push_opacity(opacity)
+    }
+
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.pop_opacity()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn estimate_text_size<'a>(
+        &self,
+        text: &str,
+        font: &FontDesc<'a>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, font)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.draws_geometry() {
+            return Ok(());
+        }
+        self.inner.blit_bitmap(pos, size, src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw_chart(layer: RenderLayer, check: impl FnMut(&MockedBackend) + 'static) {
+        let mut backend = MockedBackend::new(200, 200);
+        backend.drop_check(check);
+        let root = LayeredBackend::new(backend, layer).into_drawing_area();
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption("Layered", ("sans-serif", 10))
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        chart.configure_mesh().draw().expect("Draw mesh");
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, &RED)))
+            .expect("Draw series");
+    }
+
+    #[test]
+    fn test_geometry_layer() {
+        draw_chart(RenderLayer::Geometry, |b| {
+            assert_eq!(b.num_draw_text_call, 0);
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+    }
+
+    #[test]
+    fn test_text_layer() {
+        draw_chart(RenderLayer::Text, |b| {
+            assert!(b.num_draw_text_call > 0);
+            assert_eq!(b.num_draw_circle_call, 0);
+            assert_eq!(b.num_draw_line_call, 0);
+            assert_eq!(b.num_draw_path_call, 0);
+        });
+    }
+}
@@ -3,6 +3,11 @@ mod svg;
 #[cfg(feature = "svg")]
 pub use self::svg::{svg_types, SVGBackend};
 
+#[cfg(feature = "eps")]
+mod eps;
+#[cfg(feature = "eps")]
+pub use eps::EpsBackend;
+
 #[cfg(feature = "bitmap")]
 mod bitmap;
 #[cfg(feature = "bitmap")]
@@ -18,6 +23,18 @@ mod canvas;
 #[cfg(target_arch = "wasm32")]
 pub use canvas::CanvasBackend;
 
+mod color_vision;
+pub use color_vision::{ColorVision, ColorVisionBackend};
+
+mod contrast;
+pub use contrast::{ContrastBackend, ContrastMode};
+
+mod extent;
+pub use extent::ExtentBackend;
+
+mod layered;
+pub use layered::{LayeredBackend, RenderLayer};
+
 #[cfg(test)]
 mod mocked;
 #[cfg(test)]
@@ -33,6 +50,15 @@ mod cairo;
 #[cfg(all(not(target_arch = "wasm32"), feature = "cairo-rs"))]
 pub use self::cairo::CairoBackend;
 
+/// The hook opening the output stream of a file-producing backend in place of creating the
+/// file, which is called with the output path each time the image is saved. It can wrap the
+/// file into an encoder, e.g. the gzip compressor, tee the output into a hasher, or return a
+/// writer uploading the data without creating any file. The returned writer is flushed and
+/// dropped once the image is written. The hook is `Send`, so the backends can still be sent to
+/// another thread.
+pub type OutputHook<'a> =
+    Box<dyn FnMut(&std::path::Path) -> std::io::Result<Box<dyn std::io::Write + 'a>> + Send + 'a>;
+
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
 pub struct DummyBackendError;
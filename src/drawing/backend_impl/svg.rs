@@ -3,13 +3,23 @@ The SVG image drawing backend
 */
 pub use svg as svg_types;
 
-use svg::node::element::{Circle, Line, Polygon, Polyline, Rectangle, Text};
-use svg::Document;
-
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use svg::node::element::{
+    Circle, ClipPath, Definitions, Group, Line, Polygon, Polyline, Rectangle, Text, Use,
+};
+use svg::{Document, Node};
+
+#[cfg(feature = "svgz")]
+use flate2::{write::GzEncoder, Compression};
+
+use super::OutputHook;
+use crate::drawing::backend::{
+    draw_vertical_text, BackendCoord, BackendStyle, BackendTransform, DrawingBackend,
+    DrawingErrorKind,
+};
 use crate::style::{Color, FontStyle, FontTransform, RGBAColor, TextAlignment, TextStyle};
 
-use std::io::{Cursor, Error};
+use std::collections::HashMap;
+use std::io::{Cursor, Error, Write};
 use std::path::Path;
 
 fn make_svg_color<C: Color>(color: &C) -> String {
@@ -24,6 +34,9 @@ fn make_svg_opacity<C: Color>(color: &C) -> String {
 enum Target<'a> {
     File(&'a Path),
     Buffer(Cursor<&'a mut Vec<u8>>),
+    Hooked(&'a Path, OutputHook<'a>),
+    #[cfg(feature = "svgz")]
+    Compressed(&'a Path),
 }
 
 /// The SVG image drawing backend
@@ -31,6 +44,9 @@ pub struct SVGBackend<'a> {
     target: Target<'a>,
     size: (u32, u32),
     document: Option<Document>,
+    groups: Vec<Group>,
+    symbols: HashMap<String, String>,
+    clips: usize,
     saved: bool,
 }
 
@@ -41,22 +57,86 @@ impl<'a> SVGBackend<'a> {
         self.document = Some(op(temp.unwrap()));
     }
 
-    /// Create a new SVG drawing backend
+    /// Add the node to the innermost transformation group, or to the document if there's none
+    fn add_node<N: Node>(&mut self, node: N) {
+        if let Some(group) = self.groups.pop() {
+            self.groups.push(group.add(node));
+        } else {
+            self.update_document(|d| d.add(node));
+        }
+    }
+
+    /// Register the shape in `<defs>` and return its id, the identical shapes share the same
+    /// definition
+    fn define_symbol(&mut self, shape: Group) -> String {
+        let key = shape.to_string();
+        if let Some(id) = self.symbols.get(&key) {
+            return id.clone();
+        }
+        let id = format!("symbol-{}", self.symbols.len());
+        self.add_node(Definitions::new().add(shape.set("id", id.as_str())));
+        self.symbols.insert(key, id.clone());
+        id
+    }
+
+    /// Close the innermost transformation group
+    fn close_group(&mut self) {
+        if let Some(group) = self.groups.pop() {
+            self.add_node(group);
+        }
+    }
+
+    /// Create a new SVG drawing backend. With the `svgz` feature, the document is gzip
+    /// compressed if the file has the `.svgz` extension (in any letter case). Without the
+    /// feature, a `.svgz` path silently gets the plain SVG document.
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
-        Self {
-            target: Target::File(path.as_ref()),
-            size,
-            document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
-            saved: false,
+        let path = path.as_ref();
+        #[cfg(feature = "svgz")]
+        {
+            if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map_or(false, |ext| ext.eq_ignore_ascii_case("svgz"))
+            {
+                return Self::with_target(Target::Compressed(path), size);
+            }
         }
+        Self::with_target(Target::File(path), size)
+    }
+
+    /// Create a new SVG drawing backend which always writes the gzip compressed document,
+    /// regardless of the file extension
+    #[cfg(feature = "svgz")]
+    pub fn new_compressed<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Compressed(path.as_ref()), size)
     }
 
     /// Create a new SVG drawing backend and store the document into a u8 buffer
     pub fn with_buffer(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(Cursor::new(buf)), size)
+    }
+
+    /// Create a new SVG drawing backend which writes the document through the output hook
+    /// rather than into the file, see `OutputHook`
+    /// - `path`: The path passed to the hook
+    /// - `size`: The size of the image
+    /// - `hook`: The hook opening the output stream
+    pub fn with_output_hook<T, H>(path: &'a T, size: (u32, u32), hook: H) -> Self
+    where
+        T: AsRef<Path> + ?Sized,
+        H: FnMut(&Path) -> std::io::Result<Box<dyn Write + 'a>> + Send + 'a,
+    {
+        Self::with_target(Target::Hooked(path.as_ref(), Box::new(hook)), size)
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
         Self {
-            target: Target::Buffer(Cursor::new(buf)),
+            target,
             size,
             document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
+            groups: vec![],
+            symbols: HashMap::new(),
+            clips: 0,
             saved: false,
         }
     }
@@ -75,17 +155,115 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
         if !self.saved {
+            while !self.groups.is_empty() {
+                self.close_group();
+            }
             match self.target {
                 Target::File(path) => svg::save(path, self.document.as_ref().unwrap())
                     .map_err(DrawingErrorKind::DrawingError)?,
                 Target::Buffer(ref mut w) => svg::write(w, self.document.as_ref().unwrap())
                     .map_err(DrawingErrorKind::DrawingError)?,
+                Target::Hooked(path, ref mut hook) => {
+                    let mut writer = hook(path).map_err(DrawingErrorKind::DrawingError)?;
+                    svg::write(&mut writer, self.document.as_ref().unwrap())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                    writer.flush().map_err(DrawingErrorKind::DrawingError)?;
+                }
+                #[cfg(feature = "svgz")]
+                Target::Compressed(path) => {
+                    let file =
+                        std::fs::File::create(path).map_err(DrawingErrorKind::DrawingError)?;
+                    let mut encoder = GzEncoder::new(file, Compression::default());
+                    svg::write(&mut encoder, self.document.as_ref().unwrap())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                    encoder.finish().map_err(DrawingErrorKind::DrawingError)?;
+                }
             }
             self.saved = true;
         }
         Ok(())
     }
 
+    fn push_transform(
+        &mut self,
+        transform: BackendTransform,
+    ) -> Result<bool, DrawingErrorKind<Error>> {
+        let [a, b, c, d, e, f] = transform.matrix();
+        self.groups.push(Group::new().set(
+            "transform",
+            format!("matrix({} {} {} {} {} {})", a, b, c, d, e, f),
+        ));
+        Ok(true)
+    }
+
+    fn pop_transform(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        self.close_group();
+        Ok(())
+    }
+
+    /// Define the clip rectangle in `<defs>` and open a group clipped by it
+    fn push_clip(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Error>> {
+        let id = format!("clip-{}", self.clips);
+        self.clips += 1;
+        let rect = Rectangle::new()
+            .set("x", upper_left.0)
+            .set("y", upper_left.1)
+            .set("width", bottom_right.0 - upper_left.0 + 1)
+            .set("height", bottom_right.1 - upper_left.1 + 1);
+        self.add_node(Definitions::new().add(ClipPath::new().set("id", id.as_str()).add(rect)));
+        self.groups
+            .push(Group::new().set("clip-path", format!("url(#{})", id)));
+        Ok(true)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        self.close_group();
+        Ok(())
+    }
+
+    /// Open a group with the opacity, which the SVG renderers composite as a whole
+    fn push_opacity(&mut self, opacity: f64) -> Result<bool, DrawingErrorKind<Error>> {
+        let opacity = format!("{}", opacity.clamp(0.0, 1.0));
+        self.groups.push(Group::new().set("opacity", opacity));
+        Ok(true)
+    }
+
+    fn pop_opacity(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        self.close_group();
+        Ok(())
+    }
+
+    /// Define the shape once in `<defs>` and reference it with `<use>` for each position, the
+    /// definition is shared with the identical shapes stamped before
+    fn stamp_repeated<I, F>(&mut self, positions: I, draw: F) -> Result<(), DrawingErrorKind<Error>>
+    where
+        I: IntoIterator<Item = BackendCoord>,
+        F: Fn(&mut Self, BackendCoord) -> Result<(), DrawingErrorKind<Error>>,
+    {
+        let depth = self.groups.len();
+        self.groups.push(Group::new());
+        let result = draw(self, (0, 0));
+        while self.groups.len() > depth + 1 {
+            self.close_group();
+        }
+        let shape = self.groups.pop().unwrap();
+        result?;
+
+        let id = self.define_symbol(shape);
+        for (x, y) in positions {
+            let node = Use::new()
+                .set("href", format!("#{}", id))
+                .set("x", x)
+                .set("y", y);
+            self.add_node(node);
+        }
+        Ok(())
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -102,7 +280,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("stroke", "none")
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -123,7 +301,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()))
             .set("stroke-width", style.stroke_width());
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -155,7 +333,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -179,7 +357,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -201,7 +379,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -232,7 +410,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -242,6 +420,10 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let FontTransform::Vertical = style.font.get_transform() {
+            return draw_vertical_text(self, text, style, pos);
+        }
+
         let font = &style.font;
         let color = &style.color;
         if color.alpha() == 0.0 {
@@ -288,7 +470,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         }
         .add(context);
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
 
         Ok(())
     }
@@ -371,7 +553,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("height", h)
             .set("href", buf.as_str());
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
 
         Ok(())
     }
@@ -388,6 +570,7 @@ impl Drop for SVGBackend<'_> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::element::Circle;
     use crate::prelude::*;
     use std::fs;
     use std::path::Path;
@@ -405,6 +588,57 @@ mod test {
         fs::write(file_path, &content).unwrap();
     }
 
+    #[cfg(feature = "svgz")]
+    #[test]
+    fn test_compressed_output() {
+        use std::io::Read;
+
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_path = Path::new(DST_DIR).join("test_compressed_output.svgz");
+        {
+            let root = SVGBackend::new(&file_path, (100, 100)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+        }
+
+        let mut content = String::new();
+        flate2::read::GzDecoder::new(fs::File::open(&file_path).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert!(content.contains("<svg"));
+    }
+
+    #[test]
+    fn test_output_hook() {
+        struct SharedBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let output = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let mut paths = vec![];
+        {
+            let writer = output.clone();
+            let root = SVGBackend::with_output_hook("chart.svg", (100, 100), |path| {
+                paths.push(path.to_path_buf());
+                Ok(Box::new(SharedBuffer(writer.clone())) as Box<dyn Write>)
+            })
+            .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+        }
+
+        assert_eq!(paths, vec![Path::new("chart.svg").to_path_buf()]);
+        let content = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+        assert!(content.contains("<svg"));
+    }
+
     #[test]
     fn test_draw_mesh() {
         let mut buffer: Vec<u8> = vec![];
@@ -461,4 +695,150 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_vertical_text() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut root = SVGBackend::with_buffer(&mut buffer, (100, 100));
+            let style =
+                TextStyle::from(("sans-serif", 20).into_font()).transform(FontTransform::Vertical);
+            root.draw_text("縦書き", &style, (10, 10)).unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<text").count(), 3);
+        assert!(!content.contains("rotate"));
+        let first = content.find("縦").unwrap();
+        let last = content.find("き").unwrap();
+        assert!(first < last);
+    }
+
+    #[test]
+    fn test_transform_stack() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut root = SVGBackend::with_buffer(&mut buffer, (100, 100));
+            assert!(root
+                .push_transform(BackendTransform::translate(10.0, 20.0))
+                .unwrap());
+            root.draw_circle((0, 0), 5, &RED, true).unwrap();
+            root.pop_transform().unwrap();
+            root.draw_circle((50, 50), 5, &BLUE, true).unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_transform_stack", &content);
+
+        let group = content
+            .find("<g transform=\"matrix(1 0 0 1 10 20)\">")
+            .unwrap();
+        let end = content.find("</g>").unwrap();
+        let red = content.find("#FF0000").unwrap();
+        let blue = content.find("#0000FF").unwrap();
+        assert!(group < red && red < end && end < blue);
+    }
+
+    #[test]
+    fn test_clip() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .margin(20)
+                .build_ranged(0..10, 0..10)
+                .unwrap();
+            chart
+                .draw_series(LineSeries::new(vec![(0, 0), (20, 20)], &RED))
+                .unwrap();
+            chart
+                .draw_marker_series(&Circle::new((0, 0), 3, &BLUE), vec![(10, 10)])
+                .unwrap();
+            chart
+                .draw_converted_series(
+                    |&(x, y): &(i32, i32)| (x / 2, y / 2),
+                    LineSeries::new(vec![(0, 0), (40, 40)], &GREEN),
+                )
+                .unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_clip", &content);
+
+        // All the ways of drawing a series are clipped
+        assert_eq!(content.matches("<g clip-path=").count(), 3);
+
+        assert!(content.contains("<clipPath id=\"clip-0\">"));
+        let group = content.find("<g clip-path=\"url(#clip-0)\">").unwrap();
+        let line = content.find("<polyline").unwrap();
+        assert!(group < line);
+        // The point out of the range is kept rather than moved onto the border
+        let polyline = &content[line..];
+        assert!(polyline[..polyline.find("/>").unwrap()].contains(",-"));
+    }
+
+    #[test]
+    fn test_opacity_group() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10).unwrap();
+            chart
+                .draw_series_with_opacity(LineSeries::new(vec![(0, 0), (5, 9), (9, 0)], &RED), 0.5)
+                .unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_opacity_group", &content);
+
+        let group = content.find("<g opacity=\"0.5\">").unwrap();
+        let line = content.find("<polyline").unwrap();
+        assert!(group < line);
+    }
+
+    #[test]
+    fn test_stamp_repeated() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut root = SVGBackend::with_buffer(&mut buffer, (100, 100));
+            root.stamp_repeated(vec![(10, 10), (20, 30), (50, 50)], |b, pos| {
+                b.draw_circle(pos, 3, &RED, true)
+            })
+            .unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_stamp_repeated", &content);
+
+        assert_eq!(content.matches("<circle").count(), 1);
+        assert_eq!(content.matches("<use").count(), 3);
+        assert!(content.contains("id=\"symbol-0\""));
+    }
+
+    #[test]
+    fn test_symbol_reuse() {
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10).unwrap();
+
+            let marker = Circle::new((0, 0), 3, RED.filled());
+            chart
+                .draw_marker_series(&marker, (0..10).map(|x| (x, x)))
+                .unwrap()
+                .label("markers")
+                .stamped_legend(|(x, y)| Circle::new((x, y), 3, RED.filled()));
+            chart
+                .draw_marker_series(&marker, (0..10).map(|x| (x, 9 - x)))
+                .unwrap();
+            chart.configure_series_labels().draw().unwrap();
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        save_file("test_symbol_reuse", &content);
+
+        // The markers of both series and the legend glyph share the same definition
+        assert_eq!(content.matches("<circle").count(), 1);
+        assert_eq!(content.matches("<use").count(), 21);
+    }
 }
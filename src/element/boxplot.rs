@@ -171,6 +171,9 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
     /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
     /// let plot = Boxplot::new_horizontal("group", &quartiles).offset(-5);
     /// ```
+    ///
+    /// To make the offset relative to the category slot, which scales with the chart size, use
+    /// the slot size of the chart, for example `.offset(chart.x_slot_size().fraction(-0.2))`.
     pub fn offset<T: Into<f64> + Copy>(mut self, offset: T) -> Self {
         self.offset = offset.into();
         self
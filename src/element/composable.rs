@@ -1,5 +1,8 @@
 use super::*;
+use crate::coord::CoordTranslate;
 use crate::drawing::backend::DrawingBackend;
+use crate::drawing::ExtentBackend;
+use crate::style::SizeDesc;
 use std::borrow::Borrow;
 use std::iter::{once, Once};
 use std::marker::PhantomData;
@@ -198,3 +201,203 @@ where
         }
     }
 }
+
+/// A component of the composed element which is placed relative to the anchor point of the
+/// composed element. Unlike the plain components, which are placed with fixed pixel offsets,
+/// the offset can be a size relative to the parent drawing area, a distance in the data
+/// coordinate or a percentage of the component size, and the component can be aligned by its
+/// own bounding box, so that composite glyphs scale with the output size.
+/// The bounding box is the real extent of the drawing, e.g. including the radius of a circle and
+/// the size of a text, see `ExtentBackend`.
+pub struct Anchored<E, X: SizeDesc, Y: SizeDesc> {
+    inner: E,
+    offset: (X, Y),
+    coord_offset: BackendCoord,
+    size_offset: (f64, f64),
+    align: Option<(f64, f64)>,
+    origin: BackendCoord,
+}
+
+impl<E> Anchored<E, i32, i32> {
+    /// Create a new anchored component, by default it's placed exactly like a plain component
+    /// - `inner`: The element defined with pixel based offset coordinate
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            offset: (0, 0),
+            coord_offset: (0, 0),
+            size_offset: (0.0, 0.0),
+            align: None,
+            origin: (0, 0),
+        }
+    }
+}
+
+impl<E, X: SizeDesc, Y: SizeDesc> Anchored<E, X, Y> {
+    /// Set the offset from the anchor point
+    /// - `offset`: The offset, for example `(10, 5)` in pixels or
+    ///   `(10.percent_width(), 5.percent_height())` relative to the parent drawing area
+    pub fn offset<X1: SizeDesc, Y1: SizeDesc>(self, offset: (X1, Y1)) -> Anchored<E, X1, Y1> {
+        Anchored {
+            inner: self.inner,
+            offset,
+            coord_offset: self.coord_offset,
+            size_offset: self.size_offset,
+            align: self.align,
+            origin: self.origin,
+        }
+    }
+
+    /// Add the offset of a distance in the data coordinate, e.g. one unit of the X axis. Since
+    /// the axes may not be linear, the distance is given by two points, the offset is the
+    /// distance between them once they're mapped to the pixels.
+    /// - `coord`: The coordinate, e.g. `chart.as_coord_spec()`
+    /// - `from`: The start point of the distance
+    /// - `to`: The end point of the distance
+    pub fn coord_offset<C: CoordTranslate>(
+        mut self,
+        coord: &C,
+        from: C::From,
+        to: C::From,
+    ) -> Self {
+        let (x0, y0) = coord.translate(&from);
+        let (x1, y1) = coord.translate(&to);
+        self.coord_offset = (x1 - x0, y1 - y0);
+        self
+    }
+
+    /// Add the offset of a percentage of the component size, e.g. `(-50.0, 0.0)` moves the
+    /// component left by half of its width
+    pub fn size_offset(mut self, x_percent: f64, y_percent: f64) -> Self {
+        self.size_offset = (x_percent, y_percent);
+        self
+    }
+
+    /// Align the component by its bounding box, the bounding box point at the given fraction of
+    /// the component size is placed on the (offset) anchor point.
+    /// For example `(0.5, 0.5)` centers the component, `(1.0, 0.0)` places its upper-right
+    /// corner on the anchor point.
+    pub fn align(mut self, x: f64, y: f64) -> Self {
+        self.align = Some((x, y));
+        self
+    }
+}
+
+impl<'a, E, X: SizeDesc, Y: SizeDesc> PointCollection<'a, BackendCoord> for &'a Anchored<E, X, Y> {
+    type Borrow = &'a BackendCoord;
+    type IntoIter = Once<&'a BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.origin)
+    }
+}
+
+impl<DB: DrawingBackend, E, X: SizeDesc, Y: SizeDesc> Drawable<DB> for Anchored<E, X, Y>
+where
+    for<'a> &'a E: PointCollection<'a, BackendCoord>,
+    E: Drawable<DB> + Drawable<ExtentBackend>,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            let mut dx = x0 + self.offset.0.in_pixels(&ps) + self.coord_offset.0;
+            let mut dy = y0 + self.offset.1.in_pixels(&ps) + self.coord_offset.1;
+
+            if self.align.is_some() || self.size_offset != (0.0, 0.0) {
+                let mut measure = ExtentBackend::new(ps);
+                let points = self.inner.point_iter().into_iter().map(|p| *p.borrow());
+                // Measuring doesn't fail except for the font errors, which are reported when
+                // the component is actually drawn
+                if let (Ok(()), Some(((l, t), (r, b)))) = (
+                    Drawable::<ExtentBackend>::draw(&self.inner, points, &mut measure, ps),
+                    measure.extent(),
+                ) {
+                    let (w, h) = (f64::from(r - l), f64::from(b - t));
+                    dx += (w * self.size_offset.0 / 100.0).round() as i32;
+                    dy += (h * self.size_offset.1 / 100.0).round() as i32;
+                    if let Some((ax, ay)) = self.align {
+                        dx -= l + (w * ax).round() as i32;
+                        dy -= t + (h * ay).round() as i32;
+                    }
+                }
+            }
+
+            Drawable::<DB>::draw(
+                &self.inner,
+                self.inner.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + dx, p.1 + dy)
+                }),
+                backend,
+                ps,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_anchored_element() {
+        let da = crate::create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert_eq!(u, (90, 45));
+                assert_eq!(d, (110, 55));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (120, 60));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        da.draw(
+            &(EmptyElement::at((100, 50))
+                + Anchored::new(Rectangle::new([(0, 0), (20, 10)], &RED)).align(0.5, 0.5)
+                + Anchored::new(Circle::new((0, 0), 3, &RED))
+                    .offset((10.percent_width(), 10.percent_height()))),
+        )
+        .expect("Drawing Failure");
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_anchored_offsets() {
+        let da = crate::create_mocked_drawing_area(200, 100, |m| {
+            let centers = [(105, 55), (110, 40), (95, 50)];
+            let count = std::cell::Cell::new(0);
+            m.check_draw_circle(move |_, _, _, center, _| {
+                assert_eq!(center, centers[count.get()]);
+                count.set(count.get() + 1);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let coord: RangedCoord<RangedCoordf64, RangedCoordf64> =
+            RangedCoord::new(0.0..10.0, 0.0..10.0, (0..100, 100..0));
+
+        da.draw(
+            &(EmptyElement::at((100, 50))
+                // The bounding box includes the radius of the circle
+                + Anchored::new(Circle::new((0, 0), 5, &RED)).align(0.0, 0.0)
+                + Anchored::new(Circle::new((0, 0), 5, &RED)).coord_offset(
+                    &coord,
+                    (0.0, 0.0),
+                    (1.0, 1.0),
+                )
+                + Anchored::new(Circle::new((0, 0), 5, &RED)).size_offset(-50.0, 0.0)),
+        )
+        .expect("Drawing Failure");
+    }
+}
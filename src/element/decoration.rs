@@ -0,0 +1,257 @@
+/*!
+The decorations, which are the elements placed relative to a corner of the plotting area instead
+of a data point, such as the north arrow, the logo badge or the "draft" stamp.
+
+A decoration is defined in the pixel coordinate relative to its upper left corner, usually at
+`(0, 0)`, and `ChartContext::draw_decoration` moves it to the position in the plotting area, so
+it follows the plotting area when the chart layout or the output size changes.
+*/
+use super::{Drawable, PointCollection, ScaleBar};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{
+    FontResult, FontStyle, IntoFont, ShapeStyle, TextStyle, BLACK, RED, TRANSPARENT, WHITE,
+};
+
+/// The element which is placed relative to a corner of the plotting area
+pub trait Decoration {
+    /// Estimate the size of the decoration in pixels
+    fn estimate_dimension(&self) -> FontResult<(i32, i32)>;
+}
+
+impl<'a, Coord> Decoration for ScaleBar<'a, Coord> {
+    fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        ScaleBar::estimate_dimension(self)
+    }
+}
+
+/// The north arrow, which indicates the orientation of a map. The arrow points to the north and
+/// the "N" label is drawn upright above it.
+pub struct NorthArrow<'a, Coord> {
+    coord: Coord,
+    size: u32,
+    angle: f64,
+    style: ShapeStyle,
+    font: TextStyle<'a>,
+}
+
+impl<'a, Coord> NorthArrow<'a, Coord> {
+    /// Create a new north arrow pointing up
+    /// - `coord`: The upper left corner of the north arrow with its label
+    /// - `size`: The size of the arrow in pixels
+    /// - `style`: The style of the arrow, the label uses the same color
+    pub fn new<S: Into<ShapeStyle>>(coord: Coord, size: u32, style: S) -> Self {
+        let style = style.into();
+        Self {
+            coord,
+            size,
+            angle: 0.0,
+            font: ("sans-serif", 12).into_font().color(&style.color),
+            style,
+        }
+    }
+
+    /// Set the direction of the north
+    /// - `angle`: The angle between the north and the up direction in radians, clockwise
+    pub fn angle(mut self, angle: f64) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Set the style of the "N" label
+    pub fn label_style<F: Into<TextStyle<'a>>>(mut self, font: F) -> Self {
+        self.font = font.into();
+        self
+    }
+}
+
+impl<'a, Coord> Decoration for NorthArrow<'a, Coord> {
+    fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        let (w, h) = self.font.font.box_size("N")?;
+        let size = self.size as i32;
+        Ok((size.max(w as i32), size + h as i32))
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a NorthArrow<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for NorthArrow<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let (text_w, text_h) = self
+                .font
+                .font
+                .box_size("N")
+                .map_err(DrawingErrorKind::FontError)?;
+            let size = f64::from(self.size);
+            let width = self.size.max(text_w) as i32;
+            let center = (
+                f64::from(x) + f64::from(width) / 2.0,
+                f64::from(y + text_h as i32) + size / 2.0,
+            );
+
+            let (sin, cos) = self.angle.sin_cos();
+            let rotate = |(dx, dy): (f64, f64)| {
+                (
+                    (center.0 + dx * cos - dy * sin).round() as i32,
+                    (center.1 + dx * sin + dy * cos).round() as i32,
+                )
+            };
+            let tip = rotate((0.0, -size / 2.0));
+            let left = rotate((-size / 3.0, size / 2.0));
+            let notch = rotate((0.0, size / 4.0));
+            let right = rotate((size / 3.0, size / 2.0));
+
+            backend.fill_polygon(vec![tip, right, notch, left], &self.style)?;
+            backend.draw_text("N", &self.font, (x + (width - text_w as i32) / 2, y))?;
+        }
+        Ok(())
+    }
+}
+
+/// The badge, which is a text in a box, such as a logo, a copyright notice or a "draft" stamp
+pub struct Badge<'a, Coord> {
+    coord: Coord,
+    text: String,
+    font: TextStyle<'a>,
+    border: ShapeStyle,
+    background: ShapeStyle,
+    padding: u32,
+}
+
+impl<'a, Coord> Badge<'a, Coord> {
+    /// Create a new badge with the black border and the white background
+    /// - `coord`: The upper left corner of the badge
+    /// - `text`: The text of the badge
+    /// - `font`: The style of the text
+    pub fn new<T: Into<String>, F: Into<TextStyle<'a>>>(coord: Coord, text: T, font: F) -> Self {
+        Self {
+            coord,
+            text: text.into(),
+            font: font.into(),
+            border: BLACK.stroke_width(1),
+            background: WHITE.filled(),
+            padding: 4,
+        }
+    }
+
+    /// Create the stamp, which is a badge with the large bold red text, the thick red border
+    /// and the transparent background, e.g. `Badge::stamp((0, 0), "DRAFT")`
+    /// - `coord`: The upper left corner of the stamp
+    /// - `text`: The text of the stamp
+    pub fn stamp<T: Into<String>>(coord: Coord, text: T) -> Self {
+        let font = ("sans-serif", 24)
+            .into_font()
+            .style(FontStyle::Bold)
+            .color(&RED);
+        Self::new(coord, text, font)
+            .border_style(RED.stroke_width(2))
+            .background_style(&TRANSPARENT)
+            .padding(6)
+    }
+
+    /// Set the style of the border
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border = style.into();
+        self
+    }
+
+    /// Set the style of the background
+    pub fn background_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.background = style.into();
+        self.background.filled = true;
+        self
+    }
+
+    /// Set the distance between the text and the border in pixels
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+impl<'a, Coord> Decoration for Badge<'a, Coord> {
+    fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        let (w, h) = self.font.font.box_size(&self.text)?;
+        let padding = self.padding as i32 * 2;
+        Ok((w as i32 + padding, h as i32 + padding))
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a Badge<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for Badge<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let (w, h) = self
+                .estimate_dimension()
+                .map_err(DrawingErrorKind::FontError)?;
+            let bottom_right = (x + w, y + h);
+            backend.draw_rect((x, y), bottom_right, &self.background, true)?;
+            backend.draw_rect((x, y), bottom_right, &self.border, false)?;
+            let padding = self.padding as i32;
+            backend.draw_text(&self.text, &self.font, (x + padding, y + padding))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::render_element;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_north_arrow() {
+        let mut backend = MockedBackend::new(100, 100);
+        backend.check_draw_path(|_, _, _| panic!("The arrow should be filled"));
+        backend.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+        let arrow = NorthArrow::new((0, 0), 30, &BLACK).angle(std::f64::consts::PI);
+        let (w, h) = arrow.estimate_dimension().unwrap();
+        assert_eq!(w, 30);
+        assert!(h > 30);
+        render_element(backend, &arrow).expect("Render north arrow");
+    }
+
+    #[test]
+    fn test_badge() {
+        let mut backend = MockedBackend::new(200, 100);
+        backend.check_draw_rect(|c, width, filled, upper_left, _| {
+            assert_eq!(upper_left, (0, 0));
+            if !filled {
+                assert_eq!(c.rgb(), (255, 0, 0));
+                assert_eq!(width, 2);
+            }
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 2);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+        render_element(backend, &Badge::stamp((0, 0), "DRAFT")).expect("Render stamp");
+    }
+}
@@ -0,0 +1,122 @@
+/*!
+Render a single element on its own, without a chart around it.
+
+This is useful for the legend images and the markers of an external HTML legend, or the icons
+in the documentation, since they are produced from the same element definitions as the chart.
+The element is given in the pixel coordinate of the output image, (0, 0) is the upper-left
+corner.
+
+The composed elements are bound to the backend type they are drawn on, so they are rendered
+with `render_element` on a backend created by the caller instead of the helpers of a specific
+format.
+*/
+use super::{Drawable, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend};
+use crate::drawing::{DrawingAreaErrorKind, IntoDrawingArea};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use crate::drawing::BitMapBackend;
+#[cfg(feature = "svg")]
+use crate::drawing::SVGBackend;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use crate::style::Color;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use std::path::Path;
+
+/// Draw the element onto the whole backend and present the result
+/// - `backend`: The backend the element is rendered on, its size is the size of the output
+/// - `element`: The element to render in the pixel coordinate
+pub fn render_element<DB: DrawingBackend, E>(
+    backend: DB,
+    element: &E,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+where
+    for<'a> &'a E: PointCollection<'a, BackendCoord>,
+    E: Drawable<DB>,
+{
+    let root = backend.into_drawing_area();
+    root.draw(element)?;
+    root.present()
+}
+
+/// Render the element to a standalone SVG document with the transparent background
+/// - `element`: The element to render in the pixel coordinate
+/// - `size`: The size of the document in pixels
+/// - **returns**: The SVG document
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::element::element_to_svg;
+///
+/// let icon = element_to_svg(&Circle::new((8, 8), 6, RED.filled()), (16, 16)).unwrap();
+/// assert!(icon.contains("<circle"));
+/// ```
+#[cfg(feature = "svg")]
+pub fn element_to_svg<E>(
+    element: &E,
+    size: (u32, u32),
+) -> Result<String, DrawingAreaErrorKind<std::io::Error>>
+where
+    for<'a> &'a E: PointCollection<'a, BackendCoord>,
+    for<'b> E: Drawable<SVGBackend<'b>>,
+{
+    let mut buffer = vec![];
+    render_element(SVGBackend::with_buffer(&mut buffer, size), element)?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+/// Render the element to a standalone bitmap image file, the format is determined by the file
+/// extension
+/// - `element`: The element to render in the pixel coordinate
+/// - `size`: The size of the image in pixels
+/// - `background`: The color filling the image before the element is drawn
+/// - `path`: The path of the image file
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+pub fn element_to_bitmap<E, C: Color, T: AsRef<Path> + ?Sized>(
+    element: &E,
+    size: (u32, u32),
+    background: &C,
+    path: &T,
+) -> Result<(), DrawingAreaErrorKind<<BitMapBackend<'static> as DrawingBackend>::ErrorType>>
+where
+    for<'a> &'a E: PointCollection<'a, BackendCoord>,
+    for<'b> E: Drawable<BitMapBackend<'b>>,
+{
+    let root = BitMapBackend::new(path, size).into_drawing_area();
+    root.fill(background)?;
+    root.draw(element)?;
+    root.present()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_element() {
+        let mut backend = MockedBackend::new(16, 16);
+        backend.check_draw_circle(|_, _, filled, center, radius| {
+            assert!(filled);
+            assert_eq!(center, (8, 8));
+            assert_eq!(radius, 6);
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+        render_element(backend, &Circle::new((8, 8), 6, RED.filled())).expect("Render element");
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_element_to_svg() {
+        let svg = element_to_svg(&Circle::new((8, 8), 6, BLUE.filled()), (16, 16))
+            .expect("Render element");
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("<circle"));
+
+        let text = Text::new("A", (4, 2), ("sans-serif", 10).into_font());
+        let svg = element_to_svg(&text, (16, 16)).expect("Render element");
+        assert!(svg.contains("<text"));
+    }
+}
@@ -168,7 +168,7 @@ mod points;
 pub use points::*;
 
 mod composable;
-pub use composable::{ComposedElement, EmptyElement};
+pub use composable::{Anchored, BoxedElement, ComposedElement, EmptyElement};
 
 mod candlestick;
 pub use candlestick::CandleStick;
@@ -179,6 +179,12 @@ pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
 mod boxplot;
 pub use boxplot::Boxplot;
 
+mod scale_bar;
+pub use scale_bar::ScaleBar;
+
+mod decoration;
+pub use decoration::{Badge, Decoration, NorthArrow};
+
 #[cfg(feature = "bitmap")]
 mod image;
 #[cfg(feature = "bitmap")]
@@ -187,6 +193,13 @@ pub use self::image::BitMapElement;
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
+mod export;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+pub use export::element_to_bitmap;
+#[cfg(feature = "svg")]
+pub use export::element_to_svg;
+pub use export::render_element;
+
 /// A type which is logically a collection of points, under any given coordinate system
 pub trait PointCollection<'a, Coord> {
     /// The item in point iterator
@@ -8,6 +8,21 @@ pub trait PointElement<Coord, Size: SizeDesc> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self;
 }
 
+/// The shape of a point element, which is used to draw the same marker in the legend
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkerShape {
+    Circle,
+    Cross,
+    Triangle,
+    Pixel,
+}
+
+/// The point elements which can be drawn as the legend glyph of the series made of them
+pub trait LegendMarker {
+    /// The shape of the element
+    const SHAPE: MarkerShape;
+}
+
 /// Describe a cross
 pub struct Cross<Coord, Size: SizeDesc> {
     center: Coord,
@@ -123,3 +138,19 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+impl<Coord, Size: SizeDesc> LegendMarker for Cross<Coord, Size> {
+    const SHAPE: MarkerShape = MarkerShape::Cross;
+}
+
+impl<Coord, Size: SizeDesc> LegendMarker for TriangleMarker<Coord, Size> {
+    const SHAPE: MarkerShape = MarkerShape::Triangle;
+}
+
+impl<Coord, Size: SizeDesc> LegendMarker for Circle<Coord, Size> {
+    const SHAPE: MarkerShape = MarkerShape::Circle;
+}
+
+impl<Coord> LegendMarker for Pixel<Coord> {
+    const SHAPE: MarkerShape = MarkerShape::Pixel;
+}
@@ -0,0 +1,206 @@
+use super::{Drawable, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{FontResult, ShapeStyle, TextStyle};
+
+/// The height of the ticks at the ends of the bar in pixels
+const TICK_SIZE: i32 = 4;
+
+/// The gap between the label and the bar in pixels
+const LABEL_GAP: i32 = 2;
+
+/// The SI prefixes from 10^-12 to 10^12, in steps of 10^3
+const SI_PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+
+/// Compute the power of 10, which is exact for the negative exponents as well
+fn pow10(exp: i32) -> f64 {
+    if exp < 0 {
+        1.0 / 10f64.powi(-exp)
+    } else {
+        10f64.powi(exp)
+    }
+}
+
+/// Find the largest round length, that is 1, 2 or 5 times a power of 10, not exceeding the limit
+fn round_length(limit: f64) -> f64 {
+    if !limit.is_finite() || limit <= 0.0 {
+        return 0.0;
+    }
+    // The logarithm of an exact power of 10 may be slightly below the integer
+    let mut exp = (limit.log10() + 1e-9).floor() as i32;
+    if pow10(exp) > limit {
+        exp -= 1;
+    }
+    let base = pow10(exp);
+    let factor = [5.0, 2.0, 1.0]
+        .iter()
+        .copied()
+        .find(|&f| f * base <= limit)
+        .unwrap_or(1.0);
+    factor * base
+}
+
+/// Format the length with the SI prefix of the unit, e.g. `0.0001` meters is `100 µm`
+fn format_length(length: f64, unit: &str) -> String {
+    if unit.is_empty() || length <= 0.0 {
+        return format!("{}", length);
+    }
+    let group = (((length.log10() + 1e-9) / 3.0).floor() as i32).clamp(-4, 4);
+    let value = length / pow10(group * 3);
+    // The value is a round number, the rounding only removes the floating point error
+    let value = (value * 1e6).round() / 1e6;
+    format!("{} {}{}", value, SI_PREFIXES[(group + 4) as usize], unit)
+}
+
+/// The scale bar, which is a horizontal bar with the ticks at both ends representing a round
+/// length in the data space, with the label of the length above the bar. It's commonly used for
+/// the maps and the microscopy images, where the axes are often hidden.
+pub struct ScaleBar<'a, Coord> {
+    coord: Coord,
+    length: f64,
+    width: u32,
+    label: String,
+    style: ShapeStyle,
+    font: TextStyle<'a>,
+}
+
+impl<'a, Coord> ScaleBar<'a, Coord> {
+    /// Create a new scale bar, the length of the bar is the largest round length which fits in
+    /// the maximum width
+    /// - `coord`: The upper left corner of the scale bar with its label
+    /// - `pixels_per_unit`: The number of pixels of the unit length in the data space
+    /// - `max_width`: The maximum width of the bar in pixels
+    /// - `unit`: The unit of the data, e.g. `"m"`, which is prefixed with the SI prefix in the
+    ///   label, such as `"10 km"`. If it's empty, the label is the plain number.
+    /// - `style`: The style of the bar
+    /// - `font`: The style of the label
+    pub fn new<S: Into<ShapeStyle>, F: Into<TextStyle<'a>>>(
+        coord: Coord,
+        pixels_per_unit: f64,
+        max_width: u32,
+        unit: &str,
+        style: S,
+        font: F,
+    ) -> Self {
+        let pixels_per_unit = pixels_per_unit.abs();
+        let length = round_length(f64::from(max_width) / pixels_per_unit);
+        Self {
+            coord,
+            length,
+            width: (length * pixels_per_unit).round() as u32,
+            label: format_length(length, unit),
+            style: style.into(),
+            font: font.into(),
+        }
+    }
+
+    /// Get the length the bar represents in the data space
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    /// Get the width of the bar in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the label of the bar
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Estimate the size of the scale bar with its label
+    pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        let (w, h) = self.font.font.box_size(&self.label)?;
+        Ok((
+            (w as i32).max(self.width as i32),
+            h as i32 + LABEL_GAP + TICK_SIZE,
+        ))
+    }
+
+    /// Move the scale bar to the specified location
+    pub fn relocate(&mut self, coord: Coord) {
+        self.coord = coord
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a ScaleBar<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for ScaleBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let (text_w, text_h) = self
+                .font
+                .font
+                .box_size(&self.label)
+                .map_err(DrawingErrorKind::FontError)?;
+            let (text_w, text_h) = (text_w as i32, text_h as i32);
+            let width = self.width as i32;
+            let total_w = text_w.max(width);
+
+            let bar_x = x + (total_w - width) / 2;
+            let bar_y = y + text_h + LABEL_GAP + TICK_SIZE;
+            backend.draw_path(
+                vec![
+                    (bar_x, bar_y - TICK_SIZE),
+                    (bar_x, bar_y),
+                    (bar_x + width, bar_y),
+                    (bar_x + width, bar_y - TICK_SIZE),
+                ],
+                &self.style,
+            )?;
+            backend.draw_text(&self.label, &self.font, (x + (total_w - text_w) / 2, y))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::render_element;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_round_length() {
+        assert_eq!(round_length(130.0), 100.0);
+        assert_eq!(round_length(7.5), 5.0);
+        assert_eq!(round_length(0.00042), 0.0002);
+        assert_eq!(round_length(1000.0), 1000.0);
+        assert_eq!(round_length(0.0), 0.0);
+        assert_eq!(format_length(0.0001, "m"), "100 µm");
+        assert_eq!(format_length(10000.0, "m"), "10 km");
+        assert_eq!(format_length(1000.0, "m"), "1 km");
+        assert_eq!(format_length(2.0, "m"), "2 m");
+        assert_eq!(format_length(50.0, ""), "50");
+    }
+
+    #[test]
+    fn test_scale_bar() {
+        let bar = ScaleBar::new((0, 0), 2.0, 130, "m", &BLACK, ("sans-serif", 12));
+        assert_eq!(bar.length(), 50.0);
+        assert_eq!(bar.width(), 100);
+        assert_eq!(bar.label(), "50 m");
+
+        let mut backend = MockedBackend::new(200, 200);
+        backend.check_draw_path(|_, _, path| {
+            assert_eq!(path.len(), 4);
+            assert_eq!(path[2].0 - path[1].0, 100);
+        });
+        backend.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+        render_element(backend, &bar).expect("Render scale bar");
+    }
+}
@@ -0,0 +1,248 @@
+use crate::drawing::backend::BackendCoord;
+
+/// The clip rectangle, the upper-left and the bottom-right corners, both of them inclusive
+type ClipRect = (BackendCoord, BackendCoord);
+
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const TOP: u8 = 4;
+const BOTTOM: u8 = 8;
+
+/// Check if the point is inside of the rectangle
+/// - `point`: The point to check
+/// - `rect`: The rectangle, both of the corners are inclusive
+pub fn is_inside((x, y): BackendCoord, ((x0, y0), (x1, y1)): ClipRect) -> bool {
+    x0 <= x && x <= x1 && y0 <= y && y <= y1
+}
+
+fn to_f64((x, y): BackendCoord) -> (f64, f64) {
+    (f64::from(x), f64::from(y))
+}
+
+fn to_coord((x, y): (f64, f64)) -> BackendCoord {
+    (x.round() as i32, y.round() as i32)
+}
+
+/// The Cohen-Sutherland region code of the point
+fn outcode((x, y): (f64, f64), ((x0, y0), (x1, y1)): ((f64, f64), (f64, f64))) -> u8 {
+    let mut code = 0;
+    if x < x0 {
+        code |= LEFT;
+    } else if x > x1 {
+        code |= RIGHT;
+    }
+    if y < y0 {
+        code |= TOP;
+    } else if y > y1 {
+        code |= BOTTOM;
+    }
+    code
+}
+
+/// Clip the line segment to the rectangle with the Cohen-Sutherland algorithm
+/// - `from`: The start point of the segment
+/// - `to`: The end point of the segment
+/// - `rect`: The rectangle, both of the corners are inclusive
+/// - **returns**: The part of the segment inside of the rectangle, `None` if there's none
+pub fn clip_line(
+    from: BackendCoord,
+    to: BackendCoord,
+    rect: ClipRect,
+) -> Option<(BackendCoord, BackendCoord)> {
+    let rect_f = (to_f64(rect.0), to_f64(rect.1));
+    let ((x0, y0), (x1, y1)) = rect_f;
+    let (mut a, mut b) = (to_f64(from), to_f64(to));
+    let (mut code_a, mut code_b) = (outcode(a, rect_f), outcode(b, rect_f));
+
+    loop {
+        if code_a | code_b == 0 {
+            return Some((to_coord(a), to_coord(b)));
+        }
+        if code_a & code_b != 0 {
+            return None;
+        }
+
+        let code = if code_a != 0 { code_a } else { code_b };
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let p = if code & TOP != 0 {
+            (a.0 + dx * (y0 - a.1) / dy, y0)
+        } else if code & BOTTOM != 0 {
+            (a.0 + dx * (y1 - a.1) / dy, y1)
+        } else if code & LEFT != 0 {
+            (x0, a.1 + dy * (x0 - a.0) / dx)
+        } else {
+            (x1, a.1 + dy * (x1 - a.0) / dx)
+        };
+
+        if code == code_a {
+            a = p;
+            code_a = outcode(a, rect_f);
+        } else {
+            b = p;
+            code_b = outcode(b, rect_f);
+        }
+    }
+}
+
+/// Clip the polyline to the rectangle, the polyline is split where it leaves the rectangle
+/// - `points`: The points of the polyline
+/// - `rect`: The rectangle, both of the corners are inclusive
+/// - **returns**: The visible pieces of the polyline
+pub fn clip_polyline(points: &[BackendCoord], rect: ClipRect) -> Vec<Vec<BackendCoord>> {
+    if points.len() == 1 {
+        return if is_inside(points[0], rect) {
+            vec![points.to_vec()]
+        } else {
+            vec![]
+        };
+    }
+
+    let mut pieces = vec![];
+    let mut current: Vec<BackendCoord> = vec![];
+
+    for segment in points.windows(2) {
+        match clip_line(segment[0], segment[1], rect) {
+            Some((from, to)) => {
+                if current.last() != Some(&from) {
+                    if current.len() > 1 {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current = vec![from];
+                }
+                current.push(to);
+                if to != segment[1] && current.len() > 1 {
+                    pieces.push(std::mem::take(&mut current));
+                }
+            }
+            None => {
+                if current.len() > 1 {
+                    pieces.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Clip the polygon to the rectangle with the Sutherland-Hodgman algorithm
+/// - `points`: The vertices of the polygon
+/// - `rect`: The rectangle, both of the corners are inclusive
+/// - **returns**: The vertices of the clipped polygon, empty if nothing is left
+pub fn clip_polygon(points: &[BackendCoord], rect: ClipRect) -> Vec<BackendCoord> {
+    let ((x0, y0), (x1, y1)) = (to_f64(rect.0), to_f64(rect.1));
+    let mut vertices: Vec<(f64, f64)> = points.iter().map(|&p| to_f64(p)).collect();
+
+    // Each edge is given by the axis (0 for x, 1 for y), the bound, and the side to keep
+    let edges = [(0, x0, false), (0, x1, true), (1, y0, false), (1, y1, true)];
+
+    for &(axis, bound, upper) in edges.iter() {
+        if vertices.is_empty() {
+            break;
+        }
+        let coord = |p: &(f64, f64)| if axis == 0 { p.0 } else { p.1 };
+        let inside = |p: &(f64, f64)| {
+            if upper {
+                coord(p) <= bound
+            } else {
+                coord(p) >= bound
+            }
+        };
+        let intersect = |a: &(f64, f64), b: &(f64, f64)| {
+            let t = (bound - coord(a)) / (coord(b) - coord(a));
+            if axis == 0 {
+                (bound, a.1 + (b.1 - a.1) * t)
+            } else {
+                (a.0 + (b.0 - a.0) * t, bound)
+            }
+        };
+
+        let input = std::mem::take(&mut vertices);
+        let mut prev = input[input.len() - 1];
+        for cur in input {
+            match (inside(&prev), inside(&cur)) {
+                (true, true) => vertices.push(cur),
+                (true, false) => vertices.push(intersect(&prev, &cur)),
+                (false, true) => {
+                    vertices.push(intersect(&prev, &cur));
+                    vertices.push(cur);
+                }
+                (false, false) => {}
+            }
+            prev = cur;
+        }
+    }
+
+    let mut result: Vec<BackendCoord> = vec![];
+    for p in vertices.into_iter().map(to_coord) {
+        if result.last() != Some(&p) {
+            result.push(p);
+        }
+    }
+    if result.len() > 1 && result.first() == result.last() {
+        result.pop();
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RECT: ClipRect = ((0, 0), (99, 99));
+
+    #[test]
+    fn test_clip_line() {
+        assert_eq!(
+            clip_line((10, 10), (20, 30), RECT),
+            Some(((10, 10), (20, 30)))
+        );
+        assert_eq!(
+            clip_line((-50, 50), (150, 50), RECT),
+            Some(((0, 50), (99, 50)))
+        );
+        assert_eq!(clip_line((-10, -10), (-5, 200), RECT), None);
+        assert_eq!(
+            clip_line((-50, 0), (50, 100), RECT),
+            Some(((0, 50), (49, 99)))
+        );
+        assert_eq!(
+            clip_line((50, 50), (i32::MAX, i32::MAX), RECT),
+            Some(((50, 50), (99, 99)))
+        );
+        assert_eq!(
+            clip_line((i32::MIN, 50), (i32::MAX, 50), RECT),
+            Some(((0, 50), (99, 50)))
+        );
+    }
+
+    #[test]
+    fn test_clip_polyline() {
+        let pieces = clip_polyline(&[(-10, 10), (50, 10), (50, 200), (60, 200), (60, 50)], RECT);
+        assert_eq!(
+            pieces,
+            vec![vec![(0, 10), (50, 10), (50, 99)], vec![(60, 99), (60, 50)]]
+        );
+        assert!(clip_polyline(&[(-10, -10), (-20, 200)], RECT).is_empty());
+        assert_eq!(clip_polyline(&[(5, 5)], RECT), vec![vec![(5, 5)]]);
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let inside = vec![(10, 10), (50, 10), (30, 40)];
+        assert_eq!(clip_polygon(&inside, RECT), inside);
+        assert_eq!(
+            clip_polygon(&[(-100, -100), (200, -100), (200, 200), (-100, 200)], RECT),
+            vec![(0, 99), (0, 0), (99, 0), (99, 99)]
+        );
+        assert!(clip_polygon(&[(200, 200), (300, 200), (250, 300)], RECT).is_empty());
+        assert_eq!(
+            clip_polygon(&[(50, 50), (i32::MAX, 50), (50, i32::MAX)], RECT),
+            vec![(50, 99), (50, 50), (99, 50), (99, 99)]
+        );
+    }
+}
@@ -0,0 +1,10 @@
+/*!
+The geometry module, which implements the geometric algorithms used before rasterization.
+
+The clipping utilities cut the lines and the polygons to a rectangle in the backend coordinate,
+so that the huge coordinates of the shapes far outside of the visible area never reach the
+rasterizer, which avoids the integer overflow and the time spent on the invisible pixels.
+*/
+
+mod clip;
+pub use clip::{clip_line, clip_polygon, clip_polyline, is_inside};
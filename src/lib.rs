@@ -577,6 +577,7 @@ use `default_features = false` in the dependency description in `Cargo.toml` and
 
 - `svg` Enable the `SVGBackend`
 - `bitmap` Enable the `BitMapBackend`
+- `eps` Enable the `EpsBackend`
 
 For example, the following dependency description would avoid compiling with bitmap support:
 
@@ -596,12 +597,16 @@ This is the full list of features that is defined by `Plotters` crate. Use `defa
 |---------|--------------|--------|------------|
 | image\_encoder  | Allow `BitMapBackend` save the result to bitmap files | image | Yes |
 | svg     | Enable `SVGBackend` Support | svg | Yes |
+| eps     | Enable `EpsBackend` Support | None | Yes |
+| svgz    | Write the gzip compressed SVG files with `SVGBackend`, implies `svg` enabled | flate2 | No |
 | datetime| Enable Date and Time Coordinate Support| chrono | Yes |
 | gif\_backend| Opt-in GIF animation Rendering support for `BitMapBackend`, implies `bitmap` enabled | gif | Yes |
 | piston | Enable `PistonWindowBackend` | piston\_window | No |
 | cairo | Enable `CairoBackend` | cairo-rs | No |
 | palette\_ext | Use crate `palette` for color expression| palette | Yes |
 | evcxr | Enable Evcxr support, which allows use `Plotters` in Jupyter Note Book | None | No |
+| xlsx | Render the figures as images for the XLSX worksheets, see `xlsx::xlsx_figure` | image | No |
+| serde | Load and save the `AnnotationLayer` documents with serde | serde | No |
 
 ## FAQ List
 
@@ -643,12 +648,22 @@ pub mod coord;
 pub mod data;
 pub mod drawing;
 pub mod element;
+pub mod geometry;
 pub mod series;
 pub mod style;
 
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
+#[cfg(feature = "svg")]
+pub mod report;
+
+#[cfg(feature = "bitmap")]
+pub mod snapshot;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "xlsx"))]
+pub mod xlsx;
+
 #[cfg(test)]
 pub use crate::drawing::create_mocked_drawing_area;
 
@@ -657,28 +672,37 @@ pub use palette;
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        Annotation, AnnotationLayer, AxisMargin, AxisNotation, CellValue, ChartBuilder,
+        ChartContext, ConfusionMatrix, JointPlot, LabelAreaPosition, LabelOverflow, LabelPrecision,
+        MarginalHistograms, MeshStyleSheet, Readout, Sampling, SeriesLabelPosition,
+    };
     pub use crate::coord::{
-        Category, CoordTranslate, GroupBy, IntoCentric, IntoPartialAxis, LogCoord, LogRange,
-        LogScalable, Ranged, RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32,
-        RangedCoordi64, RangedCoordu32, RangedCoordu64, ToGroupByRange,
+        Category, CoordTranslate, GroupBy, IntoAngleCoord, IntoCentric, IntoPartialAxis, LogCoord,
+        LogRange, LogScalable, NiceRange, Ranged, RangedCoord, RangedCoordf32, RangedCoordf64,
+        RangedCoordi32, RangedCoordi64, RangedCoordu32, RangedCoordu64, ToGroupByRange,
     };
 
     #[cfg(feature = "chrono")]
-    pub use crate::coord::{make_partial_axis, RangedDate, RangedDateTime, RangedDuration};
+    pub use crate::coord::{
+        make_partial_axis, IntoRelativeTime, RangedDate, RangedDateTime, RangedDuration,
+        RangedRelativeTime,
+    };
 
     pub use crate::drawing::*;
-    pub use crate::series::{AreaSeries, Histogram, LineSeries, PointSeries};
+    pub use crate::series::{AreaSeries, GroupedBars, Histogram, LineSeries, PointSeries};
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBColor, ShapeStyle,
-        SimpleColor, TextStyle,
+        AsRelative, CategoryStyle, Color, ColorMapStyle, ColorSpace, FontDesc, FontFamily,
+        FontStyle, FontTransform, HSLColor, IntoFont, Palette, Palette100, Palette99, Palette9999,
+        PaletteColor, RGBColor, ShapeStyle, SimpleColor, StrokeWidth, StyleMapper, TextStyle,
+        Theme,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
     pub use crate::element::{
-        Boxplot, CandleStick, Circle, Cross, DynElement, EmptyElement, ErrorBar, IntoDynElement,
-        MultiLineText, PathElement, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Badge, Boxplot, CandleStick, Circle, Cross, DynElement, EmptyElement, ErrorBar,
+        IntoDynElement, MultiLineText, NorthArrow, PathElement, Pixel, Polygon, Rectangle,
+        ScaleBar, Text, TriangleMarker,
     };
 
     #[cfg(feature = "bitmap")]
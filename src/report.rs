@@ -0,0 +1,181 @@
+/*!
+The HTML report which collects several figures with their titles and captions into one
+standalone HTML file
+*/
+use crate::coord::Shift;
+use crate::data::{escape_html, DataTable};
+use crate::drawing::{DrawingArea, IntoDrawingArea, SVGBackend};
+
+use std::error::Error;
+use std::path::Path;
+
+const REPORT_STYLE: &str = "body { font-family: sans-serif; max-width: 960px; margin: 2em auto; \
+color: #222; }
+figure { margin: 2em 0; }
+figure svg { max-width: 100%; height: auto; }
+figcaption { color: #555; margin-top: 0.5em; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #ccc; padding: 0.2em 0.6em; }
+";
+
+struct ReportFigure {
+    title: String,
+    caption: String,
+    svg: String,
+    tables: Vec<String>,
+}
+
+/// The HTML report with the SVG figures embedded inline
+pub struct HtmlReport {
+    title: String,
+    figures: Vec<ReportFigure>,
+}
+
+impl HtmlReport {
+    /// Create an empty report
+    /// - `title`: The title of the report
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        Self {
+            title: title.into(),
+            figures: vec![],
+        }
+    }
+
+    /// Add a rendered SVG document to the report
+    /// - `title`: The title of the figure
+    /// - `caption`: The caption under the figure, the empty caption is omitted
+    /// - `svg`: The SVG document, e.g. the buffer of `SVGBackend::with_buffer`
+    pub fn add_svg<T: Into<String>, C: Into<String>>(
+        &mut self,
+        title: T,
+        caption: C,
+        svg: &[u8],
+    ) -> &mut Self {
+        let svg = String::from_utf8_lossy(svg);
+        // The XML declaration isn't allowed in the middle of the HTML document
+        let svg = match (svg.find("<?xml"), svg.find("?>")) {
+            (Some(0), Some(end)) => svg[end + 2..].trim_start().to_string(),
+            _ => svg.into_owned(),
+        };
+        self.figures.push(ReportFigure {
+            title: title.into(),
+            caption: caption.into(),
+            svg,
+            tables: vec![],
+        });
+        self
+    }
+
+    /// Draw a figure and add it to the report
+    /// - `title`: The title of the figure
+    /// - `caption`: The caption under the figure, the empty caption is omitted
+    /// - `size`: The size of the figure in pixels
+    /// - `draw`: The function that draws the figure
+    pub fn add_figure<T, C, Draw>(
+        &mut self,
+        title: T,
+        caption: C,
+        size: (u32, u32),
+        draw: Draw,
+    ) -> Result<&mut Self, Box<dyn Error>>
+    where
+        T: Into<String>,
+        C: Into<String>,
+        Draw: FnOnce(DrawingArea<SVGBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    {
+        let mut buffer = vec![];
+        draw(SVGBackend::with_buffer(&mut buffer, size).into_drawing_area())?;
+        Ok(self.add_svg(title, caption, &buffer))
+    }
+
+    /// Add the data table of the last figure, which is placed after the figure as the
+    /// accessible alternative to the chart
+    /// - `table`: The table of the data points
+    pub fn add_table(&mut self, table: &DataTable) -> &mut Self {
+        if self.figures.is_empty() {
+            self.add_svg("", "", &[]);
+        }
+        let figure = self.figures.last_mut().unwrap();
+        figure.tables.push(table.to_html());
+        self
+    }
+
+    /// Render the report as a standalone HTML document
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>{}</title>\n", escape_html(&self.title)));
+        html.push_str(&format!("<style>\n{}</style>\n", REPORT_STYLE));
+        html.push_str("</head>\n<body>\n");
+        html.push_str(&format!("<h1>{}</h1>\n", escape_html(&self.title)));
+        for figure in &self.figures {
+            html.push_str("<figure>\n");
+            if !figure.title.is_empty() {
+                html.push_str(&format!("<h2>{}</h2>\n", escape_html(&figure.title)));
+            }
+            if !figure.svg.is_empty() {
+                html.push_str(&figure.svg);
+                html.push('\n');
+            }
+            if !figure.caption.is_empty() {
+                html.push_str(&format!(
+                    "<figcaption>{}</figcaption>\n",
+                    escape_html(&figure.caption)
+                ));
+            }
+            html.push_str("</figure>\n");
+            for table in &figure.tables {
+                html.push_str(table);
+            }
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Write the report into an HTML file
+    /// - `path`: The path of the HTML file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_html())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_html_report() {
+        let mut report = HtmlReport::new("Results & Analysis");
+        report
+            .add_figure("Linear", "y = x for <x> in 0..10", (200, 100), |root| {
+                let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10)?;
+                chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))?;
+                Ok(())
+            })
+            .unwrap();
+        report.add_svg(
+            "",
+            "",
+            b"<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>",
+        );
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Results &amp; Analysis</title>"));
+        assert!(html.contains("<h2>Linear</h2>"));
+        assert!(html.contains("<figcaption>y = x for &lt;x&gt; in 0..10</figcaption>"));
+        assert_eq!(html.matches("<figure>").count(), 2);
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert_eq!(html.matches("<h2>").count(), 1);
+        assert_eq!(html.matches("<figcaption>").count(), 1);
+        assert!(!html.contains("<?xml"));
+
+        let mut table = DataTable::new("Linear");
+        table.add_series("y = x", (0..3).map(|x| (x, x)));
+        report.add_table(&table);
+        let html = report.to_html();
+        assert_eq!(html.matches("<table>").count(), 1);
+        assert!(html.find("</svg>\n</figure>\n<table>").is_some());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::chart::{HasLegendGlyph, LegendGlyph};
 use crate::drawing::DrawingBackend;
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
 use crate::style::colors::TRANSPARENT;
@@ -35,6 +36,12 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
     }
 }
 
+impl<DB: DrawingBackend, X: Clone, Y: Clone> HasLegendGlyph for AreaSeries<DB, X, Y> {
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area(self.area_style.clone())
+    }
+}
+
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
     type Item = DynElement<'static, DB, (X, Y)>;
     fn next(&mut self) -> Option<Self::Item> {
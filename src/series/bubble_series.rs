@@ -0,0 +1,173 @@
+use crate::chart::{HasLegendGlyph, LegendGlyph, DEFAULT_MARKER_SIZE};
+use crate::element::{Circle, MarkerShape};
+use crate::style::ShapeStyle;
+
+/// How the value of a bubble is mapped to its radius
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BubbleScale {
+    /// The area of the bubble is proportional to the value, which is how the readers compare
+    /// the bubbles, so this is the default
+    Area,
+    /// The radius of the bubble is proportional to the value, which exaggerates the large values
+    Radius,
+}
+
+/// The mapping from the value of a bubble to its radius in pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BubbleSize {
+    max_value: f64,
+    max_radius: u32,
+    scale: BubbleScale,
+}
+
+/// Round the value down to the nearest number of the 1-2-5 sequence
+fn nice_floor(value: f64) -> f64 {
+    let base = 10f64.powi(value.log10().floor() as i32);
+    [5.0, 2.0, 1.0]
+        .iter()
+        .map(|m| m * base)
+        .find(|x| *x <= value)
+        .unwrap_or(base)
+}
+
+impl BubbleSize {
+    /// Create the mapping with the area proportional to the value
+    /// - `max_value`: The value which gets the largest bubble, usually the maximum of the data
+    /// - `max_radius`: The radius of the largest bubble in pixels
+    pub fn new(max_value: f64, max_radius: u32) -> Self {
+        Self {
+            max_value,
+            max_radius,
+            scale: BubbleScale::Area,
+        }
+    }
+
+    /// Set how the value is mapped to the radius
+    pub fn scale(mut self, scale: BubbleScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Get the radius of the bubble in pixels, the values which aren't positive get the empty
+    /// bubble
+    /// - `value`: The value of the bubble
+    pub fn radius(&self, value: f64) -> u32 {
+        if value <= 0.0 || self.max_value <= 0.0 {
+            return 0;
+        }
+        let ratio = value / self.max_value;
+        let ratio = match self.scale {
+            BubbleScale::Area => ratio.sqrt(),
+            BubbleScale::Radius => ratio,
+        };
+        (ratio * f64::from(self.max_radius)).round() as u32
+    }
+
+    /// Get the round values for the size legend, from the largest to the smallest
+    pub fn reference_values(&self) -> Vec<f64> {
+        if self.max_value <= 0.0 || !self.max_value.is_finite() {
+            return vec![];
+        }
+        let mut values: Vec<f64> = vec![];
+        for fraction in [1.0, 3.0, 10.0].iter() {
+            let value = nice_floor(self.max_value / fraction);
+            if values.last() != Some(&value) {
+                values.push(value);
+            }
+        }
+        values
+    }
+}
+
+/// The bubble chart series, which draws a circle for each point with the radius encoding the
+/// third value of the point. The legend of the sizes can be drawn with
+/// `ChartContext::draw_bubble_legend`.
+pub struct BubbleSeries<I: Iterator> {
+    data_iter: I,
+    size: BubbleSize,
+    style: ShapeStyle,
+}
+
+impl<I: Iterator> BubbleSeries<I> {
+    /// Create a new bubble series
+    /// - `data`: The iterator of the points and their values
+    /// - `size`: The mapping from the values to the radius
+    /// - `style`: The style of the bubbles
+    pub fn new<T: IntoIterator<IntoIter = I>, S: Into<ShapeStyle>>(
+        data: T,
+        size: BubbleSize,
+        style: S,
+    ) -> Self {
+        Self {
+            data_iter: data.into_iter(),
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<X, Y, V: Into<f64>, I: Iterator<Item = (X, Y, V)>> Iterator for BubbleSeries<I> {
+    type Item = Circle<(X, Y), u32>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data_iter.next()?;
+        Some(Circle::new(
+            (x, y),
+            self.size.radius(value.into()),
+            self.style.clone(),
+        ))
+    }
+}
+
+impl<I: Iterator> HasLegendGlyph for BubbleSeries<I> {
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Marker(self.style.clone(), MarkerShape::Circle, DEFAULT_MARKER_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bubble_size() {
+        let area = BubbleSize::new(100.0, 20);
+        assert_eq!(area.radius(100.0), 20);
+        assert_eq!(area.radius(25.0), 10);
+        assert_eq!(area.radius(-1.0), 0);
+        assert_eq!(area.reference_values(), vec![100.0, 20.0, 10.0]);
+
+        let radius = area.scale(BubbleScale::Radius);
+        assert_eq!(radius.radius(25.0), 5);
+
+        assert_eq!(
+            BubbleSize::new(870.0, 20).reference_values(),
+            vec![500.0, 200.0, 50.0]
+        );
+    }
+
+    #[test]
+    fn test_bubble_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 5);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .right_y_label_area_size(60)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let size = BubbleSize::new(100.0, 10);
+        chart
+            .draw_series(BubbleSeries::new(
+                vec![(1, 1, 1.0), (2, 2, 50.0)],
+                size,
+                RED.filled(),
+            ))
+            .expect("Drawing error");
+        chart.draw_bubble_legend(&size, &RED).expect("Draw legend");
+    }
+}
@@ -0,0 +1,202 @@
+use std::marker::PhantomData;
+use std::vec::IntoIter;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use crate::chart::ChartContext;
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord, SlotSize};
+use crate::drawing::DrawingBackend;
+use crate::element::Rectangle;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// The series of grouped bars, each category slot holds a bar for every group and the bars are
+/// placed side by side within the slot automatically.
+pub struct GroupedBars<'a, BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    A: Default,
+    Tag: HistogramType,
+{
+    style: Box<dyn Fn(usize) -> ShapeStyle + 'a>,
+    slot: SlotSize,
+    fill: f64,
+    gap: f64,
+    groups: usize,
+    iter: IntoIter<(BR::ValueType, BR::ValueType, usize, A)>,
+    br_param: BR::RangeParameter,
+    _p: PhantomData<Tag>,
+}
+
+impl<'a, BR, A, Tag> GroupedBars<'a, BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    A: Default,
+    Tag: HistogramType,
+{
+    fn empty(br_param: BR::RangeParameter, slot: SlotSize) -> Self {
+        Self {
+            style: Box::new(|idx| Palette99::pick(idx).filled()),
+            slot,
+            fill: 0.8,
+            gap: 0.1,
+            groups: 0,
+            iter: vec![].into_iter(),
+            br_param,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the bars using a function of the group index
+    pub fn style_func(mut self, style_func: impl Fn(usize) -> ShapeStyle + 'a) -> Self {
+        self.style = Box::new(style_func);
+        self
+    }
+
+    /// Set the fraction of the category slot occupied by the whole group of bars
+    /// - `fill`: The fraction, 0.8 by default
+    pub fn fill(mut self, fill: f64) -> Self {
+        self.fill = fill.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the fraction of the bar room left empty between the adjacent bars of the group
+    /// - `gap`: The fraction, 0.1 by default
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the data iterator
+    /// - `iter`: The iterator of the categories and the values of each group in the category
+    pub fn data<V: IntoIterator<Item = A>, I: IntoIterator<Item = (BR::ValueType, V)>>(
+        mut self,
+        iter: I,
+    ) -> Self {
+        let mut buffer = vec![];
+        for (key, values) in iter {
+            for (idx, value) in values.into_iter().enumerate() {
+                buffer.push((
+                    BR::previous_value(&key, &self.br_param),
+                    BR::next_value(&key, &self.br_param),
+                    idx,
+                    value,
+                ));
+                self.groups = self.groups.max(idx + 1);
+            }
+        }
+        self.iter = buffer.into_iter();
+        self
+    }
+
+    /// Compute the margins of the bar, which spans over the previous and the next slot, so that
+    /// the bar is narrowed down to its place in the group
+    fn margins(&self, idx: usize) -> (u32, u32) {
+        let size = self.slot.in_pixels();
+        let offset = self.slot.group_offsets(self.groups, self.fill)[idx];
+        let half = self.slot.fraction(self.fill) / self.groups as f64 * (1.0 - self.gap) / 2.0;
+        (
+            (size + offset - half).max(0.0).round() as u32,
+            (size - offset - half).max(0.0).round() as u32,
+        )
+    }
+}
+
+impl<'a, BR, A> GroupedBars<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: Default,
+{
+    /// Create a new grouped bar series for the chart with a discrete X axis
+    pub fn vertical<ACoord, DB: DrawingBackend + 'a>(
+        parent: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        let dp = parent.as_coord_spec().x_spec().get_range_parameter();
+        Self::empty(dp, parent.x_slot_size())
+    }
+}
+
+impl<'a, BR, A> GroupedBars<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: Default,
+{
+    /// Create a new grouped bar series for the chart with a discrete Y axis
+    pub fn horizontal<ACoord, DB: DrawingBackend + 'a>(
+        parent: &ChartContext<DB, RangedCoord<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        let dp = parent.as_coord_spec().y_spec().get_range_parameter();
+        Self::empty(dp, parent.y_slot_size())
+    }
+}
+
+impl<'a, BR, A> Iterator for GroupedBars<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: Default,
+{
+    type Item = Rectangle<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((prev, next, idx, value)) = self.iter.next() {
+            let (l, r) = self.margins(idx);
+            let style = (self.style)(idx);
+            let mut rect = Rectangle::new([(prev, value), (next, A::default())], style);
+            rect.set_margin(0, 0, l, r);
+            return Some(rect);
+        }
+        None
+    }
+}
+
+impl<'a, BR, A> Iterator for GroupedBars<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: Default,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((prev, next, idx, value)) = self.iter.next() {
+            let (t, b) = self.margins(idx);
+            let style = (self.style)(idx);
+            let mut rect = Rectangle::new([(value, prev), (A::default(), next)], style);
+            rect.set_margin(t, b, 0, 0);
+            return Some(rect);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_bars() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let mut expected = vec![(31, 39), (41, 49), (71, 79), (81, 89)].into_iter();
+            m.check_draw_rect(move |_, _, filled, u, d| {
+                let (l, r) = expected.next().unwrap();
+                assert!(filled);
+                assert_eq!((u.0, d.0), (l, r));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let category = Category::new("host", vec!["a", "b", "c"]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(category.range(), 0..10)
+            .expect("Create chart");
+
+        let bars = GroupedBars::vertical(&chart).fill(0.5).gap(0.2).data(vec![
+            (category.get(&"a").unwrap(), vec![3, 5]),
+            (category.get(&"b").unwrap(), vec![4, 2]),
+        ]);
+        chart.draw_series(bars).expect("Drawing error");
+    }
+}
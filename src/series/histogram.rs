@@ -7,7 +7,7 @@ use crate::chart::ChartContext;
 use crate::coord::{DiscreteRanged, Ranged, RangedCoord};
 use crate::drawing::DrawingBackend;
 use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
+use crate::style::{ShapeStyle, GREEN};
 
 pub trait HistogramType {}
 pub struct Vertical;
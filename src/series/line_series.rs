@@ -1,8 +1,28 @@
+use crate::chart::{HasLegendGlyph, LegendGlyph};
 use crate::drawing::DrawingBackend;
 use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
 use crate::style::ShapeStyle;
+use num_traits::NumCast;
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 
+/// The way the line series treats the points which aren't sorted by X or share the same X.
+/// The line is drawn through the points in their order, so such data is drawn as zig-zags.
+/// The points with the same X can also be merged by the mean of their Y values with
+/// `LineSeries::mean_of_duplicates`, which needs numeric Y values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XOrderPolicy {
+    /// Draw the points in the given order, which is the default
+    Keep,
+    /// Sort the points by X, the points with the same X keep their order
+    Sort,
+    /// Sort the points by X and keep only the last one of the points with the same X
+    LastOfDuplicates,
+    /// Draw the points in the given order, but record the points which aren't sorted by X or
+    /// share the same X with the previous point, see `LineSeries::unsorted_points`
+    Warn,
+}
+
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<DB: DrawingBackend, Coord> {
@@ -10,6 +30,7 @@ pub struct LineSeries<DB: DrawingBackend, Coord> {
     data: Vec<Coord>,
     point_idx: usize,
     point_size: u32,
+    unsorted_points: Vec<usize>,
     phantom: PhantomData<DB>,
 }
 
@@ -41,6 +62,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
             data: iter.into_iter().collect(),
             point_size: 0,
             point_idx: 0,
+            unsorted_points: vec![],
             phantom: PhantomData,
         }
     }
@@ -49,12 +71,135 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         self.point_size = size;
         self
     }
+
+    /// Get the indices of the points which aren't sorted by X or share the same X with the
+    /// previous point. They are only found with `XOrderPolicy::Warn`, so that the caller can
+    /// report them before drawing the series.
+    pub fn unsorted_points(&self) -> &[usize] {
+        &self.unsorted_points
+    }
+}
+
+impl<DB: DrawingBackend, X: PartialOrd, Y> LineSeries<DB, (X, Y)> {
+    /// Set how the points which aren't sorted by X or share the same X are treated. The sorting
+    /// policies drop the points whose X can't be ordered, such as NaN.
+    /// - `policy`: The policy, see `XOrderPolicy`
+    pub fn x_order(mut self, policy: XOrderPolicy) -> Self {
+        match policy {
+            XOrderPolicy::Keep => {}
+            XOrderPolicy::Warn => {
+                self.unsorted_points = self
+                    .data
+                    .windows(2)
+                    .enumerate()
+                    .filter(|(_, w)| w[0].0.partial_cmp(&w[1].0) != Some(Ordering::Less))
+                    .map(|(idx, _)| idx + 1)
+                    .collect();
+            }
+            XOrderPolicy::Sort => self.sort_by_x(),
+            XOrderPolicy::LastOfDuplicates => self.merge_duplicates(|mut ys| ys.pop()),
+        }
+        self
+    }
+
+    /// Sort the points by X, the points whose X isn't comparable with itself are dropped, so
+    /// that the ordering is total
+    fn sort_by_x(&mut self) {
+        self.data.retain(|(x, _)| x.partial_cmp(x).is_some());
+        self.data
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    }
+
+    /// Sort the points by X and replace the points with the same X by one point, which is dropped
+    /// if `merge` returns `None`
+    /// - `merge`: The function merging the Y values of the points with the same X
+    fn merge_duplicates<F: FnMut(Vec<Y>) -> Option<Y>>(&mut self, mut merge: F) {
+        self.sort_by_x();
+        let mut groups: Vec<(X, Vec<Y>)> = vec![];
+        for (x, y) in std::mem::take(&mut self.data) {
+            match groups.last_mut() {
+                Some(last) if last.0 == x => last.1.push(y),
+                _ => groups.push((x, vec![y])),
+            }
+        }
+        self.data = groups
+            .into_iter()
+            .filter_map(|(x, ys)| merge(ys).map(|y| (x, y)))
+            .collect();
+    }
+}
+
+impl<DB: DrawingBackend, X: PartialOrd, Y: NumCast> LineSeries<DB, (X, Y)> {
+    /// Sort the points by X and replace the points with the same X by one point with the mean
+    /// of their Y values, which is rounded toward zero for the integer types. Like the sorting
+    /// policies of `x_order`, the points whose X can't be ordered are dropped.
+    pub fn mean_of_duplicates(mut self) -> Self {
+        self.merge_duplicates(|ys| {
+            let count = ys.len() as f64;
+            let sum: f64 = ys
+                .into_iter()
+                .map(|y| num_traits::cast::<Y, f64>(y).unwrap_or(f64::NAN))
+                .sum();
+            num_traits::cast(sum / count)
+        });
+        self
+    }
+}
+
+impl<DB: DrawingBackend, Coord> HasLegendGlyph for LineSeries<DB, Coord> {
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line(self.style.clone())
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::prelude::*;
 
+    #[test]
+    fn test_x_order() {
+        let data = vec![(3, 1.0), (1, 5.0), (2, 2.0), (2, 4.0), (1, 1.0)];
+        let series =
+            |policy| LineSeries::<MockedBackend, _>::new(data.clone(), &RED).x_order(policy);
+
+        assert_eq!(series(XOrderPolicy::Keep).data, data);
+        assert_eq!(series(XOrderPolicy::Warn).data, data);
+        assert_eq!(series(XOrderPolicy::Warn).unsorted_points(), &[1, 3, 4]);
+        assert!(series(XOrderPolicy::Sort).unsorted_points().is_empty());
+        assert_eq!(
+            series(XOrderPolicy::Sort).data,
+            vec![(1, 5.0), (1, 1.0), (2, 2.0), (2, 4.0), (3, 1.0)]
+        );
+        assert_eq!(
+            LineSeries::<MockedBackend, _>::new(data.clone(), &RED)
+                .mean_of_duplicates()
+                .data,
+            vec![(1, 3.0), (2, 3.0), (3, 1.0)]
+        );
+        assert_eq!(
+            series(XOrderPolicy::LastOfDuplicates).data,
+            vec![(1, 1.0), (2, 4.0), (3, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_x_order_non_numeric() {
+        let data = vec![(2.0, "b"), (f64::NAN, "nan"), (1.0, "a"), (2.0, "c")];
+        let series =
+            |policy| LineSeries::<MockedBackend, _>::new(data.clone(), &RED).x_order(policy);
+
+        assert_eq!(series(XOrderPolicy::Warn).unsorted_points(), &[1, 2]);
+        assert_eq!(
+            series(XOrderPolicy::Sort).data,
+            vec![(1.0, "a"), (2.0, "b"), (2.0, "c")]
+        );
+        assert_eq!(
+            series(XOrderPolicy::LastOfDuplicates).data,
+            vec![(1.0, "a"), (2.0, "c")]
+        );
+    }
+
     #[test]
     fn test_line_series() {
         let drawing_area = create_mocked_drawing_area(200, 200, |m| {
@@ -0,0 +1,255 @@
+use std::ops::Range;
+
+use super::LineSeries;
+use crate::chart::ChartContext;
+use crate::coord::{Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::style::ShapeStyle;
+
+/// The resolution that is used to render a level-of-detail series
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LevelOfDetail {
+    /// Every data point in the visible range is rendered
+    Raw,
+    /// Only every n-th data point in the visible range is rendered
+    Decimated(usize),
+    /// The min/max envelope of the n-th pre-aggregated level is rendered
+    Aggregated(usize),
+}
+
+/// A line series that keeps multiple resolutions of the data and picks one of them based on
+/// the pixel density of the currently visible range. This keeps interactive pan/zoom on a very
+/// large data set responsive, since we never send much more points than pixels to the backend.
+///
+/// The data points should be sorted by the X coordinate.
+pub struct LodSeries<X, Y> {
+    raw: Vec<(X, Y)>,
+    levels: Vec<Vec<(X, Y)>>,
+    style: ShapeStyle,
+    decimate_limit: f64,
+    aggregate_limit: f64,
+}
+
+/// Compute the min/max envelope of the data, each chunk of `chunk` points is replaced by its
+/// minimum point and maximum point, in the original order
+fn envelope<X: Clone, Y: PartialOrd + Clone>(data: &[(X, Y)], chunk: usize) -> Vec<(X, Y)> {
+    let mut ret = Vec::with_capacity(data.len() * 2 / chunk + 2);
+    for bucket in data.chunks(chunk) {
+        let (mut min_idx, mut max_idx) = (0, 0);
+        for (idx, (_, y)) in bucket.iter().enumerate() {
+            if *y < bucket[min_idx].1 {
+                min_idx = idx;
+            }
+            if *y > bucket[max_idx].1 {
+                max_idx = idx;
+            }
+        }
+        let (a, b) = (min_idx.min(max_idx), min_idx.max(max_idx));
+        ret.push(bucket[a].clone());
+        if a != b {
+            ret.push(bucket[b].clone());
+        }
+    }
+    ret
+}
+
+/// Take every `step`-th point of the data, the last point is always kept, so that the line
+/// ends at the same place as the raw data does
+fn decimate<T: Clone>(data: &[T], step: usize) -> Vec<T> {
+    let mut ret: Vec<_> = data.iter().step_by(step).cloned().collect();
+    if !data.is_empty() && (data.len() - 1) % step != 0 {
+        ret.extend(data.last().cloned());
+    }
+    ret
+}
+
+/// Find the first index in the sorted data that doesn't satisfy the predicate
+fn partition_point<X, Y, P: Fn(&X) -> bool>(data: &[(X, Y)], pred: P) -> usize {
+    let (mut lo, mut hi) = (0, data.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if pred(&data[mid].0) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Get the part of the data that is visible in the given range, plus one point on each side,
+/// so that the line enters and leaves the plotting area properly
+fn visible_part<'a, X: PartialOrd, Y>(data: &'a [(X, Y)], range: &Range<X>) -> &'a [(X, Y)] {
+    let begin = partition_point(data, |x| *x < range.start).saturating_sub(1);
+    let end = (partition_point(data, |x| *x <= range.end) + 1).min(data.len());
+    if begin >= end {
+        return &data[0..0];
+    }
+    &data[begin..end]
+}
+
+impl<X: PartialOrd + Clone, Y: PartialOrd + Clone> LodSeries<X, Y> {
+    /// Create a new level-of-detail series, all the aggregated levels are computed at this point
+    ///
+    /// - `iter`: The data points, sorted by X
+    /// - `style`: The style of the line
+    /// - **returns**: The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        let raw: Vec<_> = iter.into_iter().collect();
+        let mut levels: Vec<Vec<(X, Y)>> = vec![];
+        let mut last_len = raw.len();
+        while last_len > 4 {
+            let next = envelope(levels.last().map(|l| &l[..]).unwrap_or(&raw[..]), 4);
+            if next.len() >= last_len {
+                break;
+            }
+            last_len = next.len();
+            levels.push(next);
+        }
+        Self {
+            raw,
+            levels,
+            style: style.into(),
+            decimate_limit: 1.0,
+            aggregate_limit: 4.0,
+        }
+    }
+
+    /// Set the maximum number of visible points per pixel that are still rendered as is
+    pub fn decimate_limit(mut self, points_per_pixel: f64) -> Self {
+        self.decimate_limit = points_per_pixel;
+        self
+    }
+
+    /// Set the maximum number of visible points per pixel that are rendered with decimation,
+    /// any denser data is rendered with the pre-aggregated min/max envelope
+    pub fn aggregate_limit(mut self, points_per_pixel: f64) -> Self {
+        self.aggregate_limit = points_per_pixel;
+        self
+    }
+
+    /// Get the number of the pre-aggregated levels
+    pub fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Pick the resolution for the given density
+    ///
+    /// - `visible_points`: The number of raw data points in the visible range
+    /// - `pixel_width`: The width of the plotting area in pixels
+    /// - **returns**: The resolution that should be used
+    pub fn level_of_detail(&self, visible_points: usize, pixel_width: u32) -> LevelOfDetail {
+        let density = visible_points as f64 / f64::from(pixel_width.max(1));
+        if density <= self.decimate_limit {
+            return LevelOfDetail::Raw;
+        }
+        if density <= self.aggregate_limit || self.levels.is_empty() {
+            return LevelOfDetail::Decimated(density.ceil() as usize);
+        }
+        // The level n merges 4 * 2^n raw points into two points, pick the coarsest level which
+        // still has at least one bucket per pixel
+        let mut level = 0;
+        while level + 1 < self.levels.len() && (8usize << level) as f64 <= density {
+            level += 1;
+        }
+        LevelOfDetail::Aggregated(level)
+    }
+
+    /// Create the line series with the resolution that fits the chart
+    ///
+    /// - `chart`: The chart context the series is going to be drawn on
+    /// - **returns**: The line series with the selected resolution
+    pub fn series_for<'a, DB: DrawingBackend + 'a, XR, YR>(
+        &self,
+        chart: &ChartContext<'a, DB, RangedCoord<XR, YR>>,
+    ) -> LineSeries<DB, (X, Y)>
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+    {
+        let range = chart.x_range();
+        let visible = visible_part(&self.raw, &range);
+        let (width, _) = chart.plotting_area().dim_in_pixel();
+        let style = self.style.clone();
+
+        match self.level_of_detail(visible.len(), width) {
+            LevelOfDetail::Raw => LineSeries::new(visible.iter().cloned(), style),
+            LevelOfDetail::Decimated(step) => LineSeries::new(decimate(visible, step), style),
+            LevelOfDetail::Aggregated(level) => LineSeries::new(
+                visible_part(&self.levels[level], &range).iter().cloned(),
+                style,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_level_selection() {
+        let series = LodSeries::new((0..10000).map(|x| (x, x % 7)), &RED);
+        assert!(series.num_levels() > 0);
+        assert_eq!(series.level_of_detail(100, 100), LevelOfDetail::Raw);
+        assert_eq!(
+            series.level_of_detail(300, 100),
+            LevelOfDetail::Decimated(3)
+        );
+        assert_eq!(
+            series.level_of_detail(500, 100),
+            LevelOfDetail::Aggregated(0)
+        );
+        assert_eq!(
+            series.level_of_detail(800, 100),
+            LevelOfDetail::Aggregated(1)
+        );
+        assert_eq!(
+            series.level_of_detail(1600, 100),
+            LevelOfDetail::Aggregated(2)
+        );
+    }
+
+    #[test]
+    fn test_envelope() {
+        let data = [(0, 1), (1, 5), (2, 0), (3, 2), (4, 3)];
+        assert_eq!(envelope(&data, 4), vec![(1, 5), (2, 0), (4, 3)]);
+    }
+
+    #[test]
+    fn test_decimate() {
+        let data = [0, 1, 2, 3, 4, 5];
+        assert_eq!(decimate(&data, 1), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(decimate(&data, 2), vec![0, 2, 4, 5]);
+        assert_eq!(decimate(&data, 5), vec![0, 5]);
+        assert_eq!(decimate(&data[..5], 2), vec![0, 2, 4]);
+        assert!(decimate::<i32>(&[], 3).is_empty());
+
+        // A decimate limit below 1 decimates with the step of 1, which keeps all the points
+        let series = LodSeries::new((0..10).map(|x| (x, x)), &RED).decimate_limit(0.5);
+        assert_eq!(series.level_of_detail(10, 10), LevelOfDetail::Decimated(1));
+    }
+
+    #[test]
+    fn test_lod_series_draw() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() < 1000);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..100000, 0..10)
+            .expect("Build chart error");
+
+        let series = LodSeries::new((0..100000).map(|x| (x, x % 10)), &RED);
+
+        chart
+            .draw_series(series.series_for(&chart))
+            .expect("Drawing Error");
+    }
+}
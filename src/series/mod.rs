@@ -11,11 +11,23 @@
 */
 
 mod area_series;
+mod bubble_series;
+mod grouped_bars;
 mod histogram;
 mod line_series;
+mod lod_series;
 mod point_series;
+mod streaming_series;
+mod strip_series;
+mod survival_series;
 
 pub use area_series::AreaSeries;
+pub use bubble_series::{BubbleScale, BubbleSeries, BubbleSize};
+pub use grouped_bars::GroupedBars;
 pub use histogram::Histogram;
-pub use line_series::LineSeries;
+pub use line_series::{LineSeries, XOrderPolicy};
+pub use lod_series::{LevelOfDetail, LodSeries};
 pub use point_series::PointSeries;
+pub use streaming_series::StreamingLineSeries;
+pub use strip_series::{Jitter, StripSeries};
+pub use survival_series::{SurvivalElement, SurvivalSeries};
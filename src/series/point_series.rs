@@ -1,5 +1,6 @@
-use crate::element::PointElement;
-use crate::style::{ShapeStyle, SizeDesc};
+use crate::chart::{HasLegendGlyph, LegendGlyph};
+use crate::element::{LegendMarker, PointElement};
+use crate::style::{ShapeStyle, SizeDesc, StyleMapper, BLACK};
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
@@ -8,6 +9,7 @@ pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDe
     size: Size,
     data_iter: I::IntoIter,
     make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+    mapper: Option<&'a dyn StyleMapper<Coord>>,
 }
 
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterator
@@ -15,9 +17,12 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterat
 {
     type Item = E;
     fn next(&mut self) -> Option<Self::Item> {
-        self.data_iter
-            .next()
-            .map(|x| (self.make_point)(x, self.size.clone(), self.style.clone()))
+        let x = self.data_iter.next()?;
+        let style = match self.mapper {
+            Some(mapper) => mapper.style(&x),
+            None => self.style.clone(),
+        };
+        Some((self.make_point)(x, self.size.clone(), style))
     }
 }
 
@@ -35,6 +40,20 @@ where
             size,
             style: style.into(),
             make_point: &|a, b, c| E::make_point(a, b, c),
+            mapper: None,
+        }
+    }
+
+    /// Create a new point series which computes the style of each point from the data, e.g.
+    /// with `CategoryStyle` or `ColorMapStyle`. The legend of the mapping can be drawn with
+    /// `ChartContext::draw_mapper_legend`.
+    pub fn mapped<M: StyleMapper<Coord>>(iter: I, size: Size, mapper: &'a M) -> Self {
+        Self {
+            data_iter: iter.into_iter(),
+            size,
+            style: BLACK.filled(),
+            make_point: &|a, b, c| E::make_point(a, b, c),
+            mapper: Some(mapper),
         }
     }
 }
@@ -56,6 +75,17 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
             size,
             style: style.into(),
             make_point: cons,
+            mapper: None,
         }
     }
 }
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> HasLegendGlyph
+    for PointSeries<'a, Coord, I, E, Size>
+where
+    E: LegendMarker,
+{
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::marker(self.style.clone(), E::SHAPE, &self.size)
+    }
+}
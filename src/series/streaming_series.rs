@@ -0,0 +1,90 @@
+use crate::chart::{HasLegendGlyph, LegendGlyph};
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/// The streaming line series. Unlike `LineSeries`, which collects all the points before
+/// drawing, this series consumes the data iterator lazily and emits one path element for each
+/// fixed-size chunk of points. Each chunk is sent to the backend before the next chunk is read,
+/// so only a single chunk is ever held in memory, which allows drawing a huge series read from
+/// disk.
+///
+/// Consecutive chunks share their boundary point, thus the rendered line is continuous.
+pub struct StreamingLineSeries<I: Iterator> {
+    source: I,
+    last: Option<I::Item>,
+    chunk_size: usize,
+    style: ShapeStyle,
+}
+
+impl<I: Iterator> StreamingLineSeries<I> {
+    /// Create a new streaming line series
+    ///
+    /// - `iter`: The iterator of the points, it's only consumed while the series is drawn
+    /// - `style`: The style of the line
+    /// - **returns**: The newly created series
+    pub fn new<T: IntoIterator<IntoIter = I>, S: Into<ShapeStyle>>(iter: T, style: S) -> Self {
+        Self {
+            source: iter.into_iter(),
+            last: None,
+            chunk_size: 4096,
+            style: style.into(),
+        }
+    }
+
+    /// Set the number of points that are read and drawn at once
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        self.chunk_size = size.max(1);
+        self
+    }
+}
+
+impl<I: Iterator> HasLegendGlyph for StreamingLineSeries<I> {
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line(self.style.clone())
+    }
+}
+
+impl<I: Iterator> Iterator for StreamingLineSeries<I>
+where
+    I::Item: Clone,
+{
+    type Item = PathElement<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut points = Vec::with_capacity(self.chunk_size + 1);
+        points.extend(self.last.take());
+        let prev_len = points.len();
+        points.extend(self.source.by_ref().take(self.chunk_size));
+        if points.len() == prev_len {
+            return None;
+        }
+        self.last = points.last().cloned();
+        Some(PathElement::new(points, self.style.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_streaming_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() <= 101);
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 10);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..1000, 0..1000)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(StreamingLineSeries::new((0..1000).map(|x| (x, x)), &RED).chunk_size(100))
+            .expect("Drawing Error");
+    }
+}
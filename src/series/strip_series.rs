@@ -0,0 +1,230 @@
+use std::marker::PhantomData;
+use std::vec::IntoIter;
+
+use crate::chart::{ChartContext, HasLegendGlyph, LegendGlyph};
+use crate::coord::{DiscreteRanged, Ranged, RangedCoord, SlotSize};
+use crate::drawing::backend::BackendCoord;
+use crate::drawing::DrawingBackend;
+use crate::element::{BoxedElement, Circle, EmptyElement, MarkerShape};
+use crate::style::ShapeStyle;
+
+/// The pseudo-random number generator (SplitMix64) used by the jitter, which gives the same
+/// sequence for the same seed on every platform
+fn next_random(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// The layout which spreads the points of the same category over the category slot, so that
+/// the overlapping points can be told apart
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Jitter {
+    /// Offset the points randomly within the `width` fraction of the slot, the same `seed`
+    /// always gives the same layout
+    Random { width: f64, seed: u64 },
+    /// Pack the points side by side within the `width` fraction of the slot, so that the
+    /// markers don't overlap as long as there's enough room, which is known as the beeswarm plot
+    Swarm { width: f64 },
+}
+
+impl Jitter {
+    /// Compute the pixel offsets of the points of one category, perpendicular to the value axis
+    /// - `group`: The index of the category, which makes the random offsets differ between the
+    ///   categories
+    /// - `slot`: The size of the category slot
+    /// - `spacing`: The minimal distance between the centers of two points in pixels
+    /// - `positions`: The pixel positions of the points along the value axis
+    /// - **returns**: The offsets relative to the center of the slot
+    pub fn offsets(
+        &self,
+        group: usize,
+        slot: SlotSize,
+        spacing: f64,
+        positions: &[i32],
+    ) -> Vec<i32> {
+        match *self {
+            Jitter::Random { width, seed } => {
+                let mut state = seed ^ (group as u64).wrapping_mul(0xD1B5_4A32_D192_ED03);
+                let width = slot.fraction(width);
+                positions
+                    .iter()
+                    .map(|_| ((next_random(&mut state) - 0.5) * width).round() as i32)
+                    .collect()
+            }
+            Jitter::Swarm { width } => {
+                let spacing = spacing.max(1.0);
+                let columns = (slot.fraction(width) / 2.0 / spacing).floor().max(0.0) as i64;
+
+                let mut order: Vec<_> = (0..positions.len()).collect();
+                order.sort_by_key(|&idx| positions[idx]);
+
+                let mut placed: Vec<(i64, i32)> = vec![];
+                let mut offsets = vec![0; positions.len()];
+                for idx in order {
+                    let pos = positions[idx];
+                    // Try the columns from the center outward: 0, 1, -1, 2, -2, ...
+                    let mut best = (0, -1.0);
+                    for k in 0..=columns * 2 {
+                        let column = if k % 2 == 1 { (k + 1) / 2 } else { -k / 2 };
+                        let gap = placed
+                            .iter()
+                            .filter(|(c, _)| *c == column)
+                            .map(|(_, p)| f64::from((p - pos).abs()))
+                            .fold(f64::INFINITY, f64::min);
+                        if gap > best.1 {
+                            best = (column, gap);
+                        }
+                        if gap >= spacing {
+                            break;
+                        }
+                    }
+                    placed.push((best.0, pos));
+                    offsets[idx] = (best.0 as f64 * spacing).round() as i32;
+                }
+                offsets
+            }
+        }
+    }
+}
+
+/// The strip plot series, which draws the values of each category as circles spread over the
+/// category slot by the `Jitter` layout
+pub struct StripSeries<BR: DiscreteRanged, A, DB: DrawingBackend> {
+    iter: IntoIter<(BR::ValueType, A, i32)>,
+    size: u32,
+    style: ShapeStyle,
+    _p: PhantomData<DB>,
+}
+
+impl<BR, A, DB> StripSeries<BR, A, DB>
+where
+    BR: DiscreteRanged,
+    BR::ValueType: Clone,
+    A: Clone,
+    DB: DrawingBackend,
+{
+    /// Create a new strip series for the chart with a discrete X axis
+    /// - `parent`: The chart to draw on
+    /// - `jitter`: The layout of the points in the category slot
+    /// - `size`: The radius of the circles
+    /// - `style`: The style of the circles
+    /// - `data`: The iterator of the categories and the values in the category
+    pub fn vertical<ACoord, V, I, S>(
+        parent: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+        jitter: Jitter,
+        size: u32,
+        style: S,
+        data: I,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+        V: IntoIterator<Item = A>,
+        I: IntoIterator<Item = (BR::ValueType, V)>,
+        S: Into<ShapeStyle>,
+    {
+        let slot = parent.x_slot_size();
+        let mut buffer = vec![];
+        for (group, (key, values)) in data.into_iter().enumerate() {
+            let values: Vec<_> = values.into_iter().collect();
+            let positions: Vec<_> = values
+                .iter()
+                .map(|value| parent.backend_coord(&(key.clone(), value.clone())).1)
+                .collect();
+            let offsets = jitter.offsets(group, slot, f64::from(size * 2), &positions);
+            for (value, offset) in values.into_iter().zip(offsets) {
+                buffer.push((key.clone(), value, offset));
+            }
+        }
+        Self {
+            iter: buffer.into_iter(),
+            size,
+            style: style.into(),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<BR, A, DB> Iterator for StripSeries<BR, A, DB>
+where
+    BR: DiscreteRanged,
+    DB: DrawingBackend,
+{
+    type Item = BoxedElement<(BR::ValueType, A), DB, Circle<BackendCoord, u32>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value, offset) = self.iter.next()?;
+        Some(
+            EmptyElement::at((key, value))
+                + Circle::new((offset, 0), self.size, self.style.clone()),
+        )
+    }
+}
+
+impl<BR, A, DB> HasLegendGlyph for StripSeries<BR, A, DB>
+where
+    BR: DiscreteRanged,
+    DB: DrawingBackend,
+{
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::marker(self.style.clone(), MarkerShape::Circle, &self.size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_jitter_offsets() {
+        let slot = SlotSize::new(60.0);
+        let swarm = Jitter::Swarm { width: 0.8 };
+        assert_eq!(
+            swarm.offsets(0, slot, 6.0, &[100, 100, 100, 150]),
+            vec![0, 6, -6, 0]
+        );
+
+        let random = Jitter::Random {
+            width: 0.5,
+            seed: 42,
+        };
+        let offsets = random.offsets(0, slot, 6.0, &[0; 20]);
+        assert!(offsets.iter().all(|x| x.abs() <= 15));
+        assert_eq!(offsets, random.offsets(0, slot, 6.0, &[0; 20]));
+        assert_ne!(offsets, random.offsets(1, slot, 6.0, &[0; 20]));
+    }
+
+    #[test]
+    fn test_strip_series() {
+        let centers = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let drawn = centers.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_circle(move |_, _, _, center, _| {
+                drawn.borrow_mut().push(center);
+            });
+        });
+
+        let category = Category::new("group", vec!["a", "b"]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(category.range(), 0..10)
+            .expect("Create chart");
+
+        let series = StripSeries::vertical(
+            &chart,
+            Jitter::Swarm { width: 0.8 },
+            2,
+            RED.filled(),
+            vec![(category.get(&"a").unwrap(), vec![5, 5, 5])],
+        );
+        chart.draw_series(series).expect("Drawing error");
+
+        let centers = centers.borrow();
+        assert_eq!(centers.len(), 3);
+        assert_eq!(centers[1].0 - centers[0].0, 4);
+        assert_eq!(centers[0].0 - centers[2].0, 4);
+        assert!(centers.iter().all(|c| c.1 == centers[0].1));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::chart::{HasLegendGlyph, LegendGlyph};
+use crate::data::{KaplanMeier, SurvivalStep};
+use crate::drawing::backend::{BackendCoord, DrawingErrorKind};
+use crate::drawing::DrawingBackend;
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+/// Get the vertices of the step curve of a value of the survival steps, which is extended to
+/// the end of the observations
+fn step_path<F: Fn(&SurvivalStep) -> f64>(
+    steps: &[SurvivalStep],
+    end: f64,
+    value: F,
+) -> Vec<(f64, f64)> {
+    let mut path: Vec<(f64, f64)> = vec![];
+    for step in steps {
+        if let Some(&(_, last)) = path.last() {
+            path.push((step.time, last));
+        }
+        path.push((step.time, value(step)));
+    }
+    if let Some(&(time, last)) = path.last() {
+        if end > time {
+            path.push((end, last));
+        }
+    }
+    path
+}
+
+/// The element of the survival curve series
+pub enum SurvivalElement {
+    /// The confidence band, which is a polygon around the curve
+    Band(Vec<(f64, f64)>, ShapeStyle),
+    /// The step curve
+    Curve(Vec<(f64, f64)>, ShapeStyle),
+    /// The vertical tick mark of a censored observation with its half length in pixels
+    Censored((f64, f64), i32, ShapeStyle),
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a SurvivalElement {
+    type Borrow = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        match self {
+            SurvivalElement::Band(points, _) | SurvivalElement::Curve(points, _) => points,
+            SurvivalElement::Censored(point, _, _) => std::slice::from_ref(point),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for SurvivalElement {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self {
+            SurvivalElement::Band(_, style) => backend.fill_polygon(points, &style.color),
+            SurvivalElement::Curve(_, style) => backend.draw_path(points, style),
+            SurvivalElement::Censored(_, size, style) => match points.next() {
+                Some((x, y)) => backend.draw_path(vec![(x, y - size), (x, y + size)], style),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// The survival curve series, which draws the Kaplan–Meier estimate as a step curve with the
+/// tick marks at the censored observations and optionally the confidence band under the curve
+pub struct SurvivalSeries {
+    curve: Vec<(f64, f64)>,
+    band: Vec<(f64, f64)>,
+    band_style: Option<ShapeStyle>,
+    censored: Vec<(f64, f64)>,
+    mark_size: i32,
+    style: ShapeStyle,
+    state: usize,
+}
+
+impl SurvivalSeries {
+    /// Create a new survival curve series
+    /// - `estimate`: The estimated survival function
+    /// - `style`: The style of the curve and the censoring marks
+    pub fn new<S: Into<ShapeStyle>>(estimate: &KaplanMeier, style: S) -> Self {
+        let steps = estimate.steps();
+        let end = estimate.end();
+        let mut band = step_path(steps, end, |step| step.upper);
+        band.extend(step_path(steps, end, |step| step.lower).into_iter().rev());
+        Self {
+            curve: step_path(steps, end, |step| step.survival),
+            band,
+            band_style: None,
+            censored: estimate.censored().to_vec(),
+            mark_size: 4,
+            style: style.into(),
+            state: 0,
+        }
+    }
+
+    /// Draw the confidence band with the given style, which is usually a translucent fill
+    pub fn confidence_band<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band_style = Some(style.into());
+        self
+    }
+
+    /// Set the half length of the censoring tick marks in pixels, zero hides the marks
+    pub fn mark_size(mut self, size: i32) -> Self {
+        self.mark_size = size;
+        self
+    }
+}
+
+impl HasLegendGlyph for SurvivalSeries {
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line(self.style.clone())
+    }
+}
+
+impl Iterator for SurvivalSeries {
+    type Item = SurvivalElement;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let state = self.state;
+            self.state += 1;
+            match state {
+                0 => {
+                    if let Some(style) = self.band_style.clone() {
+                        let band = std::mem::take(&mut self.band);
+                        return Some(SurvivalElement::Band(band, style));
+                    }
+                }
+                1 => {
+                    let curve = std::mem::take(&mut self.curve);
+                    return Some(SurvivalElement::Curve(curve, self.style.clone()));
+                }
+                _ => {
+                    let idx = state - 2;
+                    if self.mark_size <= 0 || idx >= self.censored.len() {
+                        return None;
+                    }
+                    return Some(SurvivalElement::Censored(
+                        self.censored[idx],
+                        self.mark_size,
+                        self.style.clone(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_step_path() {
+        let km = KaplanMeier::estimate(vec![(1.0, true), (1.0, true), (2.0, true), (3.0, false)]);
+        assert_eq!(
+            step_path(km.steps(), km.end(), |step| step.survival),
+            vec![
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (1.0, 0.5),
+                (2.0, 0.5),
+                (2.0, 0.25),
+                (3.0, 0.25)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_survival_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // The curve and two censoring marks
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0.0..1.0)
+            .expect("Create chart");
+
+        let km = KaplanMeier::estimate(vec![(1.0, true), (2.0, false), (5.0, true), (8.0, false)]);
+        chart
+            .draw_series(SurvivalSeries::new(&km, &BLUE).confidence_band(BLUE.mix(0.2).filled()))
+            .expect("Drawing error")
+            .label("treatment")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+The snapshot diff, which renders two versions of a figure and compares them pixel by pixel.
+This is useful to review the changes of the generated charts, e.g. the day-over-day changes
+of a report, or to check that a figure is still rendered the same way.
+*/
+use crate::coord::Shift;
+use crate::drawing::backend::BackendCoord;
+use crate::drawing::{BitMapBackend, DrawingArea, IntoDrawingArea};
+
+use std::error::Error;
+
+/// The color of the changed pixels in the overlay
+const HIGHLIGHT: [u8; 3] = [255, 0, 0];
+
+/// The weight of the figure in the faded unchanged pixels of the overlay
+const FADE: u32 = 64;
+
+/// The result of comparing two RGB images of the same size
+pub struct SnapshotDiff {
+    size: (u32, u32),
+    changed: usize,
+    bounds: Option<(BackendCoord, BackendCoord)>,
+    overlay: Vec<u8>,
+}
+
+impl SnapshotDiff {
+    /// Compare two RGB images, the pixels are considered changed if any channel differs by
+    /// more than `tolerance`
+    /// - `size`: The size of the images in pixels
+    /// - `before`: The RGB buffer of the old image
+    /// - `after`: The RGB buffer of the new image
+    /// - `tolerance`: The largest channel difference of the unchanged pixels, which allows to
+    ///   ignore the anti-aliasing noise
+    pub fn compare(size: (u32, u32), before: &[u8], after: &[u8], tolerance: u8) -> Self {
+        let (w, h) = size;
+        let len = (w * h) as usize * 3;
+        assert!(
+            before.len() >= len && after.len() >= len,
+            "The buffer is smaller than the image"
+        );
+
+        let mut changed = 0;
+        let mut bounds: Option<(BackendCoord, BackendCoord)> = None;
+        let mut overlay = Vec::with_capacity(len);
+
+        for (idx, (old, new)) in before[..len]
+            .chunks(3)
+            .zip(after[..len].chunks(3))
+            .enumerate()
+        {
+            let is_changed = old
+                .iter()
+                .zip(new.iter())
+                .any(|(a, b)| (i32::from(*a) - i32::from(*b)).abs() > i32::from(tolerance));
+            if is_changed {
+                let (x, y) = ((idx as u32 % w) as i32, (idx as u32 / w) as i32);
+                changed += 1;
+                bounds = Some(match bounds {
+                    Some(((x0, y0), (x1, y1))) => ((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y))),
+                    None => ((x, y), (x, y)),
+                });
+                overlay.extend_from_slice(&HIGHLIGHT);
+            } else {
+                // Fade the unchanged pixels towards white, so that the changes stand out
+                overlay.extend(
+                    new.iter()
+                        .map(|c| ((u32::from(*c) * FADE + 255 * (256 - FADE)) / 256) as u8),
+                );
+            }
+        }
+
+        Self {
+            size,
+            changed,
+            bounds,
+            overlay,
+        }
+    }
+
+    /// Get the size of the compared images
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Check if no pixel is changed
+    pub fn is_identical(&self) -> bool {
+        self.changed == 0
+    }
+
+    /// Get the number of the changed pixels
+    pub fn changed_pixels(&self) -> usize {
+        self.changed
+    }
+
+    /// Get the fraction of the changed pixels
+    pub fn changed_ratio(&self) -> f64 {
+        let (w, h) = self.size;
+        if w == 0 || h == 0 {
+            return 0.0;
+        }
+        self.changed as f64 / (f64::from(w) * f64::from(h))
+    }
+
+    /// Get the upper left and the lower right corners of the smallest rectangle containing all
+    /// the changed pixels, `None` if the images are identical
+    pub fn changed_bounds(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.bounds
+    }
+
+    /// Get the RGB buffer of the overlay image, which is the new image faded out with the
+    /// changed pixels highlighted in red
+    pub fn overlay(&self) -> &[u8] {
+        &self.overlay
+    }
+
+    /// Take the RGB buffer of the overlay image
+    pub fn into_overlay(self) -> Vec<u8> {
+        self.overlay
+    }
+
+    /// Save the overlay image into a file, the format is determined by the extension
+    /// - `path`: The path of the image file
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn save_overlay<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+        let (w, h) = self.size;
+        image::save_buffer(path, &self.overlay, w, h, image::ColorType::RGB(8))?;
+        Ok(())
+    }
+}
+
+/// Render an image with the bitmap backend
+fn render<Draw>(size: (u32, u32), draw: Draw) -> Result<Vec<u8>, Box<dyn Error>>
+where
+    Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+{
+    let mut buffer = vec![0; (size.0 * size.1) as usize * 3];
+    draw(BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area())?;
+    Ok(buffer)
+}
+
+/// Render two versions of a figure and compare them. The figures are usually drawn by the same
+/// function with two datasets.
+/// - `size`: The size of the figures in pixels
+/// - `tolerance`: The largest channel difference of the unchanged pixels
+/// - `before`: The function that draws the old figure
+/// - `after`: The function that draws the new figure
+/// - **returns**: The comparison of the figures
+pub fn snapshot_diff<Before, After>(
+    size: (u32, u32),
+    tolerance: u8,
+    before: Before,
+    after: After,
+) -> Result<SnapshotDiff, Box<dyn Error>>
+where
+    Before: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    After: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+{
+    let before = render(size, before)?;
+    let after = render(size, after)?;
+    Ok(SnapshotDiff::compare(size, &before, &after, tolerance))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn draw_bar(
+        height: u32,
+    ) -> impl FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>> {
+        move |root| {
+            root.fill(&WHITE)?;
+            root.draw(&Rectangle::new(
+                [(10, 50 - height as i32), (20, 50)],
+                BLUE.filled(),
+            ))?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let same = snapshot_diff((50, 50), 0, draw_bar(10), draw_bar(10)).unwrap();
+        assert!(same.is_identical());
+        assert_eq!(same.changed_bounds(), None);
+
+        let diff = snapshot_diff((50, 50), 0, draw_bar(10), draw_bar(20)).unwrap();
+        assert!(!diff.is_identical());
+        assert_eq!(diff.changed_bounds(), Some(((10, 30), (20, 39))));
+        assert_eq!(diff.changed_pixels(), 11 * 10);
+        assert_eq!(diff.overlay().len(), 50 * 50 * 3);
+        assert_eq!(&diff.overlay()[(35 * 50 + 15) * 3..][..3], &HIGHLIGHT);
+        assert_eq!(&diff.overlay()[..3], &[255, 255, 255]);
+    }
+}
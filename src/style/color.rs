@@ -1,3 +1,4 @@
+use super::interpolation::{interpolate, ColorSpace};
 use super::palette::Palette;
 use super::ShapeStyle;
 
@@ -25,6 +26,28 @@ pub trait Color {
         RGBAColor(r, g, b, a)
     }
 
+    /// Interpolate between this color and the other color in the sRGB color space
+    /// - `other`: The color when `t` is 1
+    /// - `t`: The interpolation parameter, clamped to [0, 1]
+    fn lerp<C: Color>(&self, other: &C, t: f64) -> RGBAColor
+    where
+        Self: Sized,
+    {
+        self.lerp_in(other, t, ColorSpace::Srgb)
+    }
+
+    /// Interpolate between this color and the other color in the given color space.
+    /// Use `ColorSpace::Lab` or `ColorSpace::Hcl` for perceptually uniform gradients.
+    /// - `other`: The color when `t` is 1
+    /// - `t`: The interpolation parameter, clamped to [0, 1]
+    /// - `space`: The color space used for the interpolation
+    fn lerp_in<C: Color>(&self, other: &C, t: f64, space: ColorSpace) -> RGBAColor
+    where
+        Self: Sized,
+    {
+        interpolate(&self.to_rgba(), &other.to_rgba(), t, space)
+    }
+
     /// Make a filled style form the color
     fn filled(&self) -> ShapeStyle
     where
@@ -47,6 +70,32 @@ pub trait Color {
 #[derive(Clone, PartialEq, Debug)]
 pub struct RGBAColor(pub(super) u8, pub(super) u8, pub(super) u8, pub(super) f64);
 
+impl RGBAColor {
+    /// Create a new RGBA color, this can be used to define color constants
+    pub const fn new(r: u8, g: u8, b: u8, a: f64) -> Self {
+        RGBAColor(r, g, b, a)
+    }
+
+    /// Make a filled style from the color, this can be used to define style constants
+    pub const fn filled(&self) -> ShapeStyle {
+        ShapeStyle {
+            color: RGBAColor(self.0, self.1, self.2, self.3),
+            filled: true,
+            stroke_width: 1,
+        }
+    }
+
+    /// Make a shape style with stroke width from the color, this can be used to define style
+    /// constants
+    pub const fn stroke_width(&self, width: u32) -> ShapeStyle {
+        ShapeStyle {
+            color: RGBAColor(self.0, self.1, self.2, self.3),
+            filled: false,
+            stroke_width: width,
+        }
+    }
+}
+
 impl Color for RGBAColor {
     #[inline(always)]
     fn rgb(&self) -> (u8, u8, u8) {
@@ -95,9 +144,32 @@ impl<P: Palette> SimpleColor for PaletteColor<P> {
 }
 
 /// The color described by its RGB value
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RGBColor(pub u8, pub u8, pub u8);
 
+impl RGBColor {
+    /// Mix the color with given opacity, this can be used to define color constants
+    pub const fn mix(&self, value: f64) -> RGBAColor {
+        RGBAColor(self.0, self.1, self.2, value)
+    }
+
+    /// Convert the color into the RGBA color, this can be used to define color constants
+    pub const fn to_rgba(&self) -> RGBAColor {
+        RGBAColor(self.0, self.1, self.2, 1.0)
+    }
+
+    /// Make a filled style from the color, this can be used to define style constants
+    pub const fn filled(&self) -> ShapeStyle {
+        self.to_rgba().filled()
+    }
+
+    /// Make a shape style with stroke width from the color, this can be used to define style
+    /// constants
+    pub const fn stroke_width(&self, width: u32) -> ShapeStyle {
+        self.to_rgba().stroke_width(width)
+    }
+}
+
 impl SimpleColor for RGBColor {
     fn rgb(&self) -> (u8, u8, u8) {
         (self.0, self.1, self.2)
@@ -10,7 +10,7 @@ pub type FontError = <FontDataInternal as FontData>::ErrorType;
 pub type FontResult<T> = Result<T, FontError>;
 
 /// Specifying text transformations
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum FontTransform {
     /// Nothing to transform
     None,
@@ -20,6 +20,10 @@ pub enum FontTransform {
     Rotate180,
     /// Rotating the text 270 degree clockwise
     Rotate270,
+    /// The vertical writing mode, the characters are stacked from top to bottom without being
+    /// rotated, which is used for the CJK text. Each character takes a square cell of the font
+    /// size and the characters are centered in the column.
+    Vertical,
 }
 
 impl FontTransform {
@@ -30,7 +34,7 @@ impl FontTransform {
     /// - **returns**: The offset in pixels
     pub fn offset(&self, layout: LayoutBox) -> (i32, i32) {
         match self {
-            FontTransform::None => (0, 0),
+            FontTransform::None | FontTransform::Vertical => (0, 0),
             FontTransform::Rotate90 => ((layout.1).1 - (layout.0).1, 0),
             FontTransform::Rotate180 => ((layout.1).0 - (layout.0).0, (layout.1).1 - (layout.0).1),
             FontTransform::Rotate270 => (0, (layout.1).0 - (layout.0).0),
@@ -44,7 +48,7 @@ impl FontTransform {
     /// - **returns**: The coordinate after transform
     pub fn transform(&self, x: i32, y: i32) -> (i32, i32) {
         match self {
-            FontTransform::None => (x, y),
+            FontTransform::None | FontTransform::Vertical => (x, y),
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
@@ -289,6 +293,16 @@ impl<'a> FontDesc<'a> {
     /// This is similar to `layout_box` function, but it apply the font transformation
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
+        if let FontTransform::Vertical = self.transform {
+            let mut width = 0;
+            let mut count = 0;
+            for c in text.chars() {
+                let ((min_x, _), (max_x, _)) = self.layout_box(c.encode_utf8(&mut [0; 4]))?;
+                width = width.max(max_x - min_x);
+                count += 1;
+            }
+            return Ok((width as u32, (self.size * f64::from(count)).round() as u32));
+        }
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
         let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
         Ok((w.abs() as u32, h.abs() as u32))
@@ -0,0 +1,183 @@
+/*!
+  The color interpolation in different color spaces.
+*/
+use super::color::RGBAColor;
+
+/// The color space in which the colors are interpolated
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorSpace {
+    /// Interpolate the gamma-encoded sRGB components directly
+    Srgb,
+    /// Interpolate in the CIE L*a*b* space, which is perceptually uniform
+    Lab,
+    /// Interpolate in the cylindrical form of CIE L*a*b* (lightness, chroma and hue), the hue
+    /// takes the shortest way around the color wheel
+    Hcl,
+}
+
+/// The D65 reference white
+const WHITE_POINT: (f64, f64, f64) = (0.950_47, 1.0, 1.088_83);
+
+fn to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f64) -> u8 {
+    let c = if c <= 0.003_130_8 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+pub(super) fn rgb_to_lab((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (to_linear(r), to_linear(g), to_linear(b));
+    let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+    let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (
+        f(x / WHITE_POINT.0),
+        f(y / WHITE_POINT.1),
+        f(z / WHITE_POINT.2),
+    );
+
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+pub(super) fn lab_to_rgb((l, a, b): (f64, f64, f64)) -> (u8, u8, u8) {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let f_inv = |t: f64| {
+        let t3 = t * t * t;
+        if t3 > 216.0 / 24389.0 {
+            t3
+        } else {
+            (116.0 * t - 16.0) * 27.0 / 24389.0
+        }
+    };
+    let (x, y, z) = (
+        f_inv(fx) * WHITE_POINT.0,
+        f_inv(fy) * WHITE_POINT.1,
+        f_inv(fz) * WHITE_POINT.2,
+    );
+
+    let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+    let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z;
+    let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+
+    (from_linear(r), from_linear(g), from_linear(b))
+}
+
+fn mix(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Interpolate between two colors
+///
+/// - `from`: The color when `t` is 0
+/// - `to`: The color when `t` is 1
+/// - `t`: The interpolation parameter, clamped to [0, 1]
+/// - `space`: The color space used for the interpolation
+/// - **returns**: The interpolated color
+pub(super) fn interpolate(
+    from: &RGBAColor,
+    to: &RGBAColor,
+    t: f64,
+    space: ColorSpace,
+) -> RGBAColor {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let alpha = mix(from.3, to.3, t);
+
+    let (r, g, b) = match space {
+        ColorSpace::Srgb => (
+            mix(f64::from(from.0), f64::from(to.0), t).round() as u8,
+            mix(f64::from(from.1), f64::from(to.1), t).round() as u8,
+            mix(f64::from(from.2), f64::from(to.2), t).round() as u8,
+        ),
+        ColorSpace::Lab => {
+            let (l0, a0, b0) = rgb_to_lab((from.0, from.1, from.2));
+            let (l1, a1, b1) = rgb_to_lab((to.0, to.1, to.2));
+            lab_to_rgb((mix(l0, l1, t), mix(a0, a1, t), mix(b0, b1, t)))
+        }
+        ColorSpace::Hcl => {
+            let (l0, a0, b0) = rgb_to_lab((from.0, from.1, from.2));
+            let (l1, a1, b1) = rgb_to_lab((to.0, to.1, to.2));
+            let (c0, c1) = (a0.hypot(b0), a1.hypot(b1));
+            let (mut h0, mut h1) = (b0.atan2(a0), b1.atan2(a1));
+
+            // An achromatic color doesn't have a meaningful hue, so use the hue of the other one
+            const EPS: f64 = 1e-3;
+            if c0 < EPS {
+                h0 = h1;
+            }
+            if c1 < EPS {
+                h1 = h0;
+            }
+
+            let mut dh = h1 - h0;
+            if dh > std::f64::consts::PI {
+                dh -= 2.0 * std::f64::consts::PI;
+            } else if dh < -std::f64::consts::PI {
+                dh += 2.0 * std::f64::consts::PI;
+            }
+
+            let (l, c, h) = (mix(l0, l1, t), mix(c0, c1, t), h0 + dh * t);
+            lab_to_rgb((l, c * h.cos(), c * h.sin()))
+        }
+    };
+
+    RGBAColor(r, g, b, alpha)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lab_round_trip() {
+        for &color in &[(0, 0, 0), (255, 255, 255), (255, 0, 0), (12, 200, 77)] {
+            assert_eq!(lab_to_rgb(rgb_to_lab(color)), color);
+        }
+        let (l, _, _) = rgb_to_lab((255, 255, 255));
+        assert!((l - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let black = RGBAColor(0, 0, 0, 1.0);
+        let white = RGBAColor(255, 255, 255, 0.0);
+
+        for &space in &[ColorSpace::Srgb, ColorSpace::Lab, ColorSpace::Hcl] {
+            assert_eq!(interpolate(&black, &white, 0.0, space), black);
+            assert_eq!(interpolate(&black, &white, 1.0, space), white);
+            assert_eq!(interpolate(&black, &white, 2.0, space), white);
+        }
+
+        assert_eq!(
+            interpolate(&black, &white, 0.5, ColorSpace::Srgb),
+            RGBAColor(128, 128, 128, 0.5)
+        );
+
+        // The perceptual middle gray is darker than the arithmetic one
+        let gray = interpolate(&black, &white, 0.5, ColorSpace::Lab);
+        for &c in &[gray.0, gray.1, gray.2] {
+            assert!(c > 110 && c < 128);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use super::color::{Color, RGBAColor};
+use super::interpolation::ColorSpace;
+use super::palette::Palette;
+use super::ShapeStyle;
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// The number of colors sampled from a color map for the colorbar
+const COLORBAR_STOPS: usize = 64;
+
+/// The legend describing how the data is mapped to the styles
+#[derive(Clone)]
+pub enum MapperLegend {
+    /// The mapping can't be described in the legend
+    None,
+    /// The discrete swatches, each of them is a label and the style of the matching elements
+    Swatches(Vec<(String, ShapeStyle)>),
+    /// The continuous colorbar, the colors are evenly sampled from `min` to `max`
+    ColorBar {
+        min: f64,
+        max: f64,
+        colors: Vec<RGBAColor>,
+    },
+}
+
+/// The mapping from a datum to the style of its element, e.g. the color by the category or by
+/// the magnitude of the value. Any `Fn(&T) -> ShapeStyle` closure is a style mapper without
+/// a legend.
+pub trait StyleMapper<T> {
+    /// Get the style of the element of the datum
+    fn style(&self, datum: &T) -> ShapeStyle;
+
+    /// Describe the mapping for the legend
+    fn legend(&self) -> MapperLegend {
+        MapperLegend::None
+    }
+}
+
+impl<T, F: Fn(&T) -> ShapeStyle> StyleMapper<T> for F {
+    fn style(&self, datum: &T) -> ShapeStyle {
+        self(datum)
+    }
+}
+
+/// The style mapper which picks a palette color for each category of the data
+pub struct CategoryStyle<P: Palette, K, F> {
+    keys: Vec<K>,
+    key_of: F,
+    filled: bool,
+    _p: PhantomData<P>,
+}
+
+impl<P: Palette, K: PartialEq + Display, F> CategoryStyle<P, K, F> {
+    /// Create the mapper, the categories get the palette colors in the given order and the
+    /// unknown categories get the color after the last one
+    /// - `keys`: The categories
+    /// - `key_of`: The function that gets the category of the datum
+    pub fn new(keys: Vec<K>, key_of: F) -> Self {
+        Self {
+            keys,
+            key_of,
+            filled: true,
+            _p: PhantomData,
+        }
+    }
+
+    /// Draw the outlines rather than the filled shapes
+    pub fn outlined(mut self) -> Self {
+        self.filled = false;
+        self
+    }
+
+    fn make_style(&self, idx: usize) -> ShapeStyle {
+        let color = P::pick(idx).to_rgba();
+        if self.filled {
+            color.filled()
+        } else {
+            color.stroke_width(1)
+        }
+    }
+}
+
+impl<T, P: Palette, K: PartialEq + Display, F: Fn(&T) -> K> StyleMapper<T>
+    for CategoryStyle<P, K, F>
+{
+    fn style(&self, datum: &T) -> ShapeStyle {
+        let key = (self.key_of)(datum);
+        let idx = self
+            .keys
+            .iter()
+            .position(|k| *k == key)
+            .unwrap_or(self.keys.len());
+        self.make_style(idx)
+    }
+
+    fn legend(&self) -> MapperLegend {
+        MapperLegend::Swatches(
+            self.keys
+                .iter()
+                .enumerate()
+                .map(|(idx, key)| (key.to_string(), self.make_style(idx)))
+                .collect(),
+        )
+    }
+}
+
+/// The style mapper which uses a palette as a continuous color map for the magnitude of the
+/// data
+pub struct ColorMapStyle<P: Palette, F> {
+    range: (f64, f64),
+    value_of: F,
+    space: ColorSpace,
+    filled: bool,
+    _p: PhantomData<P>,
+}
+
+impl<P: Palette, F> ColorMapStyle<P, F> {
+    /// Create the mapper, the value `min` gets the first palette color and `max` gets the
+    /// last one
+    /// - `min`: The smallest value
+    /// - `max`: The largest value
+    /// - `value_of`: The function that gets the value of the datum
+    pub fn new(min: f64, max: f64, value_of: F) -> Self {
+        Self {
+            range: (min, max),
+            value_of,
+            space: ColorSpace::Lab,
+            filled: true,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the color space used to interpolate the palette colors, `ColorSpace::Lab` by default
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Draw the outlines rather than the filled shapes
+    pub fn outlined(mut self) -> Self {
+        self.filled = false;
+        self
+    }
+
+    fn color_at(&self, value: f64) -> RGBAColor {
+        let (min, max) = self.range;
+        let t = if max == min {
+            0.0
+        } else {
+            (value - min) / (max - min)
+        };
+        P::interpolate(t, self.space)
+    }
+}
+
+impl<T, P: Palette, F: Fn(&T) -> f64> StyleMapper<T> for ColorMapStyle<P, F> {
+    fn style(&self, datum: &T) -> ShapeStyle {
+        let color = self.color_at((self.value_of)(datum));
+        if self.filled {
+            color.filled()
+        } else {
+            color.stroke_width(1)
+        }
+    }
+
+    fn legend(&self) -> MapperLegend {
+        let (min, max) = self.range;
+        MapperLegend::ColorBar {
+            min,
+            max,
+            colors: (0..COLORBAR_STOPS)
+                .map(|i| self.color_at(min + (max - min) * i as f64 / (COLORBAR_STOPS - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{Palette99, RED};
+
+    #[test]
+    fn test_style_mappers() {
+        let by_sign = |x: &i32| {
+            if *x < 0 {
+                RED.filled()
+            } else {
+                RED.stroke_width(2)
+            }
+        };
+        assert!(by_sign.style(&-1).filled);
+        assert_eq!(by_sign.style(&1).stroke_width, 2);
+
+        let by_category = CategoryStyle::<Palette99, _, _>::new(vec![1, 2], |d: &(i32, f64)| d.0);
+        assert_eq!(
+            by_category.style(&(2, 0.5)).color.rgb(),
+            Palette99::pick(1).rgb()
+        );
+        match by_category.legend() {
+            MapperLegend::Swatches(swatches) => {
+                assert_eq!(swatches.len(), 2);
+                assert_eq!(swatches[0].0, "1");
+            }
+            _ => panic!("Expect swatches"),
+        }
+
+        let by_value = ColorMapStyle::<Palette99, _>::new(0.0, 10.0, |d: &f64| *d)
+            .color_space(ColorSpace::Srgb);
+        assert_eq!(
+            by_value.style(&10.0).color.rgb(),
+            Palette99::COLORS[Palette99::COLORS.len() - 1]
+        );
+        match by_value.legend() {
+            MapperLegend::ColorBar { min, max, colors } => {
+                assert_eq!((min, max), (0.0, 10.0));
+                assert_eq!(colors.len(), COLORBAR_STOPS);
+                assert_eq!(colors[0].rgb(), Palette99::COLORS[0]);
+            }
+            _ => panic!("Expect a colorbar"),
+        }
+    }
+}
@@ -4,10 +4,13 @@
 mod color;
 pub mod colors;
 mod font;
+mod interpolation;
+mod mapper;
 mod palette;
 mod shape;
 mod size;
 mod text;
+mod theme;
 
 #[cfg(feature = "palette_ext")]
 mod palette_ext;
@@ -19,6 +22,12 @@ pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YEL
 pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
-pub use shape::ShapeStyle;
-pub use size::{AsRelative, RelativeSize, SizeDesc};
+pub use interpolation::ColorSpace;
+pub use mapper::{CategoryStyle, ColorMapStyle, MapperLegend, StyleMapper};
+pub use shape::{
+    ShapeStyle, DEFAULT_AXIS_STYLE, DEFAULT_BOLD_MESH_STYLE, DEFAULT_FILL_STYLE,
+    DEFAULT_LIGHT_MESH_STYLE, DEFAULT_LINE_STYLE,
+};
+pub use size::{AsRelative, RelativeSize, SizeDesc, StrokeWidth};
 pub use text::{IntoTextStyle, TextAlignment, TextStyle};
+pub use theme::Theme;
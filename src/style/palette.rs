@@ -1,4 +1,5 @@
-use super::color::PaletteColor;
+use super::color::{PaletteColor, RGBAColor};
+use super::interpolation::{interpolate, ColorSpace};
 
 pub trait Palette {
     const COLORS: &'static [(u8, u8, u8)];
@@ -8,6 +9,28 @@ pub trait Palette {
     {
         PaletteColor::<Self>::pick(idx)
     }
+
+    /// Use the palette as a continuous color map and get the color at the given position
+    /// - `t`: The position in the palette, 0 is the first color and 1 is the last one
+    /// - `space`: The color space used to interpolate between the adjacent palette colors
+    fn interpolate(t: f64, space: ColorSpace) -> RGBAColor
+    where
+        Self: Sized,
+    {
+        let to_rgba = |(r, g, b): (u8, u8, u8)| RGBAColor(r, g, b, 1.0);
+        if Self::COLORS.len() < 2 {
+            return to_rgba(Self::COLORS.first().cloned().unwrap_or((0, 0, 0)));
+        }
+        let last = Self::COLORS.len() - 1;
+        let pos = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) } * last as f64;
+        let idx = (pos.floor() as usize).min(last - 1);
+        interpolate(
+            &to_rgba(Self::COLORS[idx]),
+            &to_rgba(Self::COLORS[idx + 1]),
+            pos - idx as f64,
+            space,
+        )
+    }
 }
 
 /// The palette of 99% accessibility
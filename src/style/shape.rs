@@ -1,4 +1,5 @@
 use super::color::{Color, RGBAColor};
+use super::colors::BLACK;
 
 /// Style for any of shape
 #[derive(Clone)]
@@ -8,19 +9,30 @@ pub struct ShapeStyle {
     pub stroke_width: u32,
 }
 
+/// The default style of the lines: a black stroke of 1 pixel
+pub const DEFAULT_LINE_STYLE: ShapeStyle = BLACK.stroke_width(1);
+/// The default style of the filled shapes
+pub const DEFAULT_FILL_STYLE: ShapeStyle = BLACK.filled();
+/// The default style of the axis lines
+pub const DEFAULT_AXIS_STYLE: ShapeStyle = BLACK.stroke_width(1);
+/// The default style of the bold mesh lines
+pub const DEFAULT_BOLD_MESH_STYLE: ShapeStyle = BLACK.mix(0.2).stroke_width(1);
+/// The default style of the light mesh lines
+pub const DEFAULT_LIGHT_MESH_STYLE: ShapeStyle = BLACK.mix(0.1).stroke_width(1);
+
 impl ShapeStyle {
     /// Make a filled shape style
-    pub fn filled(&self) -> Self {
+    pub const fn filled(&self) -> Self {
         Self {
-            color: self.color.to_rgba(),
+            color: RGBAColor(self.color.0, self.color.1, self.color.2, self.color.3),
             filled: true,
             stroke_width: self.stroke_width,
         }
     }
 
-    pub fn stroke_width(&self, width: u32) -> Self {
+    pub const fn stroke_width(&self, width: u32) -> Self {
         Self {
-            color: self.color.to_rgba(),
+            color: RGBAColor(self.color.0, self.color.1, self.color.2, self.color.3),
             filled: self.filled,
             stroke_width: width,
         }
@@ -36,3 +48,24 @@ impl<'a, T: Color> From<&'a T> for ShapeStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RED;
+
+    const RED_FILL: ShapeStyle = RED.mix(0.5).filled();
+
+    #[test]
+    fn test_const_shape_style() {
+        let style: ShapeStyle = (&RED.mix(0.5)).into();
+        let style = style.filled();
+        assert_eq!(RED_FILL.color, style.color);
+        assert_eq!(RED_FILL.filled, style.filled);
+        assert_eq!(RED_FILL.stroke_width, style.stroke_width);
+
+        let style = Into::<ShapeStyle>::into(&BLACK).stroke_width(1);
+        assert_eq!(DEFAULT_LINE_STYLE.color, style.color);
+        assert_eq!(DEFAULT_LINE_STYLE.stroke_width, 1);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::coord::CoordTranslate;
+use crate::drawing::backend::DEFAULT_DPI;
 use crate::drawing::DrawingArea;
 use crate::drawing::DrawingBackend;
 
@@ -9,18 +10,31 @@ use crate::drawing::DrawingBackend;
 pub trait HasDimension {
     /// Get the dimensional data for this object
     fn dim(&self) -> (u32, u32);
+
+    /// Get the resolution of this object in pixels per inch
+    fn dpi(&self) -> f64 {
+        DEFAULT_DPI
+    }
 }
 
 impl<T: DrawingBackend> HasDimension for T {
     fn dim(&self) -> (u32, u32) {
         self.get_size()
     }
+
+    fn dpi(&self) -> f64 {
+        self.get_dpi()
+    }
 }
 
 impl<D: DrawingBackend, C: CoordTranslate> HasDimension for DrawingArea<D, C> {
     fn dim(&self) -> (u32, u32) {
         self.dim_in_pixel()
     }
+
+    fn dpi(&self) -> f64 {
+        self.get_dpi()
+    }
 }
 
 impl HasDimension for (u32, u32) {
@@ -155,9 +169,51 @@ impl SizeDesc for RelativeSizeWithBound {
     }
 }
 
+/// The width of the hairline in points, which is the thinnest line visible in a print
+const HAIRLINE_POINTS: f64 = 0.25;
+
+/// Describes the stroke width in the physical units, which is resolved with the resolution of
+/// the backend, so the lines have the same thickness in a screen image and a print render
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StrokeWidth {
+    /// The number of pixels regardless of the resolution
+    Pixels(u32),
+    /// The width in points, 1/72 inch
+    Points(f64),
+    /// The width in millimeters
+    Millimeters(f64),
+    /// The thinnest visible line, which is one pixel on a screen and a quarter point in print
+    Hairline,
+}
+
+impl StrokeWidth {
+    /// Resolve the stroke width for the style, the visible line is at least one pixel wide
+    ///
+    /// - `parent`: The backend or the drawing area the line is drawn on
+    /// - **returns**: The stroke width in pixels
+    pub fn resolve<T: HasDimension>(&self, parent: &T) -> u32 {
+        self.in_pixels(parent).max(1) as u32
+    }
+}
+
+impl SizeDesc for StrokeWidth {
+    fn in_pixels<D: HasDimension>(&self, parent: &D) -> i32 {
+        let dpi = parent.dpi();
+        match self {
+            StrokeWidth::Pixels(px) => return *px as i32,
+            StrokeWidth::Points(pt) => pt * dpi / 72.0,
+            StrokeWidth::Millimeters(mm) => mm * dpi / 25.4,
+            StrokeWidth::Hairline => (HAIRLINE_POINTS * dpi / 72.0).max(1.0),
+        }
+        .round() as i32
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    #[cfg(feature = "bitmap")]
+    use crate::drawing::BitMapBackend;
     #[test]
     fn test_relative_size() {
         let size = (10).percent_height();
@@ -177,4 +233,24 @@ mod test {
         assert_eq!(size.in_pixels(&(100, 200)), 10);
         assert_eq!(size.in_pixels(&(400, 200)), 20);
     }
+
+    #[test]
+    fn test_stroke_width() {
+        let screen = crate::drawing::create_mocked_drawing_area(100, 100, |_| {});
+        assert_eq!(screen.get_dpi(), 96.0);
+        assert_eq!(StrokeWidth::Points(3.0).in_pixels(&screen), 4);
+        assert_eq!(StrokeWidth::Hairline.resolve(&screen), 1);
+        assert_eq!(StrokeWidth::Points(0.1).resolve(&screen), 1);
+        assert_eq!(StrokeWidth::Pixels(2).resolve(&screen), 2);
+    }
+
+    #[cfg(feature = "bitmap")]
+    #[test]
+    fn test_stroke_width_dpi() {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        let print = BitMapBackend::with_buffer(&mut buffer, (100, 100)).with_dpi(600.0);
+        assert_eq!(StrokeWidth::Hairline.resolve(&print), 2);
+        assert_eq!(StrokeWidth::Millimeters(0.254).resolve(&print), 6);
+        assert_eq!(StrokeWidth::Pixels(2).resolve(&print), 2);
+    }
 }
@@ -0,0 +1,238 @@
+use super::color::{Color, HSLColor, RGBColor};
+use super::interpolation::rgb_to_lab;
+use super::palette::{Palette, Palette99};
+use crate::coord::Shift;
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingArea;
+
+use std::error::Error;
+
+/// The color roles of a chart, which are used in place of the hard-coded colors so that the
+/// same chart definition can be rendered in both the light and the dark mode
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// The color filling the drawing area
+    pub background: RGBColor,
+    /// The color of the text, the axes and the frame
+    pub foreground: RGBColor,
+    /// The color of the mesh lines
+    pub grid: RGBColor,
+    /// The colors of the series, picked in order and repeated if there are more series
+    pub palette: Vec<RGBColor>,
+}
+
+/// Convert the color to the hue, the saturation and the lightness, all of them in [0, 1]
+#[allow(clippy::many_single_char_names)]
+fn to_hsl((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h / 6.0, s, l)
+}
+
+/// Mirror the lightness of the color and keep its hue and saturation
+/// - **returns**: The perceptual lightness of the original color and the inverted color
+fn invert_lightness(color: &RGBColor) -> (f64, RGBColor) {
+    let (h, s, l) = to_hsl(color.rgb());
+    let (r, g, b) = HSLColor(h, s, 1.0 - l).rgb();
+    (rgb_to_lab(color.rgb()).0, RGBColor(r, g, b))
+}
+
+impl Theme {
+    /// The light theme, the black text on the white background with the default palette
+    pub fn light() -> Self {
+        Self {
+            background: RGBColor(255, 255, 255),
+            foreground: RGBColor(0, 0, 0),
+            grid: RGBColor(204, 204, 204),
+            palette: Palette99::COLORS
+                .iter()
+                .map(|&(r, g, b)| RGBColor(r, g, b))
+                .collect(),
+        }
+    }
+
+    /// The dark theme, which is the inverted light theme
+    pub fn dark() -> Self {
+        Self::light().inverted()
+    }
+
+    /// Check if the background of the theme is dark
+    pub fn is_dark(&self) -> bool {
+        rgb_to_lab(self.background.rgb()).0 < 50.0
+    }
+
+    /// Get the color of the series
+    /// - `idx`: The index of the series
+    pub fn series_color(&self, idx: usize) -> RGBColor {
+        if self.palette.is_empty() {
+            return self.foreground;
+        }
+        self.palette[idx % self.palette.len()]
+    }
+
+    /// Make the variant of the theme for the opposite mode: the background and the foreground
+    /// are swapped, the lightness of the grid is mirrored and the series colors keep their hue
+    /// but are moved to the lightness which contrasts with the new background
+    pub fn inverted(&self) -> Self {
+        let to_dark = !self.is_dark();
+        Self {
+            background: self.foreground,
+            foreground: self.background,
+            grid: invert_lightness(&self.grid).1,
+            palette: self
+                .palette
+                .iter()
+                .map(|color| match invert_lightness(color) {
+                    (l, inverted) if (l < 50.0) == to_dark => inverted,
+                    _ => *color,
+                })
+                .collect(),
+        }
+    }
+
+    /// Fill the drawing area with the background and draw the figure with this theme
+    /// - `root`: The drawing area the figure is drawn on
+    /// - `draw`: The function that draws the figure with the colors of the theme
+    pub fn render<DB: DrawingBackend, Draw>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+        draw: Draw,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        DB::ErrorType: 'static,
+        Draw: FnOnce(&DrawingArea<DB, Shift>, &Theme) -> Result<(), Box<dyn Error>>,
+    {
+        root.fill(&self.background)?;
+        draw(root, self)?;
+        root.present()?;
+        Ok(())
+    }
+
+    /// Draw the figure in both modes, this theme is used for the first drawing area and the
+    /// inverted theme for the second one
+    /// - `root`: The drawing area of this theme
+    /// - `inverted_root`: The drawing area of the inverted theme
+    /// - `draw`: The function that draws the figure with the colors of the theme, which is
+    ///   called once for each mode
+    pub fn render_variants<DB: DrawingBackend, Draw>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+        inverted_root: &DrawingArea<DB, Shift>,
+        mut draw: Draw,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        DB::ErrorType: 'static,
+        Draw: FnMut(&DrawingArea<DB, Shift>, &Theme) -> Result<(), Box<dyn Error>>,
+    {
+        self.render(root, &mut draw)?;
+        self.inverted().render(inverted_root, &mut draw)
+    }
+
+    /// Draw the figure in both modes as the SVG documents
+    /// - `size`: The size of the figure in pixels
+    /// - `draw`: The function that draws the figure with the colors of the theme
+    /// - **returns**: The SVG documents with this theme and the inverted theme
+    #[cfg(feature = "svg")]
+    pub fn render_svg_variants<Draw>(
+        &self,
+        size: (u32, u32),
+        mut draw: Draw,
+    ) -> Result<(String, String), Box<dyn Error>>
+    where
+        Draw: FnMut(
+            &DrawingArea<crate::drawing::SVGBackend, Shift>,
+            &Theme,
+        ) -> Result<(), Box<dyn Error>>,
+    {
+        use crate::drawing::{IntoDrawingArea, SVGBackend};
+        let mut render = |theme: &Theme| -> Result<String, Box<dyn Error>> {
+            let mut buffer = vec![];
+            theme.render(
+                &SVGBackend::with_buffer(&mut buffer, size).into_drawing_area(),
+                &mut draw,
+            )?;
+            Ok(String::from_utf8_lossy(&buffer).into_owned())
+        };
+        let light = render(self)?;
+        let dark = render(&self.inverted())?;
+        Ok((light, dark))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn lightness(color: &RGBColor) -> f64 {
+        rgb_to_lab((color.0, color.1, color.2)).0
+    }
+
+    #[test]
+    fn test_inverted() {
+        let light = Theme::light();
+        let dark = Theme::dark();
+        assert!(!light.is_dark());
+        assert!(dark.is_dark());
+        assert_eq!(dark.background, light.foreground);
+        assert_eq!(dark.foreground, light.background);
+        assert_eq!(dark.grid, RGBColor(51, 51, 51));
+        assert_eq!(dark.palette.len(), light.palette.len());
+        for color in &dark.palette {
+            assert!(lightness(color) >= 49.0);
+        }
+        assert_eq!(dark.palette[18], RGBColor(127, 127, 255));
+        assert_eq!(dark.inverted().grid, light.grid);
+        assert_eq!(
+            light.series_color(light.palette.len() + 1),
+            light.palette[1]
+        );
+    }
+
+    #[test]
+    fn test_render_variants() {
+        let light = Theme::light();
+        let dark = Theme::dark();
+        let check = |background: RGBColor| {
+            move |m: &mut MockedBackend| {
+                m.check_draw_rect(move |c, _, filled, _, _| {
+                    assert!(filled);
+                    assert_eq!(c.rgb(), (background.0, background.1, background.2));
+                });
+                m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+            }
+        };
+        let light_root = create_mocked_drawing_area(100, 100, check(light.background));
+        let dark_root = create_mocked_drawing_area(100, 100, check(dark.background));
+        let mut calls = vec![];
+        light
+            .render_variants(&light_root, &dark_root, |_, theme| {
+                calls.push(theme.is_dark());
+                Ok(())
+            })
+            .expect("Render variants");
+        assert_eq!(calls, vec![false, true]);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::coord::Shift;
+use crate::drawing::{BitMapBackend, DrawingArea, IntoDrawingArea};
+
+use image::png::PNGEncoder;
+use image::ColorType;
+
+use std::error::Error;
+
+/// The number of EMUs (English Metric Units, the unit of the drawing anchors in the XLSX files)
+/// in one pixel at 96 DPI
+pub const EMU_PER_PIXEL: u32 = 9525;
+
+/// The default column width of a worksheet in pixels
+pub const DEFAULT_COLUMN_WIDTH: u32 = 64;
+
+/// The default row height of a worksheet in pixels
+pub const DEFAULT_ROW_HEIGHT: u32 = 20;
+
+/// A corner of the embedded image, which is a cell and the offset in EMUs inside the cell. This
+/// is the `<xdr:from>` and `<xdr:to>` markers of the two cell anchor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct XlsxMarker {
+    /// The zero-based row of the cell
+    pub row: u32,
+    /// The zero-based column of the cell
+    pub col: u32,
+    /// The horizontal offset inside the cell in EMUs
+    pub col_offset: u32,
+    /// The vertical offset inside the cell in EMUs
+    pub row_offset: u32,
+}
+
+/// The position and the size of the embedded image in the worksheet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct XlsxAnchor {
+    /// The zero-based row of the cell where the upper left corner of the image is placed
+    pub row: u32,
+    /// The zero-based column of the cell where the upper left corner of the image is placed
+    pub col: u32,
+    /// The offset of the image inside the cell in pixels
+    pub offset: (u32, u32),
+    /// The displayed size of the image in pixels, `None` means the rendered size
+    pub size: Option<(u32, u32)>,
+}
+
+impl XlsxAnchor {
+    /// Place the image at the upper left corner of the cell
+    /// - `row`: The zero-based row
+    /// - `col`: The zero-based column
+    pub fn new(row: u32, col: u32) -> Self {
+        Self {
+            row,
+            col,
+            offset: (0, 0),
+            size: None,
+        }
+    }
+
+    /// Set the offset of the image inside the cell
+    /// - `x`: The horizontal offset in pixels
+    /// - `y`: The vertical offset in pixels
+    pub fn offset(mut self, x: u32, y: u32) -> Self {
+        self.offset = (x, y);
+        self
+    }
+
+    /// Set the displayed size of the image, the image is scaled by the spreadsheet application
+    /// - `size`: The size in pixels
+    pub fn size(mut self, size: (u32, u32)) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Get the displayed size of the image in EMUs, this is the `<xdr:ext>` of the one cell
+    /// anchor
+    /// - `image_size`: The rendered size of the image, used when the size isn't set
+    pub fn extent(&self, image_size: (u32, u32)) -> (u64, u64) {
+        let (w, h) = self.size.unwrap_or(image_size);
+        (
+            u64::from(w) * u64::from(EMU_PER_PIXEL),
+            u64::from(h) * u64::from(EMU_PER_PIXEL),
+        )
+    }
+
+    /// Get the marker of the upper left corner of the image
+    pub fn start_marker(&self) -> XlsxMarker {
+        XlsxMarker {
+            row: self.row,
+            col: self.col,
+            col_offset: self.offset.0 * EMU_PER_PIXEL,
+            row_offset: self.offset.1 * EMU_PER_PIXEL,
+        }
+    }
+
+    /// Get the marker of the lower right corner of the image, assuming all the columns and rows
+    /// covered by the image have the same size
+    /// - `image_size`: The rendered size of the image, used when the size isn't set
+    /// - `cell_size`: The width of the columns and the height of the rows in pixels, e.g.
+    /// `(DEFAULT_COLUMN_WIDTH, DEFAULT_ROW_HEIGHT)`
+    pub fn end_marker(&self, image_size: (u32, u32), cell_size: (u32, u32)) -> XlsxMarker {
+        let (w, h) = self.size.unwrap_or(image_size);
+        let (cw, ch) = (cell_size.0.max(1), cell_size.1.max(1));
+        let right = self.offset.0 + w;
+        let bottom = self.offset.1 + h;
+        XlsxMarker {
+            row: self.row + bottom / ch,
+            col: self.col + right / cw,
+            col_offset: right % cw * EMU_PER_PIXEL,
+            row_offset: bottom % ch * EMU_PER_PIXEL,
+        }
+    }
+}
+
+/// The rendered chart which is ready to be inserted into a worksheet
+pub struct XlsxImage {
+    /// The PNG encoded image
+    pub png: Vec<u8>,
+    /// The rendered size of the image in pixels
+    pub image_size: (u32, u32),
+    /// Where the image should be placed
+    pub anchor: XlsxAnchor,
+}
+
+/// Render a figure and pass it to the writer callback, which inserts the image into the
+/// worksheet with the XLSX library used by the application.
+///
+/// - `size`: The size of the rendered image in pixels
+/// - `anchor`: The position and the displayed size of the image in the worksheet
+/// - `draw`: The function that draws the figure
+/// - `write`: The function that inserts the image into the worksheet
+pub fn xlsx_figure<Draw, Write>(
+    size: (u32, u32),
+    anchor: XlsxAnchor,
+    draw: Draw,
+    write: Write,
+) -> Result<(), Box<dyn Error>>
+where
+    Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    Write: FnOnce(&XlsxImage) -> Result<(), Box<dyn Error>>,
+{
+    let mut buffer = vec![0; size.0 as usize * size.1 as usize * 3];
+    draw(BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area())?;
+
+    let mut png = vec![];
+    PNGEncoder::new(&mut png).encode(&buffer, size.0, size.1, ColorType::RGB(8))?;
+
+    write(&XlsxImage {
+        png,
+        image_size: size,
+        anchor,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_xlsx_anchor() {
+        let anchor = XlsxAnchor::new(2, 1).offset(10, 5);
+        assert_eq!(anchor.extent((640, 480)), (640 * 9525, 480 * 9525));
+        assert_eq!(
+            anchor.start_marker(),
+            XlsxMarker {
+                row: 2,
+                col: 1,
+                col_offset: 10 * 9525,
+                row_offset: 5 * 9525,
+            }
+        );
+        assert_eq!(
+            anchor.end_marker((640, 480), (DEFAULT_COLUMN_WIDTH, DEFAULT_ROW_HEIGHT)),
+            XlsxMarker {
+                row: 26,
+                col: 11,
+                col_offset: 10 * 9525,
+                row_offset: 5 * 9525,
+            }
+        );
+        assert_eq!(
+            anchor.size((100, 40)).end_marker((640, 480), (50, 20)).col,
+            3
+        );
+    }
+
+    #[test]
+    fn test_xlsx_figure() {
+        let mut inserted = None;
+        xlsx_figure(
+            (200, 100),
+            XlsxAnchor::new(0, 0),
+            |root| {
+                root.fill(&WHITE)?;
+                let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10)?;
+                chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))?;
+                Ok(())
+            },
+            |image| {
+                inserted = Some((image.png.clone(), image.image_size));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        let (png, size) = inserted.unwrap();
+        assert_eq!(size, (200, 100));
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}